@@ -0,0 +1,39 @@
+//! Compares converting a `String2` into a `String` with the old encoder,
+//! which built a temporary `String` for every char, against the current
+//! single-allocation one.
+//!
+//! Run with `cargo bench --bench into_string`.
+
+extern crate string2;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use string2::String2;
+
+fn per_char(s: &String2) -> String {
+    s.as_slice().iter().map(|c| c.encode_utf8(&mut [0; 4]).to_string()).collect()
+}
+
+fn time<F: FnMut() -> String>(mut f: F) -> Duration {
+    const RUNS: u32 = 200;
+
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        black_box(f());
+    }
+    start.elapsed() / RUNS
+}
+
+fn main() {
+    let text = String2::from("Grüße, 世界! Hello, world. ".repeat(4096).as_str());
+    assert_eq!(per_char(&text), String::from(&text));
+
+    let old = time(|| per_char(black_box(&text)));
+    let new = time(|| String::from(black_box(&text)));
+
+    println!("{} chars", text.len());
+    println!("per-char encoder:  {:>10.1?}", old);
+    println!("single allocation: {:>10.1?}", new);
+    println!("speedup:           {:>9.1}x", old.as_secs_f64() / new.as_secs_f64());
+}
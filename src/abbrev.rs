@@ -0,0 +1,122 @@
+use alloc::vec::Vec;
+
+use crate::String2;
+
+const ELLIPSIS: char = '…';
+
+fn join_words(words: &[&[char]], capacity: usize) -> String2 {
+    let mut out = String2::with_capacity(capacity);
+
+    for (idx, word) in words.iter().enumerate() {
+        if idx > 0 {
+            out.push(' ');
+        }
+        out.extend(word.iter());
+    }
+
+    out
+}
+
+impl String2 {
+    /// Returns the initials of the words of this `String2`, uppercased.
+    ///
+    /// Words are runs of alphanumeric chars, so hyphenated names give one
+    /// initial per part.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("Ada Lovelace").initials(), "AL");
+    /// assert_eq!(String2::from("jean-luc picard").initials(), "JLP");
+    /// assert_eq!(String2::from("  ").initials(), "");
+    /// ```
+    pub fn initials(&self) -> String2 {
+        let mut out = String2::new();
+
+        let mut prev_alnum = false;
+        for &ch in &self.inner {
+            let alnum = ch.is_alphanumeric();
+            if alnum && !prev_alnum {
+                out.extend(ch.to_uppercase());
+            }
+            prev_alnum = alnum;
+        }
+
+        out
+    }
+
+    /// Shortens this `String2` to at most `max_len` chars by replacing
+    /// words in the middle with `…`.
+    ///
+    /// Runs of whitespace are first collapsed to single spaces. If that is
+    /// not enough, the first and last words are kept, along with as many
+    /// of the words after the first as fit. When not even the first and
+    /// last words fit, the string is cut and ends with `…` instead.
+    /// Strings that already fit are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("Department of Computer Science and Engineering");
+    ///
+    /// assert_eq!(s.abbreviate(30), "Department of … Engineering");
+    /// assert_eq!(s.abbreviate(24), "Department … Engineering");
+    /// assert_eq!(s.abbreviate(10), "Departmen…");
+    /// assert_eq!(s.abbreviate(100), s);
+    /// ```
+    pub fn abbreviate(&self, max_len: usize) -> String2 {
+        if self.len() <= max_len {
+            return self.clone()
+        }
+
+        let words: Vec<&[char]> = self.inner
+            .split(|ch| ch.is_whitespace())
+            .filter(|word| !word.is_empty())
+            .collect();
+
+        // Collapsing runs of whitespace may be enough.
+        let joined_len = words.iter().map(|word| word.len() + 1).sum::<usize>().saturating_sub(1);
+        if joined_len <= max_len {
+            return join_words(&words, joined_len)
+        }
+
+        if words.len() >= 2 {
+            let last = words[words.len() - 1];
+            // The first word, " … " and the last word.
+            let mut len = words[0].len() + 3 + last.len();
+
+            if len <= max_len {
+                let mut kept = 1;
+                while kept < words.len() - 1 && len + words[kept].len() < max_len {
+                    len += words[kept].len() + 1;
+                    kept += 1;
+                }
+
+                let mut out = join_words(&words[..kept], len);
+                out.push(' ');
+                out.push(ELLIPSIS);
+                out.push(' ');
+                out.extend(last.iter());
+
+                return out
+            }
+        }
+
+        if max_len == 0 {
+            return String2::new()
+        }
+
+        let mut out = String2::from(&self.inner[..max_len - 1]);
+        out.trim_end_in_place();
+        out.push(ELLIPSIS);
+        out
+    }
+}
@@ -0,0 +1,162 @@
+use core::iter::FusedIterator;
+
+use crate::String2;
+
+/// An iterator over the non-empty prefixes of a `String2`, from shortest to
+/// longest.
+///
+/// This struct is created by the [`prefixes`] method on `String2`.
+///
+/// [`prefixes`]: struct.String2.html#method.prefixes
+#[derive(Clone, Debug)]
+pub struct Prefixes<'a> {
+    chars: &'a [char],
+    // Lengths of the next prefix from the front, and one past the next from
+    // the back.
+    front: usize,
+    back: usize
+}
+
+impl<'a> Iterator for Prefixes<'a> {
+    type Item = &'a [char];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [char]> {
+        if self.front > self.back {
+            return None
+        }
+
+        let prefix = &self.chars[..self.front];
+        self.front += 1;
+        Some(prefix)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back + 1).saturating_sub(self.front);
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Prefixes<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [char]> {
+        if self.front > self.back {
+            return None
+        }
+
+        let prefix = &self.chars[..self.back];
+        self.back -= 1;
+        Some(prefix)
+    }
+}
+
+impl<'a> ExactSizeIterator for Prefixes<'a> {}
+
+impl<'a> FusedIterator for Prefixes<'a> {}
+
+/// An iterator over the non-empty suffixes of a `String2`, from longest to
+/// shortest.
+///
+/// This struct is created by the [`suffixes`] method on `String2`.
+///
+/// [`suffixes`]: struct.String2.html#method.suffixes
+#[derive(Clone, Debug)]
+pub struct Suffixes<'a> {
+    chars: &'a [char],
+    // Start indices of the next suffix from the front, and one past the
+    // next from the back.
+    front: usize,
+    back: usize
+}
+
+impl<'a> Iterator for Suffixes<'a> {
+    type Item = &'a [char];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [char]> {
+        if self.front >= self.back {
+            return None
+        }
+
+        let suffix = &self.chars[self.front..];
+        self.front += 1;
+        Some(suffix)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Suffixes<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [char]> {
+        if self.front >= self.back {
+            return None
+        }
+
+        self.back -= 1;
+        Some(&self.chars[self.back..])
+    }
+}
+
+impl<'a> ExactSizeIterator for Suffixes<'a> {}
+
+impl<'a> FusedIterator for Suffixes<'a> {}
+
+impl String2 {
+    /// An iterator over the non-empty prefixes of this `String2`, as char
+    /// slices, from shortest to longest.
+    ///
+    /// The prefixes borrow from the string, so no substring is allocated.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("abc");
+    /// let prefixes: Vec<String2> = s.prefixes().map(String2::from).collect();
+    ///
+    /// assert_eq!(prefixes, ["a", "ab", "abc"]);
+    /// ```
+    #[inline]
+    pub fn prefixes(&self) -> Prefixes<'_> {
+        Prefixes {
+            chars: &self.inner,
+            front: 1,
+            back: self.inner.len()
+        }
+    }
+
+    /// An iterator over the non-empty suffixes of this `String2`, as char
+    /// slices, from longest to shortest.
+    ///
+    /// The suffixes borrow from the string, so no substring is allocated.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("abc");
+    /// let suffixes: Vec<String2> = s.suffixes().map(String2::from).collect();
+    ///
+    /// assert_eq!(suffixes, ["abc", "bc", "c"]);
+    /// ```
+    #[inline]
+    pub fn suffixes(&self) -> Suffixes<'_> {
+        Suffixes {
+            chars: &self.inner,
+            front: 0,
+            back: self.inner.len()
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use core::fmt;
+use core::ops;
+
+use allocator_api2::alloc::{Allocator, Global};
+use allocator_api2::vec::Vec;
+
+use crate::String2;
+
+/// A `String2` whose chars live in a buffer from the allocator `A`.
+///
+/// This lets arena and bump allocators back the char buffer, which pays off
+/// when many short-lived strings are built and dropped together. The
+/// allocator traits come from [`allocator-api2`], which matches the unstable
+/// `Allocator` trait of the standard library and works on stable Rust.
+///
+/// `String2In` supports building and editing text; it dereferences to
+/// `[char]` for reading it. For the rest of the `String2` API, convert it
+/// with [`to_string2`].
+///
+/// This type requires the `allocator_api` feature.
+///
+/// [`allocator-api2`]: https://docs.rs/allocator-api2
+/// [`to_string2`]: #method.to_string2
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate allocator_api2;
+/// # extern crate string2;
+/// use allocator_api2::alloc::Global;
+/// use string2::String2In;
+///
+/// # fn main() {
+/// let mut s = String2In::with_capacity_in(16, Global);
+///
+/// s.push_str("héllo");
+/// s.push('!');
+///
+/// assert_eq!(s.len(), 6);
+/// assert_eq!(s[1], 'é');
+/// assert_eq!(s.to_string2(), "héllo!");
+/// # }
+/// ```
+pub struct String2In<A: Allocator = Global> {
+    inner: Vec<char, A>
+}
+
+impl<A: Allocator> String2In<A> {
+    /// Creates a new empty `String2In` in the given allocator.
+    ///
+    /// Nothing is allocated until chars are added.
+    #[inline]
+    pub fn new_in(alloc: A) -> String2In<A> {
+        String2In {
+            inner: Vec::new_in(alloc)
+        }
+    }
+
+    /// Creates a new empty `String2In` with room for at least `capacity`
+    /// chars, allocated in the given allocator.
+    #[inline]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> String2In<A> {
+        String2In {
+            inner: Vec::with_capacity_in(capacity, alloc)
+        }
+    }
+
+    /// Creates a `String2In` holding the chars of `string`, allocated in
+    /// the given allocator.
+    #[inline]
+    pub fn from_str_in(string: &str, alloc: A) -> String2In<A> {
+        let mut s = String2In::with_capacity_in(string.len(), alloc);
+        s.push_str(string);
+        s
+    }
+
+    /// Returns a reference to the underlying allocator.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.inner.allocator()
+    }
+
+    /// Returns the number of chars this string can hold without
+    /// reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Reserves room for at least `additional` more chars.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    /// Shrinks the capacity of this string to match its length.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+
+    /// Returns the chars of this string.
+    #[inline]
+    pub fn as_slice(&self) -> &[char] {
+        &self.inner
+    }
+
+    /// Returns the chars of this string, mutably.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [char] {
+        &mut self.inner
+    }
+
+    /// Appends a char to the end of this string.
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        self.inner.push(ch)
+    }
+
+    /// Appends a string slice to the end of this string.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.inner.extend(string.chars())
+    }
+
+    /// Removes the last char and returns it, or `None` if this string is
+    /// empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        self.inner.pop()
+    }
+
+    /// Inserts a char at char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of this string.
+    #[inline]
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        self.inner.insert(idx, ch)
+    }
+
+    /// Removes the char at char index `idx` and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> char {
+        self.inner.remove(idx)
+    }
+
+    /// Shortens this string to `new_len` chars, doing nothing if it is
+    /// already shorter.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.inner.truncate(new_len)
+    }
+
+    /// Removes all chars, keeping the capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    /// Copies this string into a `String2` on the global heap.
+    #[inline]
+    pub fn to_string2(&self) -> String2 {
+        String2::from(&self.inner[..])
+    }
+}
+
+impl<A: Allocator + Clone> Clone for String2In<A> {
+    #[inline]
+    fn clone(&self) -> String2In<A> {
+        String2In {
+            inner: self.inner.clone()
+        }
+    }
+}
+
+impl<A: Allocator> ops::Deref for String2In<A> {
+    type Target = [char];
+
+    #[inline]
+    fn deref(&self) -> &[char] {
+        &self.inner
+    }
+}
+
+impl<A: Allocator> ops::DerefMut for String2In<A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [char] {
+        &mut self.inner
+    }
+}
+
+impl<A: Allocator> AsRef<[char]> for String2In<A> {
+    #[inline]
+    fn as_ref(&self) -> &[char] {
+        &self.inner
+    }
+}
+
+impl<A: Allocator> Extend<char> for String2In<A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<'a, A: Allocator> Extend<&'a str> for String2In<A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(s);
+        }
+    }
+}
+
+impl<A: Allocator, B: Allocator> PartialEq<String2In<B>> for String2In<A> {
+    #[inline]
+    fn eq(&self, other: &String2In<B>) -> bool {
+        self.inner[..] == other.inner[..]
+    }
+}
+
+impl<A: Allocator> Eq for String2In<A> {}
+
+impl<A: Allocator> PartialEq<String2> for String2In<A> {
+    #[inline]
+    fn eq(&self, other: &String2) -> bool {
+        self.inner[..] == other.inner[..]
+    }
+}
+
+impl<A: Allocator> PartialEq<str> for String2In<A> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.inner.iter().copied().eq(other.chars())
+    }
+}
+
+impl<'a, A: Allocator> PartialEq<&'a str> for String2In<A> {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self == *other
+    }
+}
+
+impl<A: Allocator> fmt::Display for String2In<A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::fmt_display(&self.inner, f)
+    }
+}
+
+impl<A: Allocator> fmt::Debug for String2In<A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::fmt_debug(&self.inner, f)
+    }
+}
@@ -0,0 +1,64 @@
+use core::char;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::String2;
+
+// Scalar values at the edges of the valid ranges, where encoders and
+// decoders tend to get off by one.
+const EDGE_CHARS: [char; 10] = [
+    '\0', '\u{7f}', '\u{80}', '\u{7ff}', '\u{800}',
+    '\u{d7ff}', '\u{e000}', '\u{fffd}', '\u{ffff}', '\u{10ffff}'
+];
+
+fn arbitrary_char(u: &mut Unstructured) -> Result<char> {
+    let code = match u.int_in_range(0..=7u8)? {
+        // ASCII, half of the time.
+        0..=3 => u.int_in_range(0..=0x7f)?,
+        4 => return u.choose(&EDGE_CHARS).cloned(),
+        // Combining diacritical marks.
+        5 => u.int_in_range(0x300..=0x36f)?,
+        // Astral planes: emoji, historic scripts, private use.
+        6 => u.int_in_range(0x1_0000..=0x10_ffff)?,
+        _ => return char::arbitrary(u)
+    };
+
+    Ok(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER))
+}
+
+/// Generates strings biased towards the chars that tend to break text
+/// handling: the edges of the surrogate gap and of each UTF-8 length,
+/// combining marks and astral plane chars, mixed with plenty of ASCII.
+///
+/// This impl requires the `arbitrary` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate arbitrary;
+/// # extern crate string2;
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use string2::String2;
+///
+/// # fn main() {
+/// let data = [7u8; 64];
+/// let mut u = Unstructured::new(&data);
+///
+/// let s = String2::arbitrary(&mut u).unwrap();
+/// assert!(s.len() <= data.len());
+/// # }
+/// ```
+impl<'a> Arbitrary<'a> for String2 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<String2> {
+        let len = u.arbitrary_len::<char>()?;
+        let mut s = String2::with_capacity(len);
+
+        for _ in 0..len {
+            s.push(arbitrary_char(u)?);
+        }
+
+        Ok(s)
+    }
+}
@@ -0,0 +1,220 @@
+use core::borrow::Borrow;
+use core::ops;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::snapshot::Snapshot2;
+use crate::view::View2;
+
+/// An immutable string whose chars are shared between its clones through
+/// an `Arc<[char]>`.
+///
+/// Cloning an `ArcString2` only bumps an atomic reference count, so the
+/// same text can be handed out many times, and to other threads, without
+/// copying it, as caches and interners do. To change it, make a `String2`
+/// copy with [`to_mut`].
+///
+/// An `ArcString2` dereferences to `[char]`, and borrows as `[char]`, so a
+/// map keyed by `ArcString2` can be looked up with a char slice.
+///
+/// [`to_mut`]: #method.to_mut
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::thread;
+/// use string2::{ArcString2, String2};
+///
+/// let name = String2::from("shared").into_shared();
+/// let other = name.clone();
+/// assert!(ArcString2::ptr_eq(&name, &other));
+///
+/// let len = thread::spawn(move || other.len()).join().unwrap();
+/// assert_eq!(len, 6);
+///
+/// let mut copy = name.to_mut();
+/// copy.push('!');
+/// assert_eq!(name, "shared");
+/// assert_eq!(copy, "shared!");
+/// ```
+#[derive(Clone)]
+pub struct ArcString2 {
+    inner: Arc<[char]>
+}
+
+impl ArcString2 {
+    /// Creates a new empty `ArcString2`.
+    #[inline]
+    pub fn new() -> ArcString2 {
+        ArcString2 {
+            inner: Arc::from(Vec::new())
+        }
+    }
+
+    /// Returns the length of this string, in chars.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if this string has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the chars of this string.
+    #[inline]
+    pub fn as_slice(&self) -> &[char] {
+        &self.inner
+    }
+
+    /// Returns a [`View2`] of this string, to search, trim and split it.
+    ///
+    /// [`View2`]: struct.View2.html
+    #[inline]
+    pub fn view(&self) -> View2<'_> {
+        View2::new(&self.inner)
+    }
+
+    /// Returns `true` if the two strings share the same chars, rather than
+    /// only being equal.
+    #[inline]
+    pub fn ptr_eq(this: &ArcString2, other: &ArcString2) -> bool {
+        Arc::ptr_eq(&this.inner, &other.inner)
+    }
+
+    /// Returns a copy of this string as a `String2`, which can be changed.
+    #[inline]
+    pub fn to_mut(&self) -> String2 {
+        String2::from(&self.inner[..])
+    }
+
+    /// Takes a snapshot of this string, which shares its chars instead of
+    /// copying them.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{ArcString2, String2};
+    ///
+    /// let mut s = ArcString2::from("draft");
+    /// let saved = s.snapshot();
+    ///
+    /// let mut preview = s.to_mut();
+    /// preview.push_str(" with a preview");
+    /// s = preview.into_shared();
+    /// assert_eq!(s, "draft with a preview");
+    ///
+    /// s.restore(&saved);
+    /// assert_eq!(s, "draft");
+    /// assert_eq!(s.as_slice().as_ptr(), saved.as_slice().as_ptr());
+    /// ```
+    #[inline]
+    pub fn snapshot(&self) -> Snapshot2 {
+        Snapshot2 {
+            chars: Arc::clone(&self.inner)
+        }
+    }
+
+    /// Replaces the contents of this string with those of `snapshot`,
+    /// sharing its chars instead of copying them.
+    #[inline]
+    pub fn restore(&mut self, snapshot: &Snapshot2) {
+        self.inner = Arc::clone(&snapshot.chars);
+    }
+}
+
+impl String2 {
+    /// Converts this `String2` into an [`ArcString2`], whose clones share
+    /// its chars.
+    ///
+    /// The chars are moved into a new allocation that also holds the
+    /// reference counts.
+    ///
+    /// [`ArcString2`]: struct.ArcString2.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let shared = String2::from("abc").into_shared();
+    /// assert_eq!(shared.clone(), "abc");
+    /// ```
+    #[inline]
+    pub fn into_shared(self) -> ArcString2 {
+        ArcString2 {
+            inner: Arc::from(self.inner)
+        }
+    }
+}
+
+impl Default for ArcString2 {
+    #[inline]
+    fn default() -> ArcString2 {
+        ArcString2::new()
+    }
+}
+
+impl ops::Deref for ArcString2 {
+    type Target = [char];
+
+    #[inline]
+    fn deref(&self) -> &[char] {
+        &self.inner
+    }
+}
+
+impl AsRef<[char]> for ArcString2 {
+    #[inline]
+    fn as_ref(&self) -> &[char] {
+        &self.inner
+    }
+}
+
+impl Borrow<[char]> for ArcString2 {
+    #[inline]
+    fn borrow(&self) -> &[char] {
+        &self.inner
+    }
+}
+
+impl<'a> From<&'a str> for ArcString2 {
+    #[inline]
+    fn from(string: &'a str) -> ArcString2 {
+        ArcString2 {
+            inner: string.chars().collect()
+        }
+    }
+}
+
+impl From<String2> for ArcString2 {
+    #[inline]
+    fn from(s: String2) -> ArcString2 {
+        s.into_shared()
+    }
+}
+
+impl From<ArcString2> for String2 {
+    #[inline]
+    fn from(s: ArcString2) -> String2 {
+        s.to_mut()
+    }
+}
+
+impl PartialEq for ArcString2 {
+    #[inline]
+    fn eq(&self, other: &ArcString2) -> bool {
+        ArcString2::ptr_eq(self, other) || self.inner == other.inner
+    }
+}
+
+impl_char_string_traits!([] ArcString2);
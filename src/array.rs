@@ -0,0 +1,319 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops;
+
+use crate::String2;
+use crate::LengthError;
+
+/// A string of at most `N` chars, stored in a fixed `[char; N]` buffer.
+///
+/// An `ArrayString2` never allocates, so it can be used where allocating is
+/// not possible or too slow to be predictable, such as in real-time code.
+/// Its API follows the one of `String2`: [`push`], [`insert`] and
+/// [`push_str`] panic when the buffer is full, while [`try_push`],
+/// [`try_insert`] and [`try_push_str`] return a [`LengthError`] and leave
+/// the string untouched.
+///
+/// An `ArrayString2` dereferences to `[char]`, so it can be indexed and
+/// sliced by char index.
+///
+/// [`push`]: #method.push
+/// [`insert`]: #method.insert
+/// [`push_str`]: #method.push_str
+/// [`try_push`]: #method.try_push
+/// [`try_insert`]: #method.try_insert
+/// [`try_push_str`]: #method.try_push_str
+/// [`LengthError`]: struct.LengthError.html
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::ArrayString2;
+///
+/// let mut s = ArrayString2::<5>::new();
+/// s.push_str("héll");
+/// s.push('o');
+///
+/// assert_eq!(s, "héllo");
+/// assert_eq!(s[1], 'é');
+/// assert_eq!(s[1..3], ['é', 'l']);
+///
+/// let err = s.try_push('!').unwrap_err();
+/// assert_eq!(err.attempted_len(), 6);
+/// assert_eq!(s.remove(0), 'h');
+/// assert!(s.try_push('!').is_ok());
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayString2<const N: usize> {
+    buf: [char; N],
+    len: usize
+}
+
+impl<const N: usize> ArrayString2<N> {
+    /// Creates a new empty `ArrayString2`.
+    #[inline]
+    pub const fn new() -> ArrayString2<N> {
+        ArrayString2 {
+            buf: ['\0'; N],
+            len: 0
+        }
+    }
+
+    /// Returns the number of chars this string can hold, which is `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of chars that can still be added.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        N - self.len
+    }
+
+    /// Returns `true` if no more chars can be added.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the chars of this string.
+    #[inline]
+    pub fn as_slice(&self) -> &[char] {
+        &self.buf[..self.len]
+    }
+
+    /// Returns the chars of this string, mutably. Its chars can be changed,
+    /// but not its length.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [char] {
+        &mut self.buf[..self.len]
+    }
+
+    /// Appends a char to the end of this string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is already `N` chars long.
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        self.try_push(ch).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Appends a char to the end of this string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthError`] if the string is already `N` chars long.
+    ///
+    /// [`LengthError`]: struct.LengthError.html
+    #[inline]
+    pub fn try_push(&mut self, ch: char) -> Result<(), LengthError> {
+        self.check(1)?;
+        self.buf[self.len] = ch;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends a string slice to the end of this string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be longer than `N` chars.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.try_push_str(string).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Appends a string slice to the end of this string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthError`] if the result would be longer than `N`
+    /// chars.
+    ///
+    /// [`LengthError`]: struct.LengthError.html
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), LengthError> {
+        self.check(string.chars().count())?;
+        for ch in string.chars() {
+            self.buf[self.len] = ch;
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    /// Inserts a char at char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of this string, or if the
+    /// string is already `N` chars long.
+    #[inline]
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        self.try_insert(idx, ch).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Inserts a char at char index `idx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthError`] if the string is already `N` chars long.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of this string.
+    ///
+    /// [`LengthError`]: struct.LengthError.html
+    pub fn try_insert(&mut self, idx: usize, ch: char) -> Result<(), LengthError> {
+        assert!(idx <= self.len, "insertion index {} is out of bounds", idx);
+        self.check(1)?;
+        self.buf.copy_within(idx..self.len, idx + 1);
+        self.buf[idx] = ch;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the last char and returns it, or `None` if this string is
+    /// empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        self.len = self.len.checked_sub(1)?;
+        Some(self.buf[self.len])
+    }
+
+    /// Removes the char at char index `idx` and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn remove(&mut self, idx: usize) -> char {
+        assert!(idx < self.len, "removal index {} is out of bounds", idx);
+        let ch = self.buf[idx];
+        self.buf.copy_within(idx + 1..self.len, idx);
+        self.len -= 1;
+        ch
+    }
+
+    /// Shortens this string to `new_len` chars, doing nothing if it is
+    /// already shorter.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.len = self.len.min(new_len);
+    }
+
+    /// Removes all chars.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Copies this string into a `String2`.
+    #[inline]
+    pub fn to_string2(&self) -> String2 {
+        String2::from(self.as_slice())
+    }
+
+    fn check(&self, additional: usize) -> Result<(), LengthError> {
+        if additional > self.remaining() {
+            Err(LengthError {
+                max: N,
+                attempted: self.len + additional
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<const N: usize> Default for ArrayString2<N> {
+    #[inline]
+    fn default() -> ArrayString2<N> {
+        ArrayString2::new()
+    }
+}
+
+impl<const N: usize> ops::Deref for ArrayString2<N> {
+    type Target = [char];
+
+    #[inline]
+    fn deref(&self) -> &[char] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> ops::DerefMut for ArrayString2<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [char] {
+        self.as_mut_slice()
+    }
+}
+
+impl<const N: usize> AsRef<[char]> for ArrayString2<N> {
+    #[inline]
+    fn as_ref(&self) -> &[char] {
+        self.as_slice()
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a str> for ArrayString2<N> {
+    type Error = LengthError;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<ArrayString2<N>, LengthError> {
+        let mut array = ArrayString2::new();
+        array.try_push_str(s)?;
+        Ok(array)
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a [char]> for ArrayString2<N> {
+    type Error = LengthError;
+
+    fn try_from(chars: &'a [char]) -> Result<ArrayString2<N>, LengthError> {
+        let mut array = ArrayString2::new();
+        array.check(chars.len())?;
+        array.buf[..chars.len()].copy_from_slice(chars);
+        array.len = chars.len();
+        Ok(array)
+    }
+}
+
+impl<const N: usize> From<ArrayString2<N>> for String2 {
+    #[inline]
+    fn from(s: ArrayString2<N>) -> String2 {
+        s.to_string2()
+    }
+}
+
+impl<const N: usize> Extend<char> for ArrayString2<N> {
+    /// Appends the chars of `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be longer than `N` chars.
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for ch in iter {
+            self.push(ch);
+        }
+    }
+}
+
+impl<const N: usize> fmt::Write for ArrayString2<N> {
+    /// Appends `s`, or returns an error and leaves the string untouched if
+    /// it does not fit.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<ArrayString2<M>> for ArrayString2<N> {
+    #[inline]
+    fn eq(&self, other: &ArrayString2<M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl_char_string_traits!([const N: usize] ArrayString2<N>);
@@ -0,0 +1,208 @@
+use core::future::Future;
+use std::io;
+use core::pin::Pin;
+use core::str;
+use core::task::{Context, Poll};
+use alloc::vec::Vec;
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::String2;
+
+const READ_CHUNK: usize = 8 * 1024;
+
+impl String2 {
+    /// Reads `reader` to the end, decoding its UTF-8 contents into a new
+    /// `String2` as they arrive.
+    ///
+    /// The bytes are decoded chunk by chunk, so only a partial UTF-8
+    /// sequence at the end of a chunk is ever buffered, instead of the whole
+    /// input.
+    ///
+    /// This method requires the `tokio` feature.
+    ///
+    /// # Errors
+    ///
+    /// The future resolves to an error of kind [`InvalidData`] if the input
+    /// is not valid UTF-8, and to any error returned by the reader.
+    ///
+    /// [`InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use std::future::Future;
+    /// # use std::task::{Context, Poll, Waker};
+    /// # fn block_on<F: Future>(fut: F) -> F::Output {
+    /// #     let mut fut = Box::pin(fut);
+    /// #     let mut cx = Context::from_waker(Waker::noop());
+    /// #     loop {
+    /// #         if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+    /// #             return out
+    /// #         }
+    /// #     }
+    /// # }
+    /// use string2::String2;
+    ///
+    /// let mut reader: &[u8] = "héllo, wörld".as_bytes();
+    ///
+    /// let s = block_on(String2::from_async_reader(&mut reader)).unwrap();
+    ///
+    /// assert_eq!(s, String2::from("héllo, wörld"));
+    /// ```
+    #[inline]
+    pub fn from_async_reader<R>(reader: &mut R) -> ReadToString2<'_, R>
+        where R: AsyncRead + Unpin + ?Sized
+    {
+        ReadToString2 {
+            reader,
+            buf: vec![0; READ_CHUNK],
+            pending: 0,
+            output: String2::new()
+        }
+    }
+
+    /// Writes the UTF-8 encoding of this `String2` to `writer`.
+    ///
+    /// The future resolves once every byte has been accepted by the writer;
+    /// it does not flush it.
+    ///
+    /// This method requires the `tokio` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use std::future::Future;
+    /// # use std::task::{Context, Poll, Waker};
+    /// # fn block_on<F: Future>(fut: F) -> F::Output {
+    /// #     let mut fut = Box::pin(fut);
+    /// #     let mut cx = Context::from_waker(Waker::noop());
+    /// #     loop {
+    /// #         if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+    /// #             return out
+    /// #         }
+    /// #     }
+    /// # }
+    /// use string2::String2;
+    ///
+    /// let mut out: Vec<u8> = Vec::new();
+    ///
+    /// block_on(String2::from("héllo").write_utf8_async(&mut out)).unwrap();
+    ///
+    /// assert_eq!(out, "héllo".as_bytes());
+    /// ```
+    #[inline]
+    pub fn write_utf8_async<'a, W>(&self, writer: &'a mut W) -> WriteUtf8<'a, W>
+        where W: AsyncWrite + Unpin + ?Sized
+    {
+        WriteUtf8 {
+            writer,
+            bytes: self.as_bytes(),
+            written: 0
+        }
+    }
+}
+
+/// Future for the [`from_async_reader`] method on `String2`.
+///
+/// [`from_async_reader`]: struct.String2.html#method.from_async_reader
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadToString2<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: Vec<u8>,
+    // Number of bytes at the start of `buf` holding an incomplete UTF-8
+    // sequence left over from the previous read.
+    pending: usize,
+    output: String2
+}
+
+impl<'a, R> Future for ReadToString2<'a, R>
+    where R: AsyncRead + Unpin + ?Sized
+{
+    type Output = io::Result<String2>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<String2>> {
+        let this = self.get_mut();
+
+        loop {
+            let pending = this.pending;
+            let mut read_buf = ReadBuf::new(&mut this.buf[pending..]);
+
+            match Pin::new(&mut *this.reader).poll_read(cx, &mut read_buf) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Ready(Ok(())) => ()
+            }
+
+            let filled = read_buf.filled().len();
+
+            if filled == 0 {
+                if pending > 0 {
+                    return Poll::Ready(Err(invalid_utf8()))
+                }
+
+                return Poll::Ready(Ok(::core::mem::take(&mut this.output)))
+            }
+
+            let end = pending + filled;
+            let valid = match str::from_utf8(&this.buf[..end]) {
+                Ok(s) => s,
+                // An incomplete sequence at the end may be completed by the
+                // next read.
+                Err(err) if err.error_len().is_none() => {
+                    str::from_utf8(&this.buf[..err.valid_up_to()]).unwrap()
+                }
+                Err(_) => return Poll::Ready(Err(invalid_utf8()))
+            };
+
+            let valid_len = valid.len();
+            this.output.push_str(valid);
+            this.buf.copy_within(valid_len..end, 0);
+            this.pending = end - valid_len;
+        }
+    }
+}
+
+/// Future for the [`write_utf8_async`] method on `String2`.
+///
+/// [`write_utf8_async`]: struct.String2.html#method.write_utf8_async
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WriteUtf8<'a, W: ?Sized> {
+    writer: &'a mut W,
+    bytes: Vec<u8>,
+    written: usize
+}
+
+impl<'a, W> Future for WriteUtf8<'a, W>
+    where W: AsyncWrite + Unpin + ?Sized
+{
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        while this.written < this.bytes.len() {
+            let n = match Pin::new(&mut *this.writer).poll_write(cx, &this.bytes[this.written..]) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Ready(Ok(n)) => n
+            };
+
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()))
+            }
+
+            this.written += n;
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+fn invalid_utf8() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+}
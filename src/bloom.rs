@@ -0,0 +1,162 @@
+use core::iter::FromIterator;
+use alloc::vec::Vec;
+
+use crate::String2;
+
+// The bits per string used when the number of strings is only known once
+// they have all been seen, which gives about 1% false positives.
+const DEFAULT_BITS_PER_ITEM: usize = 10;
+
+/// A Bloom filter over strings: a compact set that can tell that a string
+/// is certainly not in it, or that it might be.
+///
+/// A `StringBloom` never forgets a string that was inserted, but may claim
+/// to hold one that was not, at a rate that depends on the bits it is
+/// given per string: about 5% with 6 bits, 1% with 10 and 0.1% with 15.
+/// It is used to skip expensive lookups, in an index or on disk, for
+/// strings that cannot be found there.
+///
+/// Strings are placed by their [`fingerprint128`], which is the same in
+/// every process, so a filter built in one process gives the same answers
+/// in another.
+///
+/// [`fingerprint128`]: struct.String2.html#method.fingerprint128
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{String2, StringBloom};
+///
+/// let seen: StringBloom = vec!["apple", "banana", "cherry"]
+///     .into_iter()
+///     .map(String2::from)
+///     .collect();
+///
+/// assert!(seen.might_contain(&String2::from("banana")));
+///
+/// let mut filter = StringBloom::new(1000, 10);
+/// filter.insert(&String2::from("apple"));
+/// assert!(filter.might_contain(&String2::from("apple")));
+/// assert!(!filter.might_contain(&String2::from("durian")));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringBloom {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32
+}
+
+impl StringBloom {
+    /// Creates an empty filter sized for `expected` strings, with
+    /// `bits_per_item` bits for each of them.
+    ///
+    /// Inserting more strings than expected raises the rate of false
+    /// positives.
+    pub fn new(expected: usize, bits_per_item: usize) -> StringBloom {
+        let num_bits = expected.max(1).saturating_mul(bits_per_item.max(1)).max(64);
+        // The best number of hashes is `bits_per_item * ln 2`.
+        let num_hashes = (bits_per_item * 693 / 1000).clamp(1, 32) as u32;
+
+        StringBloom {
+            bits: vec![0; num_bits.div_ceil(64)],
+            num_bits: num_bits as u64,
+            num_hashes
+        }
+    }
+
+    /// Returns the number of bits of this filter.
+    #[inline]
+    pub fn num_bits(&self) -> u64 {
+        self.num_bits
+    }
+
+    /// Returns the number of bits set for each string.
+    #[inline]
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// Adds `s` to this filter.
+    #[inline]
+    pub fn insert(&mut self, s: &String2) {
+        self.insert_fingerprint(s.fingerprint128());
+    }
+
+    /// Returns `false` if `s` is certainly not in this filter, and `true`
+    /// if it might be.
+    pub fn might_contain(&self, s: &String2) -> bool {
+        let fingerprint = s.fingerprint128();
+        (0..self.num_hashes).all(|idx| {
+            let bit = self.bit(fingerprint, idx);
+            self.bits[(bit / 64) as usize] & (1 << (bit % 64)) != 0
+        })
+    }
+
+    /// Removes all strings from this filter.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bits.iter_mut().for_each(|word| *word = 0);
+    }
+
+    fn from_fingerprints(fingerprints: Vec<u128>) -> StringBloom {
+        let mut filter = StringBloom::new(fingerprints.len(), DEFAULT_BITS_PER_ITEM);
+        for fingerprint in fingerprints {
+            filter.insert_fingerprint(fingerprint);
+        }
+        filter
+    }
+
+    fn insert_fingerprint(&mut self, fingerprint: u128) {
+        for idx in 0..self.num_hashes {
+            let bit = self.bit(fingerprint, idx);
+            self.bits[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+    }
+
+    // The bit set by the `idx`th hash, derived from the two halves of the
+    // fingerprint by double hashing.
+    #[inline]
+    fn bit(&self, fingerprint: u128, idx: u32) -> u64 {
+        let low = fingerprint as u64;
+        let high = (fingerprint >> 64) as u64 | 1;
+        low.wrapping_add(high.wrapping_mul(u64::from(idx))) % self.num_bits
+    }
+}
+
+impl FromIterator<String2> for StringBloom {
+    /// Builds a filter holding the strings of `iter`, with 10 bits for each
+    /// of them.
+    fn from_iter<I: IntoIterator<Item = String2>>(iter: I) -> StringBloom {
+        let fingerprints: Vec<u128> = iter.into_iter().map(|s| s.fingerprint128()).collect();
+        StringBloom::from_fingerprints(fingerprints)
+    }
+}
+
+impl<'a> FromIterator<&'a String2> for StringBloom {
+    /// Builds a filter holding the strings of `iter`, with 10 bits for each
+    /// of them.
+    fn from_iter<I: IntoIterator<Item = &'a String2>>(iter: I) -> StringBloom {
+        let fingerprints: Vec<u128> = iter.into_iter().map(String2::fingerprint128).collect();
+        StringBloom::from_fingerprints(fingerprints)
+    }
+}
+
+impl<'a> Extend<&'a String2> for StringBloom {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a String2>>(&mut self, iter: I) {
+        for s in iter {
+            self.insert(s);
+        }
+    }
+}
+
+impl Extend<String2> for StringBloom {
+    #[inline]
+    fn extend<I: IntoIterator<Item = String2>>(&mut self, iter: I) {
+        for s in iter {
+            self.insert(&s);
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use core::convert::TryFrom;
+use core::error;
+use core::fmt;
+use core::ops;
+
+use crate::String2;
+
+/// The error returned when an operation would make a [`BoundedString2`] or
+/// an [`ArrayString2`] longer than its maximum length.
+///
+/// [`BoundedString2`]: struct.BoundedString2.html
+/// [`ArrayString2`]: struct.ArrayString2.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthError {
+    pub(crate) max: usize,
+    pub(crate) attempted: usize
+}
+
+impl LengthError {
+    /// Returns the maximum length, in chars, that was exceeded.
+    #[inline]
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Returns the length, in chars, that the operation would have
+    /// produced.
+    #[inline]
+    pub fn attempted_len(&self) -> usize {
+        self.attempted
+    }
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "length of {} chars exceeds the maximum of {}", self.attempted, self.max)
+    }
+}
+
+impl error::Error for LengthError {}
+
+/// A `String2` that never holds more than `MAX` chars.
+///
+/// The limit is checked when the string is created and on every mutation.
+/// An operation that would exceed it returns a [`LengthError`] and leaves
+/// the string untouched, instead of silently growing, which makes this type
+/// a good fit for protocol fields and database columns with a fixed size.
+///
+/// A `BoundedString2` dereferences to [`String2`], so all the non-mutating
+/// methods of `String2` are available on it.
+///
+/// [`LengthError`]: struct.LengthError.html
+/// [`String2`]: struct.String2.html
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use string2::BoundedString2;
+///
+/// let mut code = BoundedString2::<4>::try_from("ab").unwrap();
+///
+/// code.push('c').unwrap();
+/// code.push_str("d").unwrap();
+///
+/// let err = code.push('e').unwrap_err();
+/// assert_eq!(err.max(), 4);
+/// assert_eq!(err.attempted_len(), 5);
+///
+/// assert_eq!(code.len(), 4);
+/// assert!(BoundedString2::<4>::try_from("hello").is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct BoundedString2<const MAX: usize> {
+    inner: String2
+}
+
+impl<const MAX: usize> BoundedString2<MAX> {
+    /// The maximum length of this string, in chars.
+    pub const MAX: usize = MAX;
+
+    /// Creates a new empty `BoundedString2`.
+    #[inline]
+    pub fn new() -> BoundedString2<MAX> {
+        BoundedString2 {
+            inner: String2::new()
+        }
+    }
+
+    /// Creates a `BoundedString2` from a `String2`, checking its length.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthError`] if `s` is longer than `MAX` chars.
+    ///
+    /// [`LengthError`]: struct.LengthError.html
+    #[inline]
+    pub fn from_string2(s: String2) -> Result<BoundedString2<MAX>, LengthError> {
+        BoundedString2::<MAX>::check(s.len())?;
+
+        Ok(BoundedString2 {
+            inner: s
+        })
+    }
+
+    /// Returns the number of chars that can still be added.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        MAX - self.inner.len()
+    }
+
+    /// Returns a reference to the underlying `String2`.
+    #[inline]
+    pub fn as_string2(&self) -> &String2 {
+        &self.inner
+    }
+
+    /// Converts this `BoundedString2` into the underlying `String2`.
+    #[inline]
+    pub fn into_string2(self) -> String2 {
+        self.inner
+    }
+
+    /// Appends a char to the end of this string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthError`] if the string is already `MAX` chars long.
+    ///
+    /// [`LengthError`]: struct.LengthError.html
+    #[inline]
+    pub fn push(&mut self, ch: char) -> Result<(), LengthError> {
+        BoundedString2::<MAX>::check(self.inner.len() + 1)?;
+        self.inner.push(ch);
+        Ok(())
+    }
+
+    /// Appends a string slice to the end of this string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthError`] if the result would be longer than `MAX`
+    /// chars, in which case nothing is appended.
+    ///
+    /// [`LengthError`]: struct.LengthError.html
+    #[inline]
+    pub fn push_str(&mut self, string: &str) -> Result<(), LengthError> {
+        BoundedString2::<MAX>::check(self.inner.len() + string.chars().count())?;
+        self.inner.push_str(string);
+        Ok(())
+    }
+
+    /// Inserts a char at char index `idx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthError`] if the string is already `MAX` chars long.
+    ///
+    /// [`LengthError`]: struct.LengthError.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the string's length.
+    #[inline]
+    pub fn insert(&mut self, idx: usize, ch: char) -> Result<(), LengthError> {
+        BoundedString2::<MAX>::check(self.inner.len() + 1)?;
+        self.inner.insert(idx, ch);
+        Ok(())
+    }
+
+    /// Removes the last char and returns it, or `None` if the string is
+    /// empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        self.inner.pop()
+    }
+
+    /// Removes the char at char index `idx` and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> char {
+        self.inner.remove(idx)
+    }
+
+    /// Shortens this string to `new_len` chars.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.inner.truncate(new_len);
+    }
+
+    /// Removes all chars from this string.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Returns a mutable char slice of this string. Its chars can be
+    /// changed, but not its length.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [char] {
+        self.inner.as_mut_slice()
+    }
+
+    #[inline]
+    fn check(len: usize) -> Result<(), LengthError> {
+        if len > MAX {
+            Err(LengthError {
+                max: MAX,
+                attempted: len
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<const MAX: usize> ops::Deref for BoundedString2<MAX> {
+    type Target = String2;
+    #[inline]
+    fn deref(&self) -> &String2 {
+        &self.inner
+    }
+}
+
+impl<const MAX: usize> AsRef<String2> for BoundedString2<MAX> {
+    #[inline]
+    fn as_ref(&self) -> &String2 {
+        &self.inner
+    }
+}
+
+impl<const MAX: usize> AsRef<[char]> for BoundedString2<MAX> {
+    #[inline]
+    fn as_ref(&self) -> &[char] {
+        self.inner.as_ref()
+    }
+}
+
+impl<const MAX: usize> TryFrom<String2> for BoundedString2<MAX> {
+    type Error = LengthError;
+    #[inline]
+    fn try_from(s: String2) -> Result<BoundedString2<MAX>, LengthError> {
+        BoundedString2::from_string2(s)
+    }
+}
+
+impl<'a, const MAX: usize> TryFrom<&'a str> for BoundedString2<MAX> {
+    type Error = LengthError;
+    #[inline]
+    fn try_from(s: &'a str) -> Result<BoundedString2<MAX>, LengthError> {
+        BoundedString2::<MAX>::check(s.chars().count())?;
+        Ok(BoundedString2 {
+            inner: String2::from(s)
+        })
+    }
+}
+
+impl<const MAX: usize> From<BoundedString2<MAX>> for String2 {
+    #[inline]
+    fn from(s: BoundedString2<MAX>) -> String2 {
+        s.inner
+    }
+}
+
+impl<const MAX: usize> fmt::Display for BoundedString2<MAX> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<const MAX: usize> fmt::Debug for BoundedString2<MAX> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
@@ -0,0 +1,76 @@
+use core::str::{self, Utf8Error};
+
+use bytes::Bytes;
+
+use crate::String2;
+
+impl String2 {
+    /// Encodes this `String2` as UTF-8 into a [`Bytes`] buffer, ready to be
+    /// sent over the network.
+    ///
+    /// This method requires the `bytes` feature.
+    ///
+    /// [`Bytes`]: https://docs.rs/bytes/1/bytes/struct.Bytes.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("héllo");
+    ///
+    /// assert_eq!(&s.to_bytes()[..], "héllo".as_bytes());
+    /// ```
+    #[inline]
+    pub fn to_bytes(&self) -> Bytes {
+        Bytes::from(self.as_bytes())
+    }
+
+    /// Decodes a UTF-8 [`Bytes`] buffer into a `String2`.
+    ///
+    /// This method requires the `bytes` feature.
+    ///
+    /// [`Bytes`]: https://docs.rs/bytes/1/bytes/struct.Bytes.html
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Utf8Error`] if `bytes` is not valid UTF-8.
+    ///
+    /// [`Utf8Error`]: https://doc.rust-lang.org/std/str/struct.Utf8Error.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # extern crate bytes;
+    /// # extern crate string2;
+    /// use bytes::Bytes;
+    /// use string2::String2;
+    ///
+    /// let s = String2::from_bytes(Bytes::from_static("héllo".as_bytes())).unwrap();
+    /// assert_eq!(s, String2::from("héllo"));
+    ///
+    /// assert!(String2::from_bytes(Bytes::from_static(&[0xff, 0xfe])).is_err());
+    /// ```
+    #[inline]
+    pub fn from_bytes(bytes: Bytes) -> Result<String2, Utf8Error> {
+        str::from_utf8(&bytes).map(String2::from)
+    }
+}
+
+impl From<String2> for Bytes {
+    #[inline]
+    fn from(s: String2) -> Bytes {
+        s.to_bytes()
+    }
+}
+
+impl<'a> From<&'a String2> for Bytes {
+    #[inline]
+    fn from(s: &'a String2) -> Bytes {
+        s.to_bytes()
+    }
+}
@@ -0,0 +1,646 @@
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::pattern::CharSeq;
+
+/// Maps `ch` to a representative of its simple case folding class, so that
+/// two chars match case-insensitively exactly when their representatives
+/// are equal.
+///
+/// The representative is the single-char lowercase form where there is one,
+/// corrected for the chars whose folding differs from their lowercase.
+pub(crate) fn simple_fold(ch: char) -> char {
+    if ch.is_ascii() {
+        return ch.to_ascii_lowercase()
+    }
+
+    match ch {
+        '\u{00B5}' => '\u{03BC}',
+        '\u{017F}' => 's',
+        '\u{0345}' | '\u{1FBE}' => '\u{03B9}',
+        '\u{03C2}' => '\u{03C3}',
+        '\u{03D0}' => '\u{03B2}',
+        '\u{03D1}' => '\u{03B8}',
+        '\u{03D5}' => '\u{03C6}',
+        '\u{03D6}' => '\u{03C0}',
+        '\u{03F0}' => '\u{03BA}',
+        '\u{03F1}' => '\u{03C1}',
+        '\u{03F5}' => '\u{03B5}',
+        '\u{1C80}' => '\u{0432}',
+        '\u{1C81}' => '\u{0434}',
+        '\u{1C82}' => '\u{043E}',
+        '\u{1C83}' => '\u{0441}',
+        '\u{1C84}' | '\u{1C85}' => '\u{0442}',
+        '\u{1C86}' => '\u{044A}',
+        '\u{1C87}' => '\u{0463}',
+        '\u{1C88}' => '\u{A64B}',
+        '\u{1E9B}' => '\u{1E61}',
+        '\u{1FD3}' => '\u{0390}',
+        '\u{1FE3}' => '\u{03B0}',
+        '\u{FB05}' => '\u{FB06}',
+        _ => {
+            let mut lower = ch.to_lowercase();
+            match (lower.next(), lower.next()) {
+                (Some(lower), None) => lower,
+                _ => ch
+            }
+        }
+    }
+}
+
+/// An iterator over the full case folding of a char.
+///
+/// This struct is created by [`full_fold`].
+#[cfg(feature = "unicode-normalization")]
+pub(crate) struct CaseFold {
+    chars: [char; 3],
+    len: usize,
+    idx: usize
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl Iterator for CaseFold {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if self.idx < self.len {
+            self.idx += 1;
+            Some(self.chars[self.idx - 1])
+        } else {
+            None
+        }
+    }
+}
+
+// The full case foldings that map one char to several, the entries of
+// status `F` in CaseFolding.txt. Every other char folds to the single char
+// given by `simple_fold`. Sorted by char, for binary search.
+#[cfg(feature = "unicode-normalization")]
+static FULL_FOLDS: &[(char, &str)] = &[
+    ('\u{00DF}', "ss"),
+    ('\u{0130}', "i\u{0307}"),
+    ('\u{0149}', "\u{02BC}n"),
+    ('\u{01F0}', "j\u{030C}"),
+    ('\u{0390}', "\u{03B9}\u{0308}\u{0301}"),
+    ('\u{03B0}', "\u{03C5}\u{0308}\u{0301}"),
+    ('\u{0587}', "\u{0565}\u{0582}"),
+    ('\u{1E96}', "h\u{0331}"),
+    ('\u{1E97}', "t\u{0308}"),
+    ('\u{1E98}', "w\u{030A}"),
+    ('\u{1E99}', "y\u{030A}"),
+    ('\u{1E9A}', "a\u{02BE}"),
+    ('\u{1E9E}', "ss"),
+    ('\u{1F50}', "\u{03C5}\u{0313}"),
+    ('\u{1F52}', "\u{03C5}\u{0313}\u{0300}"),
+    ('\u{1F54}', "\u{03C5}\u{0313}\u{0301}"),
+    ('\u{1F56}', "\u{03C5}\u{0313}\u{0342}"),
+    ('\u{1F80}', "\u{1F00}\u{03B9}"),
+    ('\u{1F81}', "\u{1F01}\u{03B9}"),
+    ('\u{1F82}', "\u{1F02}\u{03B9}"),
+    ('\u{1F83}', "\u{1F03}\u{03B9}"),
+    ('\u{1F84}', "\u{1F04}\u{03B9}"),
+    ('\u{1F85}', "\u{1F05}\u{03B9}"),
+    ('\u{1F86}', "\u{1F06}\u{03B9}"),
+    ('\u{1F87}', "\u{1F07}\u{03B9}"),
+    ('\u{1F88}', "\u{1F00}\u{03B9}"),
+    ('\u{1F89}', "\u{1F01}\u{03B9}"),
+    ('\u{1F8A}', "\u{1F02}\u{03B9}"),
+    ('\u{1F8B}', "\u{1F03}\u{03B9}"),
+    ('\u{1F8C}', "\u{1F04}\u{03B9}"),
+    ('\u{1F8D}', "\u{1F05}\u{03B9}"),
+    ('\u{1F8E}', "\u{1F06}\u{03B9}"),
+    ('\u{1F8F}', "\u{1F07}\u{03B9}"),
+    ('\u{1F90}', "\u{1F20}\u{03B9}"),
+    ('\u{1F91}', "\u{1F21}\u{03B9}"),
+    ('\u{1F92}', "\u{1F22}\u{03B9}"),
+    ('\u{1F93}', "\u{1F23}\u{03B9}"),
+    ('\u{1F94}', "\u{1F24}\u{03B9}"),
+    ('\u{1F95}', "\u{1F25}\u{03B9}"),
+    ('\u{1F96}', "\u{1F26}\u{03B9}"),
+    ('\u{1F97}', "\u{1F27}\u{03B9}"),
+    ('\u{1F98}', "\u{1F20}\u{03B9}"),
+    ('\u{1F99}', "\u{1F21}\u{03B9}"),
+    ('\u{1F9A}', "\u{1F22}\u{03B9}"),
+    ('\u{1F9B}', "\u{1F23}\u{03B9}"),
+    ('\u{1F9C}', "\u{1F24}\u{03B9}"),
+    ('\u{1F9D}', "\u{1F25}\u{03B9}"),
+    ('\u{1F9E}', "\u{1F26}\u{03B9}"),
+    ('\u{1F9F}', "\u{1F27}\u{03B9}"),
+    ('\u{1FA0}', "\u{1F60}\u{03B9}"),
+    ('\u{1FA1}', "\u{1F61}\u{03B9}"),
+    ('\u{1FA2}', "\u{1F62}\u{03B9}"),
+    ('\u{1FA3}', "\u{1F63}\u{03B9}"),
+    ('\u{1FA4}', "\u{1F64}\u{03B9}"),
+    ('\u{1FA5}', "\u{1F65}\u{03B9}"),
+    ('\u{1FA6}', "\u{1F66}\u{03B9}"),
+    ('\u{1FA7}', "\u{1F67}\u{03B9}"),
+    ('\u{1FA8}', "\u{1F60}\u{03B9}"),
+    ('\u{1FA9}', "\u{1F61}\u{03B9}"),
+    ('\u{1FAA}', "\u{1F62}\u{03B9}"),
+    ('\u{1FAB}', "\u{1F63}\u{03B9}"),
+    ('\u{1FAC}', "\u{1F64}\u{03B9}"),
+    ('\u{1FAD}', "\u{1F65}\u{03B9}"),
+    ('\u{1FAE}', "\u{1F66}\u{03B9}"),
+    ('\u{1FAF}', "\u{1F67}\u{03B9}"),
+    ('\u{1FB2}', "\u{1F70}\u{03B9}"),
+    ('\u{1FB3}', "\u{03B1}\u{03B9}"),
+    ('\u{1FB4}', "\u{03AC}\u{03B9}"),
+    ('\u{1FB6}', "\u{03B1}\u{0342}"),
+    ('\u{1FB7}', "\u{03B1}\u{0342}\u{03B9}"),
+    ('\u{1FBC}', "\u{03B1}\u{03B9}"),
+    ('\u{1FC2}', "\u{1F74}\u{03B9}"),
+    ('\u{1FC3}', "\u{03B7}\u{03B9}"),
+    ('\u{1FC4}', "\u{03AE}\u{03B9}"),
+    ('\u{1FC6}', "\u{03B7}\u{0342}"),
+    ('\u{1FC7}', "\u{03B7}\u{0342}\u{03B9}"),
+    ('\u{1FCC}', "\u{03B7}\u{03B9}"),
+    ('\u{1FD2}', "\u{03B9}\u{0308}\u{0300}"),
+    ('\u{1FD3}', "\u{03B9}\u{0308}\u{0301}"),
+    ('\u{1FD6}', "\u{03B9}\u{0342}"),
+    ('\u{1FD7}', "\u{03B9}\u{0308}\u{0342}"),
+    ('\u{1FE2}', "\u{03C5}\u{0308}\u{0300}"),
+    ('\u{1FE3}', "\u{03C5}\u{0308}\u{0301}"),
+    ('\u{1FE4}', "\u{03C1}\u{0313}"),
+    ('\u{1FE6}', "\u{03C5}\u{0342}"),
+    ('\u{1FE7}', "\u{03C5}\u{0308}\u{0342}"),
+    ('\u{1FF2}', "\u{1F7C}\u{03B9}"),
+    ('\u{1FF3}', "\u{03C9}\u{03B9}"),
+    ('\u{1FF4}', "\u{03CE}\u{03B9}"),
+    ('\u{1FF6}', "\u{03C9}\u{0342}"),
+    ('\u{1FF7}', "\u{03C9}\u{0342}\u{03B9}"),
+    ('\u{1FFC}', "\u{03C9}\u{03B9}"),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB05}', "st"),
+    ('\u{FB06}', "st"),
+    ('\u{FB13}', "\u{0574}\u{0576}"),
+    ('\u{FB14}', "\u{0574}\u{0565}"),
+    ('\u{FB15}', "\u{0574}\u{056B}"),
+    ('\u{FB16}', "\u{057E}\u{0576}"),
+    ('\u{FB17}', "\u{0574}\u{056D}"),
+];
+
+/// Returns the full case folding of `ch`, which may expand to several
+/// chars, as in `'ß'` folding to `"ss"` and `'ﬀ'` to `"ff"`.
+#[cfg(feature = "unicode-normalization")]
+pub(crate) fn full_fold(ch: char) -> CaseFold {
+    let mut fold = CaseFold {
+        chars: [ch, '\0', '\0'],
+        len: 1,
+        idx: 0
+    };
+
+    match FULL_FOLDS.binary_search_by_key(&ch, |&(from, _)| from) {
+        Ok(idx) => {
+            fold.len = 0;
+            for c in FULL_FOLDS[idx].1.chars() {
+                fold.chars[fold.len] = c;
+                fold.len += 1;
+            }
+        }
+        Err(_) => fold.chars[0] = simple_fold(ch)
+    }
+
+    fold
+}
+
+/// Returns `true` if `ch` has the Unicode `Cased` property: it is
+/// uppercase, lowercase or titlecase.
+pub(crate) fn is_cased(ch: char) -> bool {
+    if ch.is_lowercase() || ch.is_uppercase() {
+        return true
+    }
+
+    // Titlecase letters like 'ǅ' are neither upper- nor lowercase, but
+    // have both an upper- and a lowercase mapping different from
+    // themselves.
+    ch.to_lowercase().next() != Some(ch) && ch.to_uppercase().next() != Some(ch)
+}
+
+/// Returns `true` if `ch` has the Unicode `Case_Ignorable` property, for the
+/// chars that occur in practice: combining marks, format controls, modifier
+/// letters and symbols, and the apostrophes and punctuation that may appear
+/// inside a word.
+pub(crate) fn is_case_ignorable(ch: char) -> bool {
+    matches!(ch,
+        '\'' | '.' | ':' | '^' | '`' | '\u{00A8}' | '\u{00AD}' | '\u{00AF}' | '\u{00B4}'
+        | '\u{00B7}' | '\u{00B8}' | '\u{02B0}'..='\u{036F}' | '\u{0374}' | '\u{0375}'
+        | '\u{037A}' | '\u{0384}' | '\u{0385}' | '\u{0387}' | '\u{0483}'..='\u{0489}'
+        | '\u{0559}' | '\u{055F}' | '\u{0591}'..='\u{05BD}' | '\u{05BF}' | '\u{05C1}'
+        | '\u{05C2}' | '\u{05C4}' | '\u{05C5}' | '\u{05C7}' | '\u{05F4}'
+        | '\u{0610}'..='\u{061A}' | '\u{064B}'..='\u{065F}' | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1D2C}'..='\u{1D6A}' | '\u{1DC0}'..='\u{1DFF}' | '\u{1FBD}' | '\u{1FBF}'..='\u{1FC1}'
+        | '\u{1FCD}'..='\u{1FCF}' | '\u{1FDD}'..='\u{1FDF}' | '\u{1FED}'..='\u{1FEF}'
+        | '\u{1FFD}' | '\u{1FFE}' | '\u{200B}'..='\u{200F}' | '\u{2018}' | '\u{2019}'
+        | '\u{2024}' | '\u{2027}' | '\u{202A}'..='\u{202E}' | '\u{2060}'..='\u{2064}'
+        | '\u{2071}' | '\u{207F}' | '\u{2090}'..='\u{209C}' | '\u{20D0}'..='\u{20F0}'
+        | '\u{2C7C}' | '\u{2C7D}' | '\u{A67C}' | '\u{A67D}' | '\u{A67F}' | '\u{A69C}'
+        | '\u{A69D}' | '\u{A700}'..='\u{A721}' | '\u{A770}' | '\u{A788}'..='\u{A78A}'
+        | '\u{FE00}'..='\u{FE0F}' | '\u{FE13}' | '\u{FE20}'..='\u{FE2F}' | '\u{FE52}'
+        | '\u{FE55}' | '\u{FEFF}' | '\u{FF07}' | '\u{FF0E}' | '\u{FF1A}' | '\u{FF3E}'
+        | '\u{FF40}' | '\u{FF70}' | '\u{FF9E}' | '\u{FF9F}' | '\u{FFE3}'
+        | '\u{E0001}' | '\u{E0020}'..='\u{E007F}' | '\u{E0100}'..='\u{E01EF}'
+    )
+}
+
+/// Returns `true` if the capital sigma at `idx` in `chars` ends a word, and
+/// so lowercases to the final form `'ς'` rather than `'σ'`.
+///
+/// This is the `Final_Sigma` condition of the Unicode Standard, section
+/// 3.13: the sigma is preceded by a cased letter and not followed by one,
+/// skipping case-ignorable chars in both directions.
+pub(crate) fn is_final_sigma(chars: &[char], idx: usize) -> bool {
+    let before = chars[..idx].iter().rev().find(|&&c| !is_case_ignorable(c));
+    let after = chars[idx + 1..].iter().find(|&&c| !is_case_ignorable(c));
+
+    before.is_some_and(|&c| is_cased(c)) && !after.is_some_and(|&c| is_cased(c))
+}
+
+/// Appends the titlecase mapping of `ch` to `out`.
+///
+/// The titlecase form is the uppercase one except for the Latin digraphs,
+/// the Greek letters with an iota subscript, and the chars whose uppercase
+/// form expands to several chars, where only the first one is capitalized,
+/// as in `'ß'` titlecasing to `"Ss"`.
+pub(crate) fn push_titlecase(ch: char, out: &mut Vec<char>) {
+    let title = match ch {
+        '\u{01C4}'..='\u{01C6}' => '\u{01C5}',
+        '\u{01C7}'..='\u{01C9}' => '\u{01C8}',
+        '\u{01CA}'..='\u{01CC}' => '\u{01CB}',
+        '\u{01F1}'..='\u{01F3}' => '\u{01F2}',
+        '\u{1F80}'..='\u{1F87}' | '\u{1F90}'..='\u{1F97}' | '\u{1FA0}'..='\u{1FA7}' => {
+            char::from_u32(ch as u32 + 8).unwrap()
+        }
+        '\u{1F88}'..='\u{1F8F}' | '\u{1F98}'..='\u{1F9F}' | '\u{1FA8}'..='\u{1FAF}'
+        | '\u{1FBC}' | '\u{1FCC}' | '\u{1FFC}' => ch,
+        // Georgian Mkhedruli letters are their own titlecase, even though
+        // they uppercase to Mtavruli.
+        '\u{10D0}'..='\u{10FA}' | '\u{10FD}'..='\u{10FF}' => ch,
+        '\u{1FB3}' => '\u{1FBC}',
+        '\u{1FC3}' => '\u{1FCC}',
+        '\u{1FF3}' => '\u{1FFC}',
+        _ => {
+            let start = out.len();
+            out.extend(ch.to_uppercase());
+
+            if out.len() - start > 1 {
+                match ch {
+                    // The iota subscript stays a combining mark.
+                    '\u{1FB2}' | '\u{1FB4}' | '\u{1FB7}' | '\u{1FC2}' | '\u{1FC4}'
+                    | '\u{1FC7}' | '\u{1FF2}' | '\u{1FF4}' | '\u{1FF7}' => {
+                        *out.last_mut().unwrap() = '\u{0345}';
+                    }
+                    _ if is_cased(out[start]) => {
+                        let rest: Vec<char> = out.drain(start + 1..).collect();
+                        out.extend(rest.into_iter().flat_map(char::to_lowercase));
+                    }
+                    _ => ()
+                }
+            }
+
+            return
+        }
+    };
+
+    out.push(title);
+}
+
+/// Replaces the char at `idx` with the chars of `mapping`, returning how
+/// many were inserted.
+#[inline]
+fn replace_char<I: ExactSizeIterator<Item = char>>(chars: &mut Vec<char>, idx: usize, mut mapping: I) -> usize {
+    let len = mapping.len();
+
+    if len == 1 {
+        chars[idx] = mapping.next().unwrap();
+    } else {
+        chars.splice(idx..idx + 1, mapping);
+    }
+
+    len
+}
+
+#[inline]
+fn eq_ignore_case(a: char, b: char) -> bool {
+    if a.is_ascii() && b.is_ascii() {
+        a.eq_ignore_ascii_case(&b)
+    } else {
+        a == b || simple_fold(a) == simple_fold(b)
+    }
+}
+
+impl String2 {
+    /// Returns the lowercase equivalent of this `String2`, as a new
+    /// `String2`.
+    ///
+    /// 'Lowercase' is defined according to the terms of the Unicode Derived
+    /// Core Property `Lowercase`. Since some chars can expand into multiple
+    /// chars when changing the case, the result may be longer than the
+    /// original. A capital sigma at the end of a word becomes the final form
+    /// `'ς'`, and `'σ'` anywhere else.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("HELLO").to_lowercase(), String2::from("hello"));
+    ///
+    /// // 'İ' lowercases to 'i' followed by a combining dot above.
+    /// assert_eq!(String2::from("İ").to_lowercase().len(), 2);
+    ///
+    /// assert_eq!(String2::from("ὈΔΥΣΣΕΎΣ").to_lowercase(), String2::from("ὀδυσσεύς"));
+    /// ```
+    pub fn to_lowercase(&self) -> String2 {
+        let mut lower = String2::with_capacity(self.len());
+
+        for (idx, &ch) in self.inner.iter().enumerate() {
+            if ch == 'Σ' {
+                lower.push(if is_final_sigma(&self.inner, idx) { 'ς' } else { 'σ' });
+            } else {
+                lower.extend(ch.to_lowercase());
+            }
+        }
+
+        lower
+    }
+
+    /// Returns the uppercase equivalent of this `String2`, as a new
+    /// `String2`.
+    ///
+    /// 'Uppercase' is defined according to the terms of the Unicode Derived
+    /// Core Property `Uppercase`. Since some chars can expand into multiple
+    /// chars when changing the case, the result may be longer than the
+    /// original.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("hello").to_uppercase(), String2::from("HELLO"));
+    /// assert_eq!(String2::from("tschüß").to_uppercase(), String2::from("TSCHÜSS"));
+    /// ```
+    pub fn to_uppercase(&self) -> String2 {
+        let mut upper = String2::with_capacity(self.len());
+
+        for &ch in &self.inner {
+            upper.extend(ch.to_uppercase());
+        }
+
+        upper
+    }
+
+    /// Returns the titlecase equivalent of this `String2`, as a new
+    /// `String2`.
+    ///
+    /// The first cased char of every word is mapped to titlecase, and the
+    /// other chars of the word to lowercase. A word starts after any char
+    /// that is neither alphanumeric nor case-ignorable, so an apostrophe
+    /// inside a word does not start a new one.
+    ///
+    /// Titlecase is the same as uppercase for most chars, but not for the
+    /// Latin digraphs like `'ǆ'`, or for chars whose uppercase form is
+    /// several chars long.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("they're bILL's friends from the UK");
+    ///
+    /// assert_eq!(s.to_titlecase(), String2::from("They're Bill's Friends From The Uk"));
+    ///
+    /// assert_eq!(String2::from("ǆungla").to_titlecase(), String2::from("ǅungla"));
+    /// assert_eq!(String2::from("ßa").to_titlecase(), String2::from("Ssa"));
+    /// ```
+    pub fn to_titlecase(&self) -> String2 {
+        let mut title = Vec::with_capacity(self.len());
+        let mut in_word = false;
+
+        for (idx, &ch) in self.inner.iter().enumerate() {
+            if !in_word && is_cased(ch) {
+                push_titlecase(ch, &mut title);
+                in_word = true;
+                continue
+            }
+
+            if ch == 'Σ' {
+                title.push(if is_final_sigma(&self.inner, idx) { 'ς' } else { 'σ' });
+            } else {
+                title.extend(ch.to_lowercase());
+            }
+
+            if !ch.is_alphanumeric() && !is_case_ignorable(ch) {
+                in_word = false;
+            }
+        }
+
+        String2::from(title)
+    }
+
+    /// Returns a copy of this `String2` with its first char mapped to
+    /// titlecase, and the rest to lowercase.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("hELLO wORLD").capitalize(), String2::from("Hello world"));
+    /// assert_eq!(String2::from("élan").capitalize(), String2::from("Élan"));
+    /// assert_eq!(String2::from("  x").capitalize(), String2::from("  x"));
+    /// ```
+    pub fn capitalize(&self) -> String2 {
+        let mut capitalized = Vec::with_capacity(self.len());
+
+        for (idx, &ch) in self.inner.iter().enumerate() {
+            if idx == 0 {
+                push_titlecase(ch, &mut capitalized);
+            } else if ch == 'Σ' {
+                capitalized.push(if is_final_sigma(&self.inner, idx) { 'ς' } else { 'σ' });
+            } else {
+                capitalized.extend(ch.to_lowercase());
+            }
+        }
+
+        String2::from(capitalized)
+    }
+
+    /// Returns a copy of this `String2` with the case of every cased char
+    /// toggled: uppercase chars become lowercase and lowercase chars become
+    /// uppercase. Titlecase chars like `'ǅ'` become lowercase.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("Hello World").swapcase(), String2::from("hELLO wORLD"));
+    /// assert_eq!(String2::from("straße").swapcase(), String2::from("STRASSE"));
+    /// ```
+    pub fn swapcase(&self) -> String2 {
+        let mut swapped = self.clone();
+        swapped.swapcase_in_place();
+        swapped
+    }
+
+    /// Toggles the case of every cased char of this `String2`, in place.
+    ///
+    /// Chars whose case mapping is a single char are replaced directly;
+    /// only the rare chars that expand, like `'ß'` to `"SS"`, shift the
+    /// rest of the string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("ΣΊΣΥΦΟΣ and sisyphus");
+    /// s.swapcase_in_place();
+    ///
+    /// assert_eq!(s, String2::from("σίσυφος AND SISYPHUS"));
+    /// ```
+    pub fn swapcase_in_place(&mut self) {
+        let mut idx = 0;
+
+        while idx < self.inner.len() {
+            let ch = self.inner[idx];
+
+            if ch == 'Σ' {
+                self.inner[idx] = if is_final_sigma(&self.inner, idx) { 'ς' } else { 'σ' };
+                idx += 1;
+                continue
+            }
+
+            idx += if ch.is_lowercase() {
+                replace_char(&mut self.inner, idx, ch.to_uppercase())
+            } else if is_cased(ch) {
+                replace_char(&mut self.inner, idx, ch.to_lowercase())
+            } else {
+                1
+            };
+        }
+
+        self.recount_byte_len();
+    }
+
+    /// Checks that this `String2` and `other` are an ASCII case-insensitive
+    /// match.
+    ///
+    /// Same as comparing `to_ascii_lowercase` forms of both, but without
+    /// allocating and copying temporaries. Non-ASCII chars must match
+    /// exactly.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("Ferris");
+    ///
+    /// assert!(s.eq_ignore_ascii_case("FERRIS"));
+    /// assert!(s.eq_ignore_ascii_case(&String2::from("fErRiS")));
+    /// assert!(s.eq_ignore_ascii_case(&['f', 'e', 'r', 'r', 'i', 's'][..]));
+    ///
+    /// assert!(!s.eq_ignore_ascii_case("Ferri"));
+    /// assert!(!String2::from("é").eq_ignore_ascii_case("É"));
+    /// ```
+    pub fn eq_ignore_ascii_case<S: CharSeq>(&self, other: S) -> bool {
+        let mut chars = self.inner.iter();
+
+        for o in other.char_seq() {
+            match chars.next() {
+                Some(c) if c.eq_ignore_ascii_case(&o) => (),
+                _ => return false
+            }
+        }
+
+        chars.next().is_none()
+    }
+
+    /// Returns `true` if `prefix` is a prefix of this `String2`, ignoring
+    /// case.
+    ///
+    /// ASCII chars are compared directly; other chars are compared by their
+    /// Unicode simple case folding, so `'ſ'` matches `'s'` and `'ς'` matches
+    /// `'Σ'`. No allocation is performed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let header = String2::from("Content-Type: text/plain");
+    ///
+    /// assert!(header.starts_with_ignore_case("content-type:"));
+    /// assert!(!header.starts_with_ignore_case("content-length:"));
+    ///
+    /// assert!(String2::from("ΣΊΣΥΦΟΣ").starts_with_ignore_case("σίσυφος"));
+    /// ```
+    pub fn starts_with_ignore_case<S: CharSeq>(&self, prefix: S) -> bool {
+        let mut chars = self.inner.iter();
+
+        for p in prefix.char_seq() {
+            match chars.next() {
+                Some(&c) if eq_ignore_case(c, p) => (),
+                _ => return false
+            }
+        }
+
+        true
+    }
+
+    /// Returns `true` if `suffix` is a suffix of this `String2`, ignoring
+    /// case.
+    ///
+    /// Chars are compared as with [`starts_with_ignore_case`].
+    ///
+    /// [`starts_with_ignore_case`]: #method.starts_with_ignore_case
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let host = String2::from("www.Example.COM");
+    ///
+    /// assert!(host.ends_with_ignore_case(".example.com"));
+    /// assert!(!host.ends_with_ignore_case(".example.org"));
+    /// ```
+    pub fn ends_with_ignore_case<S: CharSeq>(&self, suffix: S) -> bool {
+        let mut chars = self.inner.iter().rev();
+
+        for p in suffix.char_seq().rev() {
+            match chars.next() {
+                Some(&c) if eq_ignore_case(c, p) => (),
+                _ => return false
+            }
+        }
+
+        true
+    }
+}
@@ -0,0 +1,127 @@
+use core::iter::{Enumerate, FusedIterator};
+use core::slice;
+
+/// A borrowing iterator over the chars of a `String2`.
+///
+/// This struct is created by the [`chars`] method on `String2`, and by
+/// iterating over a `&String2`.
+///
+/// [`chars`]: struct.String2.html#method.chars
+#[derive(Clone, Debug)]
+pub struct Chars<'a> {
+    inner: slice::Iter<'a, char>
+}
+
+impl<'a> Chars<'a> {
+    #[inline]
+    pub(crate) fn new(chars: &'a [char]) -> Chars<'a> {
+        Chars {
+            inner: chars.iter()
+        }
+    }
+
+    /// Views the remaining chars of this iterator as a char slice.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("abc");
+    /// let mut chars = s.chars();
+    ///
+    /// chars.next();
+    ///
+    /// assert_eq!(&['b', 'c'][..], chars.as_slice());
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &'a [char] {
+        self.inner.as_slice()
+    }
+}
+
+impl<'a> Iterator for Chars<'a> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.inner.next().cloned()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<char> {
+        self.inner.nth(n).cloned()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<char> {
+        self.next_back()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Chars<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        self.inner.next_back().cloned()
+    }
+}
+
+impl<'a> ExactSizeIterator for Chars<'a> {}
+
+impl<'a> FusedIterator for Chars<'a> {}
+
+/// An iterator over the chars of a `String2`, and their positions.
+///
+/// This struct is created by the [`char_indices`] method on `String2`.
+///
+/// [`char_indices`]: struct.String2.html#method.char_indices
+#[derive(Clone, Debug)]
+pub struct CharIndices<'a> {
+    inner: Enumerate<slice::Iter<'a, char>>
+}
+
+impl<'a> CharIndices<'a> {
+    #[inline]
+    pub(crate) fn new(chars: &'a [char]) -> CharIndices<'a> {
+        CharIndices {
+            inner: chars.iter().enumerate()
+        }
+    }
+}
+
+impl<'a> Iterator for CharIndices<'a> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        self.inner.next().map(|(idx, &ch)| (idx, ch))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for CharIndices<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, char)> {
+        self.inner.next_back().map(|(idx, &ch)| (idx, ch))
+    }
+}
+
+impl<'a> ExactSizeIterator for CharIndices<'a> {}
+
+impl<'a> FusedIterator for CharIndices<'a> {}
@@ -0,0 +1,180 @@
+use core::ops::{Bound, RangeBounds};
+use alloc::vec::Vec;
+
+use crate::String2;
+
+// Turns `range` into a half-open `(start, end)` pair; an unbounded end is
+// `usize::MAX`.
+fn bounds<R: RangeBounds<usize>>(range: &R) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.saturating_add(1),
+        Bound::Unbounded => 0
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.saturating_add(1),
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => usize::MAX
+    };
+
+    (start, end)
+}
+
+impl String2 {
+    /// Returns the char at column `col` of each line, or `None` for lines
+    /// that are too short.
+    ///
+    /// Lines are split like [`lines`], and columns count chars from `0`.
+    ///
+    /// [`lines`]: #method.lines
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let text = String2::from("abc\nd\nefg");
+    ///
+    /// assert_eq!(text.nth_column_of_lines(1), [Some('b'), None, Some('f')]);
+    /// ```
+    pub fn nth_column_of_lines(&self, col: usize) -> Vec<Option<char>> {
+        self.lines().map(|line| line.get(col).cloned()).collect()
+    }
+
+    /// Returns the chars in the column range `range` of each line, as in a
+    /// rectangular selection.
+    ///
+    /// Lines that end inside the range give only the chars they have, and
+    /// lines that end before it give an empty `String2`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let text = String2::from("let a = 1;\nlet bc = 2;\nx");
+    /// let block = text.column_range_of_lines(4..6);
+    ///
+    /// assert_eq!(block, [String2::from("a "), String2::from("bc"), String2::new()]);
+    /// ```
+    pub fn column_range_of_lines<R: RangeBounds<usize>>(&self, range: R) -> Vec<String2> {
+        let (start, end) = bounds(&range);
+
+        self.lines()
+            .map(|line| {
+                let end = end.min(line.len());
+                let start = start.min(end);
+                String2::from(&line[start..end])
+            })
+            .collect()
+    }
+
+    /// Inserts `text` at column `col` of each line in the line range
+    /// `lines`, as in a rectangular (block) insertion.
+    ///
+    /// Lines shorter than `col` are padded with spaces first, and line
+    /// numbers past the last line are ignored. The string is rebuilt in a
+    /// single pass.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut text = String2::from("one\ntwo\nthree\r\nfour");
+    /// text.insert_block(1..3, 3, "!");
+    ///
+    /// assert_eq!(text, String2::from("one\ntwo!\nthr!ee\r\nfour"));
+    ///
+    /// let mut code = String2::from("a\nbb\n");
+    /// code.insert_block(.., 2, "// ");
+    ///
+    /// assert_eq!(code, String2::from("a // \nbb// \n"));
+    /// ```
+    pub fn insert_block<R: RangeBounds<usize>>(&mut self, lines: R, col: usize, text: &str) {
+        let (first, last) = bounds(&lines);
+        let text: Vec<char> = text.chars().collect();
+
+        self.rebuild_lines(|idx, line, out| {
+            out.extend_from_slice(line);
+
+            if idx >= first && idx < last {
+                let len = out.len();
+                let at = len - line.len() + col;
+
+                if at > len {
+                    out.resize(at, ' ');
+                }
+                out.splice(at..at, text.iter().cloned());
+            }
+        });
+    }
+
+    /// Deletes the chars in the column range `cols` of each line in the
+    /// line range `lines`, as in a rectangular (block) deletion.
+    ///
+    /// Lines that end inside the column range lose only the chars they
+    /// have. The string is rebuilt in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut text = String2::from("// one\n// two\n/\nkeep");
+    /// text.delete_block(..3, 0..3);
+    ///
+    /// assert_eq!(text, String2::from("one\ntwo\n\nkeep"));
+    /// ```
+    pub fn delete_block<R, C>(&mut self, lines: R, cols: C)
+        where R: RangeBounds<usize>, C: RangeBounds<usize>
+    {
+        let (first, last) = bounds(&lines);
+        let (start, end) = bounds(&cols);
+
+        self.rebuild_lines(|idx, line, out| {
+            if idx >= first && idx < last {
+                let end = end.min(line.len());
+                let start = start.min(end);
+
+                out.extend_from_slice(&line[..start]);
+                out.extend_from_slice(&line[end..]);
+            } else {
+                out.extend_from_slice(line);
+            }
+        });
+    }
+
+    // Rebuilds the string line by line: `edit` receives the index and
+    // contents of each line, without its ending, and pushes its new contents
+    // onto the output; the line ending is then added back.
+    fn rebuild_lines<F>(&mut self, mut edit: F)
+        where F: FnMut(usize, &[char], &mut Vec<char>)
+    {
+        let mut out = Vec::with_capacity(self.inner.len());
+
+        for (idx, line) in self.lines_with_terminators().enumerate() {
+            let mut content = line;
+            if content.last() == Some(&'\n') {
+                content = &content[..content.len() - 1];
+                if content.last() == Some(&'\r') {
+                    content = &content[..content.len() - 1];
+                }
+            }
+
+            edit(idx, content, &mut out);
+            out.extend_from_slice(&line[content.len()..]);
+        }
+
+        self.inner = out;
+        self.recount_byte_len();
+    }
+}
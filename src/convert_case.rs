@@ -0,0 +1,220 @@
+use core::iter::FusedIterator;
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::case::{is_final_sigma, push_titlecase};
+
+/// An iterator over the words of an identifier or phrase, as char slices.
+///
+/// This struct is created by the [`split_identifier_words`] method on
+/// `String2`.
+///
+/// [`split_identifier_words`]: struct.String2.html#method.split_identifier_words
+#[derive(Clone, Debug)]
+pub struct IdentifierWords<'a> {
+    chars: &'a [char],
+    pos: usize
+}
+
+impl<'a> IdentifierWords<'a> {
+    // Whether a new word starts at `idx`, inside a run of alphanumerics.
+    fn is_boundary(&self, idx: usize) -> bool {
+        let (prev, ch) = (self.chars[idx - 1], self.chars[idx]);
+
+        ch.is_uppercase() && (
+            prev.is_lowercase() || prev.is_numeric()
+            || (prev.is_uppercase() && self.chars.get(idx + 1).is_some_and(|c| c.is_lowercase()))
+        )
+    }
+}
+
+impl<'a> Iterator for IdentifierWords<'a> {
+    type Item = &'a [char];
+
+    fn next(&mut self) -> Option<&'a [char]> {
+        let chars = self.chars;
+
+        let start = self.pos + chars[self.pos..].iter().take_while(|ch| !ch.is_alphanumeric()).count();
+        if start == chars.len() {
+            self.pos = start;
+            return None
+        }
+
+        let mut end = start + 1;
+        while end < chars.len() && chars[end].is_alphanumeric() && !self.is_boundary(end) {
+            end += 1;
+        }
+
+        self.pos = end;
+        Some(&chars[start..end])
+    }
+}
+
+impl<'a> FusedIterator for IdentifierWords<'a> {}
+
+fn push_lowercase(word: &[char], out: &mut Vec<char>) {
+    for (idx, &ch) in word.iter().enumerate() {
+        if ch == 'Σ' {
+            out.push(if is_final_sigma(word, idx) { 'ς' } else { 'σ' });
+        } else {
+            out.extend(ch.to_lowercase());
+        }
+    }
+}
+
+fn push_uppercase(word: &[char], out: &mut Vec<char>) {
+    for &ch in word {
+        out.extend(ch.to_uppercase());
+    }
+}
+
+fn push_capitalized(word: &[char], out: &mut Vec<char>) {
+    if let Some((&first, rest)) = word.split_first() {
+        push_titlecase(first, out);
+        push_lowercase(rest, out);
+    }
+}
+
+fn join<F>(chars: &[char], separator: Option<char>, mut push_word: F) -> String2
+    where F: FnMut(usize, &[char], &mut Vec<char>)
+{
+    let mut out = Vec::with_capacity(chars.len());
+
+    let words = IdentifierWords { chars, pos: 0 };
+
+    for (idx, word) in words.enumerate() {
+        if idx > 0 {
+            if let Some(separator) = separator {
+                out.push(separator);
+            }
+        }
+
+        push_word(idx, word, &mut out);
+    }
+
+    String2::from(out)
+}
+
+impl String2 {
+    /// An iterator over the words of this `String2` seen as an identifier
+    /// or phrase, as char slices.
+    ///
+    /// Any char that is not alphanumeric separates words and is dropped. A
+    /// new word also starts at an uppercase char that follows a lowercase
+    /// char or a digit (`"fooBar"`, `"v2Beta"`), and at the last uppercase
+    /// char of an acronym that is followed by a lowercase char
+    /// (`"HTTPServer"`). These are the words that the case conversions
+    /// like [`to_snake_case`] work on.
+    ///
+    /// [`to_snake_case`]: #method.to_snake_case
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("parseHTTPServer_v2Beta");
+    /// let words: Vec<String2> = s.split_identifier_words().map(String2::from).collect();
+    ///
+    /// assert_eq!(words, ["parse", "HTTP", "Server", "v2", "Beta"]);
+    /// ```
+    #[inline]
+    pub fn split_identifier_words(&self) -> IdentifierWords<'_> {
+        IdentifierWords {
+            chars: &self.inner,
+            pos: 0
+        }
+    }
+
+    /// Converts this `String2` to `snake_case`.
+    ///
+    /// Words are split at separators, at case changes and around acronyms,
+    /// then lowercased and joined with underscores.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("HTTPServerError").to_snake_case(), String2::from("http_server_error"));
+    /// assert_eq!(String2::from("parse XML-file v2Beta").to_snake_case(), String2::from("parse_xml_file_v2_beta"));
+    /// assert_eq!(String2::from("ÉtéChaud").to_snake_case(), String2::from("été_chaud"));
+    /// ```
+    pub fn to_snake_case(&self) -> String2 {
+        join(&self.inner, Some('_'), |_, word, out| push_lowercase(word, out))
+    }
+
+    /// Converts this `String2` to `kebab-case`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("userIDToken").to_kebab_case(), String2::from("user-id-token"));
+    /// ```
+    pub fn to_kebab_case(&self) -> String2 {
+        join(&self.inner, Some('-'), |_, word, out| push_lowercase(word, out))
+    }
+
+    /// Converts this `String2` to `SCREAMING_SNAKE_CASE`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("maxRetryCount").to_screaming_snake_case(), String2::from("MAX_RETRY_COUNT"));
+    /// ```
+    pub fn to_screaming_snake_case(&self) -> String2 {
+        join(&self.inner, Some('_'), |_, word, out| push_uppercase(word, out))
+    }
+
+    /// Converts this `String2` to `camelCase`.
+    ///
+    /// The first word is lowercased, and the others are capitalized, so
+    /// acronyms are only capitalized on their first letter.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("XML http_request").to_camel_case(), String2::from("xmlHttpRequest"));
+    /// ```
+    pub fn to_camel_case(&self) -> String2 {
+        join(&self.inner, None, |idx, word, out| {
+            if idx == 0 {
+                push_lowercase(word, out);
+            } else {
+                push_capitalized(word, out);
+            }
+        })
+    }
+
+    /// Converts this `String2` to `PascalCase`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("xml_http_request").to_pascal_case(), String2::from("XmlHttpRequest"));
+    /// assert_eq!(String2::from("ǆungla tours").to_pascal_case(), String2::from("ǅunglaTours"));
+    /// ```
+    pub fn to_pascal_case(&self) -> String2 {
+        join(&self.inner, None, |_, word, out| push_capitalized(word, out))
+    }
+}
@@ -0,0 +1,104 @@
+//! Database interop: `String2` columns are stored and loaded through their
+//! UTF-8 form, exactly like `String` columns.
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use alloc::string::String;
+
+    use sqlx::{Database, Decode, Encode, Type};
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+
+    use crate::String2;
+
+    /// `String2` has the same SQL type as `String` for every database.
+    ///
+    /// This impl requires the `sqlx` feature.
+    impl<DB: Database> Type<DB> for String2
+        where String: Type<DB>
+    {
+        #[inline]
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        #[inline]
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    /// Encodes a `String2` as its UTF-8 form.
+    ///
+    /// This impl requires the `sqlx` feature.
+    impl<'q, DB: Database> Encode<'q, DB> for String2
+        where String: Encode<'q, DB>
+    {
+        #[inline]
+        fn encode_by_ref(&self, buf: &mut <DB as Database>::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+            <String as Encode<'q, DB>>::encode(String::from(self), buf)
+        }
+    }
+
+    /// Decodes a `String2` from a text value.
+    ///
+    /// This impl requires the `sqlx` feature.
+    impl<'r, DB: Database> Decode<'r, DB> for String2
+        where &'r str: Decode<'r, DB>
+    {
+        #[inline]
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<String2, BoxDynError> {
+            <&'r str as Decode<'r, DB>>::decode(value).map(String2::from)
+        }
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite_impls {
+    use alloc::string::String;
+
+    use rusqlite::types::{FromSql, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+    use crate::String2;
+
+    /// Binds a `String2` as a `TEXT` value.
+    ///
+    /// This impl requires the `rusqlite` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # extern crate rusqlite;
+    /// # extern crate string2;
+    /// use rusqlite::Connection;
+    /// use string2::String2;
+    ///
+    /// let conn = Connection::open_in_memory().unwrap();
+    /// conn.execute("CREATE TABLE t (name TEXT)", ()).unwrap();
+    ///
+    /// let name = String2::from("héllo");
+    /// conn.execute("INSERT INTO t (name) VALUES (?1)", [&name]).unwrap();
+    ///
+    /// let loaded: String2 = conn.query_row("SELECT name FROM t", (), |row| row.get(0)).unwrap();
+    ///
+    /// assert_eq!(loaded, name);
+    /// ```
+    impl ToSql for String2 {
+        #[inline]
+        fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+            Ok(ToSqlOutput::from(String::from(self)))
+        }
+    }
+
+    /// Reads a `String2` from a `TEXT` value.
+    ///
+    /// This impl requires the `rusqlite` feature.
+    impl FromSql for String2 {
+        #[inline]
+        fn column_result(value: ValueRef<'_>) -> FromSqlResult<String2> {
+            value.as_str().map(String2::from)
+        }
+    }
+}
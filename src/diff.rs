@@ -0,0 +1,94 @@
+use alloc::vec::Vec;
+
+/// Returns the pairs of indices `(i, j)` at which `a[i] == b[j]` in a
+/// longest common subsequence of `a` and `b`, in increasing order.
+///
+/// This is Myers' O(ND) algorithm, after stripping the common prefix and
+/// suffix, so inputs that are mostly equal are cheap to compare.
+pub(crate) fn matching_pairs<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let prefix = a.iter().zip(b).take_while(|&(x, y)| x == y).count();
+    let suffix = a[prefix..].iter().rev().zip(b[prefix..].iter().rev()).take_while(|&(x, y)| x == y).count();
+
+    let mut pairs: Vec<(usize, usize)> = (0..prefix).map(|i| (i, i)).collect();
+
+    let mid_a = &a[prefix..a.len() - suffix];
+    let mid_b = &b[prefix..b.len() - suffix];
+    pairs.extend(myers(mid_a, mid_b).into_iter().map(|(i, j)| (i + prefix, j + prefix)));
+
+    let (start_a, start_b) = (a.len() - suffix, b.len() - suffix);
+    pairs.extend((0..suffix).map(|i| (start_a + i, start_b + i)));
+
+    pairs
+}
+
+fn myers<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    if n == 0 || m == 0 {
+        return Vec::new()
+    }
+
+    let max = n + m;
+    let offset = max as usize;
+    let mut v = vec![0isize; 2 * offset + 2];
+    // The furthest reaching x of each diagonal `k` in `-d..=d` after round
+    // `d`, kept to walk the path back.
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'search: for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let idx = (k + offset as isize) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+
+            v[idx] = x;
+
+            if x >= n && y >= m {
+                trace.push(v[offset - d as usize..=offset + d as usize].to_vec());
+                break 'search
+            }
+        }
+
+        trace.push(v[offset - d as usize..=offset + d as usize].to_vec());
+    }
+
+    let mut pairs = Vec::new();
+    let (mut x, mut y) = (n, m);
+
+    for d in (1..trace.len() as isize).rev() {
+        let prev = &trace[d as usize - 1];
+        // `prev` holds diagonals `-(d - 1)..=(d - 1)`.
+        let at = |k: isize| prev[(k + d - 1) as usize];
+
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) { k + 1 } else { k - 1 };
+        let prev_x = at(prev_k);
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            pairs.push((x as usize, y as usize));
+        }
+
+        x = prev_x;
+        y = prev_y;
+    }
+
+    while x > 0 && y > 0 {
+        x -= 1;
+        y -= 1;
+        pairs.push((x as usize, y as usize));
+    }
+
+    pairs.reverse();
+    pairs
+}
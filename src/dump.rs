@@ -0,0 +1,112 @@
+use core::fmt::Write;
+use alloc::vec::Vec;
+use alloc::string::{String, ToString};
+
+use crate::String2;
+
+const BYTES_PER_LINE: usize = 16;
+
+impl String2 {
+    /// Returns an `xxd`-style hex dump of the UTF-8 encoding of this
+    /// `String2`.
+    ///
+    /// Each line shows the byte offset, sixteen bytes in groups of two, and
+    /// the printable ASCII form of those bytes, with `.` standing for
+    /// anything else. This makes it easy to see how a string is actually
+    /// encoded when two strings that look the same compare unequal.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("Héllo, wörld!\n");
+    ///
+    /// assert_eq!(
+    ///     s.to_hex_dump(),
+    ///     String2::from(
+    ///         "00000000: 48c3 a96c 6c6f 2c20 77c3 b672 6c64 210a  H..llo, w..rld!.\n"
+    ///     )
+    /// );
+    /// ```
+    pub fn to_hex_dump(&self) -> String2 {
+        let bytes = self.as_bytes();
+        let mut out = String::new();
+
+        for (line, chunk) in bytes.chunks(BYTES_PER_LINE).enumerate() {
+            let _ = write!(out, "{:08x}:", line * BYTES_PER_LINE);
+
+            for idx in 0..BYTES_PER_LINE {
+                if idx % 2 == 0 {
+                    out.push(' ');
+                }
+
+                match chunk.get(idx) {
+                    Some(byte) => {
+                        let _ = write!(out, "{:02x}", byte);
+                    }
+                    None => out.push_str("  ")
+                }
+            }
+
+            out.push_str("  ");
+            out.extend(chunk.iter().map(|&byte| {
+                if byte == b' ' || byte.is_ascii_graphic() {
+                    byte as char
+                } else {
+                    '.'
+                }
+            }));
+            out.push('\n');
+        }
+
+        String2::from(out)
+    }
+
+    /// Returns a multi-line listing of the chars of this `String2`, one per
+    /// line, with the char index, the char as a Rust literal, its code
+    /// point and its UTF-8 bytes.
+    ///
+    /// Chars are escaped like in `Debug` output, so invisible and combining
+    /// chars show up. This is the quickest way to find out why two strings
+    /// that look the same compare unequal.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let composed = String2::from("é");
+    /// let decomposed = String2::from("e\u{301}");
+    ///
+    /// assert_eq!(composed.inspect(), "0  'é'  U+00E9  c3 a9\n");
+    /// assert_eq!(
+    ///     decomposed.inspect(),
+    ///     "0  'e'        U+0065  65\n\
+    ///      1  '\\u{301}'  U+0301  cc 81\n"
+    /// );
+    /// ```
+    pub fn inspect(&self) -> String2 {
+        let literals: Vec<String> = self.inner.iter().map(|ch| format!("{:?}", ch)).collect();
+        let index_width = self.inner.len().saturating_sub(1).to_string().len();
+        let literal_width = literals.iter().map(|lit| lit.chars().count()).max().unwrap_or(0);
+
+        let mut out = String2::new();
+
+        for (idx, (&ch, literal)) in self.inner.iter().zip(&literals).enumerate() {
+            let _ = write!(out, "{:>iw$}  {:<lw$}  U+{:04X} ", idx, literal, ch as u32, iw = index_width, lw = literal_width);
+
+            for byte in ch.encode_utf8(&mut [0; 4]).bytes() {
+                let _ = write!(out, " {:02x}", byte);
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+}
@@ -0,0 +1,327 @@
+use core::error;
+use core::fmt;
+use alloc::vec::Vec;
+
+use crate::String2;
+
+/// The identifier of a char in an [`EditLog2`].
+///
+/// It pairs a Lamport clock value with the replica that inserted the char,
+/// which makes it unique across replicas and gives all ids a total order.
+///
+/// [`EditLog2`]: struct.EditLog2.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CharId {
+    /// The Lamport clock value of the insertion.
+    pub counter: u64,
+    /// The replica that made the insertion.
+    pub replica: u64
+}
+
+/// A position-stable edit, as recorded by an [`EditLog2`].
+///
+/// Operations refer to chars by their [`CharId`] rather than by index, so
+/// they stay meaningful when applied on a replica that has seen other
+/// edits in the meantime.
+///
+/// [`EditLog2`]: struct.EditLog2.html
+/// [`CharId`]: struct.CharId.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EditOp {
+    /// Inserts `ch`, with the id `id`, right after the char `after`, or at
+    /// the start if `after` is `None`.
+    Insert {
+        /// The id of the new char.
+        id: CharId,
+        /// The char it is inserted after.
+        after: Option<CharId>,
+        /// The inserted char.
+        ch: char
+    },
+    /// Deletes the char `id`, leaving a tombstone in its place.
+    Delete {
+        /// The id of the deleted char.
+        id: CharId
+    }
+}
+
+/// The error returned when an [`EditOp`] refers to a char that the
+/// [`EditLog2`] has not seen yet.
+///
+/// Operations must be applied in causal order: an insertion after the
+/// insertion of the char it follows, and a deletion after the insertion of
+/// the char it deletes.
+///
+/// [`EditOp`]: enum.EditOp.html
+/// [`EditLog2`]: struct.EditLog2.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownCharError {
+    id: CharId
+}
+
+impl UnknownCharError {
+    /// Returns the id of the unknown char.
+    #[inline]
+    pub fn id(&self) -> CharId {
+        self.id
+    }
+}
+
+impl fmt::Display for UnknownCharError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown char {}@{}", self.id.counter, self.id.replica)
+    }
+}
+
+impl error::Error for UnknownCharError {}
+
+/// A text buffer that records its edits as operations for CRDT sync.
+///
+/// Every char gets a [`CharId`] when it is inserted, and deleted chars stay
+/// behind as tombstones, so edits can be described by id instead of by
+/// index. Local edits are recorded as [`EditOp`]s, which can be sent to
+/// other replicas with [`take_ops`] and applied there with [`apply`]. This
+/// is a replicated growable array (RGA): replicas that have applied the
+/// same operations, in any causal order, hold the same text.
+///
+/// Each replica must use a distinct replica id.
+///
+/// [`CharId`]: struct.CharId.html
+/// [`EditOp`]: enum.EditOp.html
+/// [`take_ops`]: #method.take_ops
+/// [`apply`]: #method.apply
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::EditLog2;
+///
+/// let mut alice = EditLog2::new(1);
+/// alice.insert_str(0, "hello");
+///
+/// let mut bob = EditLog2::new(2);
+/// for op in alice.take_ops() {
+///     bob.apply(&op).unwrap();
+/// }
+///
+/// // Concurrent edits.
+/// alice.insert_str(5, " world");
+/// bob.remove(0);
+/// bob.insert(0, 'H');
+///
+/// let (from_alice, from_bob) = (alice.take_ops(), bob.take_ops());
+/// for op in &from_bob {
+///     alice.apply(op).unwrap();
+/// }
+/// for op in &from_alice {
+///     bob.apply(op).unwrap();
+/// }
+///
+/// assert_eq!(alice.to_string2(), "Hello world");
+/// assert_eq!(bob.to_string2(), "Hello world");
+/// ```
+#[derive(Clone, Debug)]
+pub struct EditLog2 {
+    replica: u64,
+    clock: u64,
+    // Every char ever inserted, tombstones included, in document order.
+    ids: Vec<CharId>,
+    chars: Vec<char>,
+    deleted: Vec<bool>,
+    pending: Vec<EditOp>
+}
+
+impl EditLog2 {
+    /// Creates a new empty `EditLog2` for the replica `replica`.
+    #[inline]
+    pub fn new(replica: u64) -> EditLog2 {
+        EditLog2 {
+            replica,
+            clock: 0,
+            ids: Vec::new(),
+            chars: Vec::new(),
+            deleted: Vec::new(),
+            pending: Vec::new()
+        }
+    }
+
+    /// Returns the replica id of this `EditLog2`.
+    #[inline]
+    pub fn replica(&self) -> u64 {
+        self.replica
+    }
+
+    /// Returns the number of chars in the text, not counting tombstones.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.deleted.iter().filter(|&&d| !d).count()
+    }
+
+    /// Returns `true` if the text holds no chars.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.deleted.iter().all(|&d| d)
+    }
+
+    /// Returns the current text.
+    pub fn to_string2(&self) -> String2 {
+        self.chars.iter()
+            .zip(&self.deleted)
+            .filter(|&(_, &deleted)| !deleted)
+            .map(|(&ch, _)| ch)
+            .collect()
+    }
+
+    /// Inserts a char at char index `idx` of the text, and records the
+    /// operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the text's length.
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        let after = if idx == 0 {
+            None
+        } else {
+            Some(self.ids[self.physical(idx - 1)])
+        };
+
+        self.clock += 1;
+        let op = EditOp::Insert {
+            id: CharId {
+                counter: self.clock,
+                replica: self.replica
+            },
+            after,
+            ch
+        };
+
+        self.integrate(&op).unwrap();
+        self.pending.push(op);
+    }
+
+    /// Inserts a string slice at char index `idx` of the text, and records
+    /// the operations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the text's length.
+    pub fn insert_str(&mut self, idx: usize, string: &str) {
+        for (offset, ch) in string.chars().enumerate() {
+            self.insert(idx + offset, ch);
+        }
+    }
+
+    /// Removes the char at char index `idx` of the text and returns it, and
+    /// records the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn remove(&mut self, idx: usize) -> char {
+        let pos = self.physical(idx);
+        let op = EditOp::Delete {
+            id: self.ids[pos]
+        };
+
+        self.deleted[pos] = true;
+        self.pending.push(op);
+        self.chars[pos]
+    }
+
+    /// Applies an operation received from another replica.
+    ///
+    /// Operations that were already applied are ignored, so delivering one
+    /// more than once is harmless. The operation is not recorded for
+    /// [`take_ops`].
+    ///
+    /// [`take_ops`]: #method.take_ops
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnknownCharError`] if the operation refers to a char
+    /// this replica has not seen yet.
+    ///
+    /// [`UnknownCharError`]: struct.UnknownCharError.html
+    pub fn apply(&mut self, op: &EditOp) -> Result<(), UnknownCharError> {
+        if let EditOp::Insert { id, .. } = *op {
+            self.clock = self.clock.max(id.counter);
+        }
+
+        self.integrate(op)
+    }
+
+    /// Returns the operations recorded for the local edits since the last
+    /// call, leaving the record empty.
+    #[inline]
+    pub fn take_ops(&mut self) -> Vec<EditOp> {
+        ::core::mem::take(&mut self.pending)
+    }
+
+    /// Returns operations that rebuild the full state of this replica,
+    /// tombstones included, for bootstrapping a new replica.
+    pub fn export_ops(&self) -> Vec<EditOp> {
+        let mut ops = Vec::with_capacity(self.ids.len());
+        let mut prev = None;
+
+        for (&id, &ch) in self.ids.iter().zip(&self.chars) {
+            ops.push(EditOp::Insert { id, after: prev, ch });
+            prev = Some(id);
+        }
+
+        for (&id, &deleted) in self.ids.iter().zip(&self.deleted) {
+            if deleted {
+                ops.push(EditOp::Delete { id });
+            }
+        }
+
+        ops
+    }
+
+    // Returns the position, among all chars, of the visible char `idx`.
+    fn physical(&self, idx: usize) -> usize {
+        self.deleted.iter()
+            .enumerate()
+            .filter(|&(_, &deleted)| !deleted)
+            .nth(idx)
+            .map(|(pos, _)| pos)
+            .expect("index out of bounds")
+    }
+
+    fn position(&self, id: CharId) -> Result<usize, UnknownCharError> {
+        self.ids.iter().position(|&other| other == id).ok_or(UnknownCharError { id })
+    }
+
+    fn integrate(&mut self, op: &EditOp) -> Result<(), UnknownCharError> {
+        match *op {
+            EditOp::Insert { id, after, ch } => {
+                if self.ids.contains(&id) {
+                    return Ok(())
+                }
+
+                let mut pos = match after {
+                    Some(after) => self.position(after)? + 1,
+                    None => 0
+                };
+
+                // Concurrent insertions after the same char are ordered by
+                // descending id; skipping the greater ids also skips the
+                // chars inserted after them, which have greater ids still.
+                while pos < self.ids.len() && self.ids[pos] > id {
+                    pos += 1;
+                }
+
+                self.ids.insert(pos, id);
+                self.chars.insert(pos, ch);
+                self.deleted.insert(pos, false);
+            }
+            EditOp::Delete { id } => {
+                let pos = self.position(id)?;
+                self.deleted[pos] = true;
+            }
+        }
+
+        Ok(())
+    }
+}
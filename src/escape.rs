@@ -0,0 +1,284 @@
+use core::error;
+use core::fmt;
+
+use crate::String2;
+
+/// The shell that [`quote_shell`] quotes arguments for.
+///
+/// [`quote_shell`]: struct.String2.html#method.quote_shell
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShellDialect {
+    /// POSIX shells like `sh`, `bash` and `zsh`.
+    Posix,
+    /// Windows PowerShell and PowerShell Core.
+    PowerShell
+}
+
+impl ShellDialect {
+    /// Returns the usual shell of the target platform: PowerShell on
+    /// Windows, and a POSIX shell everywhere else.
+    #[inline]
+    pub fn native() -> ShellDialect {
+        if cfg!(windows) {
+            ShellDialect::PowerShell
+        } else {
+            ShellDialect::Posix
+        }
+    }
+}
+
+/// The SQL dialect that [`quote_sql_literal`] writes string literals for.
+///
+/// [`quote_sql_literal`]: struct.String2.html#method.quote_sql_literal
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SqlDialect {
+    /// Standard SQL, as spoken by SQLite, SQL Server and Oracle, where a
+    /// backslash is an ordinary char.
+    Standard,
+    /// PostgreSQL, whose literals can treat backslashes either way,
+    /// depending on the `standard_conforming_strings` setting.
+    PostgreSql,
+    /// MySQL and MariaDB, where a backslash escapes the next char unless
+    /// the `NO_BACKSLASH_ESCAPES` SQL mode is set.
+    MySql
+}
+
+/// The error returned by [`quote_sql_literal`] and [`quote_shell`] when the
+/// string holds a NUL char, which neither SQL string literals nor shell
+/// arguments can hold.
+///
+/// [`quote_sql_literal`]: struct.String2.html#method.quote_sql_literal
+/// [`quote_shell`]: struct.String2.html#method.quote_shell
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NulCharError {
+    idx: usize
+}
+
+impl NulCharError {
+    /// Returns the char index of the first NUL char.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.idx
+    }
+}
+
+impl fmt::Display for NulCharError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NUL char at index {} cannot be quoted", self.idx)
+    }
+}
+
+impl error::Error for NulCharError {}
+
+impl String2 {
+    /// Returns a copy of this `String2` with a backslash before every
+    /// Markdown metacharacter, so that it shows up as plain text when
+    /// embedded in a Markdown document.
+    ///
+    /// ``\ ` * _ [ ] < > & |`` and `~` are escaped everywhere, which also
+    /// covers links, images, inline HTML, entities and the tables and
+    /// strikethrough of GitHub Flavored Markdown. Headings, block quotes,
+    /// list items and setext underlines only start at the beginning of a
+    /// line, so `#`, `+`, `-` and `=` are escaped there, as is the `.` or
+    /// `)` after a number that would start an ordered list. Indentation is
+    /// left alone.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("# 1. *Bold* [link](x) a_b - c\n2) `code`");
+    /// assert_eq!(s.escape_markdown(), r"\# 1. \*Bold\* \[link\](x) a\_b - c
+    /// 2\) \`code\`");
+    /// ```
+    pub fn escape_markdown(&self) -> String2 {
+        let mut out = String2::with_capacity(self.len());
+
+        // Whether the current line has only had indentation so far, and
+        // whether it has had indentation followed by digits.
+        let mut line_start = true;
+        let mut digits = false;
+
+        for &ch in &self.inner {
+            let escape = match ch {
+                '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '&' | '|' | '~' => true,
+                '#' | '+' | '-' | '=' => line_start,
+                '.' | ')' => digits,
+                _ => false
+            };
+            if escape {
+                out.push('\\');
+            }
+            out.push(ch);
+
+            if ch == '\n' {
+                line_start = true;
+                digits = false;
+            } else if ch.is_ascii_digit() && (line_start || digits) {
+                line_start = false;
+                digits = true;
+            } else if !(line_start && (ch == ' ' || ch == '\t')) {
+                line_start = false;
+                digits = false;
+            }
+        }
+
+        out
+    }
+
+    /// Returns a copy of this `String2` quoted so that `shell` reads it as
+    /// a single argument, with no expansion or interpretation.
+    ///
+    /// Strings made only of ASCII alphanumeric chars and a few safe
+    /// punctuation chars are returned as they are, unless they start with a
+    /// char that is special there, like the `=` that zsh expands to the
+    /// path of a command, or the `-` of a PowerShell parameter name. Others
+    /// are put in single
+    /// quotes, inside of which both shells take every char literally. A
+    /// POSIX shell cannot escape a single quote inside single quotes, so
+    /// each one ends the quotes, is escaped and opens new quotes, as in
+    /// `'it'\''s'`. PowerShell doubles it instead, as well as the curly
+    /// single quotes that it treats the same way.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NulCharError`] if the string holds a NUL char, as no
+    /// shell can take one in an argument.
+    ///
+    /// [`NulCharError`]: struct.NulCharError.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{ShellDialect, String2};
+    ///
+    /// let path = String2::from("/tmp/report.txt");
+    /// assert_eq!(path.quote_shell(ShellDialect::Posix).unwrap(), "/tmp/report.txt");
+    ///
+    /// let s = String2::from("it's $HOME");
+    /// assert_eq!(s.quote_shell(ShellDialect::Posix).unwrap(), r"'it'\''s $HOME'");
+    /// assert_eq!(s.quote_shell(ShellDialect::PowerShell).unwrap(), "'it''s $HOME'");
+    ///
+    /// assert_eq!(String2::from("=ls").quote_shell(ShellDialect::Posix).unwrap(), "'=ls'");
+    /// assert_eq!(String2::from("~root").quote_shell(ShellDialect::Posix).unwrap(), "'~root'");
+    /// assert_eq!(String2::new().quote_shell(ShellDialect::Posix).unwrap(), "''");
+    ///
+    /// let err = String2::from("a\0").quote_shell(ShellDialect::Posix).unwrap_err();
+    /// assert_eq!(err.index(), 1);
+    /// ```
+    pub fn quote_shell(&self, shell: ShellDialect) -> Result<String2, NulCharError> {
+        if let Some(idx) = self.inner.iter().position(|&ch| ch == '\0') {
+            return Err(NulCharError {
+                idx
+            })
+        }
+
+        let safe = |ch: char| match shell {
+            ShellDialect::Posix => ch.is_ascii_alphanumeric() || "_-+=@%:,./".contains(ch),
+            ShellDialect::PowerShell => ch.is_ascii_alphanumeric() || "_-:./\\".contains(ch)
+        };
+        // A leading `=` makes zsh look up a command, and a leading dash
+        // makes PowerShell read an operator or a parameter name. A leading
+        // `~` is never safe, so it is always quoted.
+        let leading_special = matches!(
+            (shell, self.inner.first()),
+            (ShellDialect::Posix, Some(&'=')) | (ShellDialect::PowerShell, Some(&'-'))
+        );
+
+        if !self.is_empty() && !leading_special && self.inner.iter().all(|&ch| safe(ch)) {
+            return Ok(self.clone())
+        }
+
+        let mut out = String2::with_capacity(self.len() + 2);
+        out.push('\'');
+        for &ch in &self.inner {
+            match (shell, ch) {
+                (ShellDialect::Posix, '\'') => out.push_str("'\\''"),
+                (ShellDialect::PowerShell, '\'' | '‘' | '’' | '‚' | '‛') => {
+                    out.push(ch);
+                    out.push(ch);
+                }
+                _ => out.push(ch)
+            }
+        }
+        out.push('\'');
+
+        Ok(out)
+    }
+
+    /// Returns this `String2` as a SQL string literal for `dialect`,
+    /// quotes included.
+    ///
+    /// # Security
+    ///
+    /// **Do not use this to put user input into queries run by an
+    /// application.** Pass values as parameters of a prepared statement
+    /// instead, which keeps them out of the SQL text entirely. This method
+    /// is meant for writing SQL by hand, in quick scripts or when
+    /// reproducing a logged query, and is only correct for the dialects
+    /// and settings described below; a mismatch with the server, such as a
+    /// different character set or SQL mode, can make the literal unsafe.
+    ///
+    /// The string is put in single quotes, and every single quote in it is
+    /// doubled. For [`PostgreSql`], a string holding a backslash is written
+    /// as an escape string, `E'...'`, with the backslashes doubled, so it
+    /// reads the same whatever the `standard_conforming_strings` setting.
+    /// For [`MySql`], backslashes are doubled, which is only right when the
+    /// `NO_BACKSLASH_ESCAPES` mode is off, as it is by default.
+    ///
+    /// [`PostgreSql`]: enum.SqlDialect.html#variant.PostgreSql
+    /// [`MySql`]: enum.SqlDialect.html#variant.MySql
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NulCharError`] if the string holds a NUL char.
+    ///
+    /// [`NulCharError`]: struct.NulCharError.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{SqlDialect, String2};
+    ///
+    /// let s = String2::from(r"O'Brien\n");
+    ///
+    /// assert_eq!(s.quote_sql_literal(SqlDialect::Standard).unwrap(), r"'O''Brien\n'");
+    /// assert_eq!(s.quote_sql_literal(SqlDialect::PostgreSql).unwrap(), r"E'O''Brien\\n'");
+    /// assert_eq!(s.quote_sql_literal(SqlDialect::MySql).unwrap(), r"'O''Brien\\n'");
+    ///
+    /// let err = String2::from("a\0b").quote_sql_literal(SqlDialect::Standard).unwrap_err();
+    /// assert_eq!(err.index(), 1);
+    /// ```
+    pub fn quote_sql_literal(&self, dialect: SqlDialect) -> Result<String2, NulCharError> {
+        if let Some(idx) = self.inner.iter().position(|&ch| ch == '\0') {
+            return Err(NulCharError {
+                idx
+            })
+        }
+
+        let has_backslash = self.inner.contains(&'\\');
+        let double_backslashes = dialect != SqlDialect::Standard;
+
+        let mut out = String2::with_capacity(self.len() + 3);
+        if dialect == SqlDialect::PostgreSql && has_backslash {
+            out.push('E');
+        }
+        out.push('\'');
+        for &ch in &self.inner {
+            if ch == '\'' || (ch == '\\' && double_backslashes) {
+                out.push(ch);
+            }
+            out.push(ch);
+        }
+        out.push('\'');
+
+        Ok(out)
+    }
+}
@@ -0,0 +1,256 @@
+use core::error;
+use core::fmt;
+use alloc::string::String;
+
+use crate::String2;
+
+/// The error returned by [`expand_env_with`] for a malformed `${...}`
+/// expansion.
+///
+/// Positions are char indices of the `$` in the string.
+///
+/// [`expand_env_with`]: struct.String2.html#method.expand_env_with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpandEnvError {
+    /// A `${` is never closed by a `}`.
+    Unclosed {
+        /// The position of the `$`.
+        idx: usize
+    },
+    /// The braces hold no valid variable name, or the name is followed by
+    /// something other than `}`, `-`, `+`, `:-` or `:+`.
+    InvalidName {
+        /// The position of the `$`.
+        idx: usize
+    }
+}
+
+impl fmt::Display for ExpandEnvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExpandEnvError::Unclosed { idx } => write!(f, "unclosed `${{` at {}", idx),
+            ExpandEnvError::InvalidName { idx } => write!(f, "invalid variable expansion at {}", idx)
+        }
+    }
+}
+
+impl error::Error for ExpandEnvError {}
+
+fn is_name_start(ch: char) -> bool {
+    ch == '_' || ch.is_ascii_alphabetic()
+}
+
+fn is_name_char(ch: char) -> bool {
+    ch == '_' || ch.is_ascii_alphanumeric()
+}
+
+// Returns the position of the `}` ending the word that starts at `start`,
+// skipping over nested `${...}` and `$$`.
+fn word_end(chars: &[char], start: usize) -> Option<usize> {
+    let mut depth = 0;
+
+    let mut idx = start;
+    while idx < chars.len() {
+        match (chars[idx], chars.get(idx + 1)) {
+            ('$', Some('$')) => idx += 1,
+            ('$', Some('{')) => {
+                depth += 1;
+                idx += 1;
+            }
+            ('}', _) if depth == 0 => return Some(idx),
+            ('}', _) => depth -= 1,
+            _ => {}
+        }
+        idx += 1;
+    }
+
+    None
+}
+
+// Expands `chars`, found at `base` in the string, into `out`.
+fn expand_into<F, V>(chars: &[char], base: usize, lookup: &mut F, out: &mut String2) -> Result<(), ExpandEnvError>
+    where F: FnMut(&str) -> Option<V>, V: AsRef<str>
+{
+    let mut idx = 0;
+    while idx < chars.len() {
+        if chars[idx] != '$' {
+            out.push(chars[idx]);
+            idx += 1;
+            continue
+        }
+
+        match chars.get(idx + 1) {
+            Some('$') => {
+                out.push('$');
+                idx += 2;
+            }
+            Some('{') => idx = expand_braced(chars, idx, base, lookup, out)?,
+            Some(&ch) if is_name_start(ch) => {
+                let len = chars[idx + 1..].iter().take_while(|&&ch| is_name_char(ch)).count();
+                let name: String = chars[idx + 1..idx + 1 + len].iter().collect();
+
+                if let Some(value) = lookup(&name) {
+                    out.push_str(value.as_ref());
+                }
+                idx += 1 + len;
+            }
+            // A `$` that starts no expansion is kept as is.
+            _ => {
+                out.push('$');
+                idx += 1;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Expands the `${...}` whose `$` is at `start`, returning the position
+// after its `}`.
+fn expand_braced<F, V>(
+    chars: &[char],
+    start: usize,
+    base: usize,
+    lookup: &mut F,
+    out: &mut String2
+) -> Result<usize, ExpandEnvError>
+    where F: FnMut(&str) -> Option<V>, V: AsRef<str>
+{
+    let invalid = ExpandEnvError::InvalidName { idx: base + start };
+    let unclosed = ExpandEnvError::Unclosed { idx: base + start };
+
+    let name_start = start + 2;
+    let name_len = chars[name_start..].iter().take_while(|&&ch| is_name_char(ch)).count();
+    if name_len == 0 || !is_name_start(chars[name_start]) {
+        return Err(if name_start == chars.len() { unclosed } else { invalid })
+    }
+    let name: String = chars[name_start..name_start + name_len].iter().collect();
+
+    let mut idx = name_start + name_len;
+    let colon = chars.get(idx) == Some(&':');
+    if colon {
+        idx += 1;
+    }
+
+    let op = match chars.get(idx) {
+        Some('}') if !colon => {
+            if let Some(value) = lookup(&name) {
+                out.push_str(value.as_ref());
+            }
+            return Ok(idx + 1)
+        }
+        Some(&op) if op == '-' || op == '+' => op,
+        Some(_) => return Err(invalid),
+        None => return Err(unclosed)
+    };
+
+    let word_start = idx + 1;
+    let end = word_end(chars, word_start).ok_or(unclosed)?;
+    let word = &chars[word_start..end];
+
+    // With a colon, an empty variable counts as unset.
+    let value = lookup(&name).filter(|value| !colon || !value.as_ref().is_empty());
+
+    match (op, value) {
+        ('-', Some(value)) => out.push_str(value.as_ref()),
+        ('-', None) | ('+', Some(_)) => expand_into(word, base + word_start, lookup, out)?,
+        _ => {}
+    }
+
+    Ok(end + 1)
+}
+
+impl String2 {
+    /// Expands shell-style variables in this `String2`, looking up their
+    /// values with `lookup`.
+    ///
+    /// The supported forms are:
+    ///
+    /// * `$NAME` and `${NAME}`: the value of the variable, or nothing if it
+    ///   is unset.
+    /// * `${NAME-word}`: the value, or `word` if the variable is unset.
+    /// * `${NAME:-word}`: the value, or `word` if the variable is unset or
+    ///   empty.
+    /// * `${NAME+word}`: `word` if the variable is set, or nothing.
+    /// * `${NAME:+word}`: `word` if the variable is set and not empty, or
+    ///   nothing.
+    ///
+    /// Names are made of ASCII letters, digits and underscores, and do not
+    /// start with a digit. `word` is only expanded when it is used, and may
+    /// hold further expansions. A literal `$` is written `$$`; a `$` that
+    /// does not start an expansion is also kept as is. Backslashes have no
+    /// special meaning, so Windows paths need no escaping.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExpandEnvError`] if a `${` is not closed, or does not
+    /// hold one of the forms above.
+    ///
+    /// [`ExpandEnvError`]: enum.ExpandEnvError.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{ExpandEnvError, String2};
+    ///
+    /// let lookup = |name: &str| match name {
+    ///     "HOME" => Some("/home/ada"),
+    ///     "EMPTY" => Some(""),
+    ///     _ => None
+    /// };
+    ///
+    /// let s = String2::from("$HOME/.config:${XDG_CONFIG_DIRS:-/etc/xdg}");
+    /// assert_eq!(s.expand_env_with(lookup).unwrap(), "/home/ada/.config:/etc/xdg");
+    ///
+    /// let s = String2::from("${EMPTY-unset}|${EMPTY:-empty}|${HOME:+set} costs $$5");
+    /// assert_eq!(s.expand_env_with(lookup).unwrap(), "|empty|set costs $5");
+    ///
+    /// let s = String2::from("${CACHE:-${HOME}/.cache}");
+    /// assert_eq!(s.expand_env_with(lookup).unwrap(), "/home/ada/.cache");
+    ///
+    /// let err = String2::from("x ${HOME").expand_env_with(lookup).unwrap_err();
+    /// assert_eq!(err, ExpandEnvError::Unclosed { idx: 2 });
+    /// ```
+    pub fn expand_env_with<F, V>(&self, mut lookup: F) -> Result<String2, ExpandEnvError>
+        where F: FnMut(&str) -> Option<V>, V: AsRef<str>
+    {
+        let mut out = String2::with_capacity(self.len());
+        expand_into(&self.inner, 0, &mut lookup, &mut out)?;
+        Ok(out)
+    }
+
+    /// Expands shell-style variables in this `String2` from the environment
+    /// of the process.
+    ///
+    /// See [`expand_env_with`] for the supported forms. Variables whose
+    /// value is not valid unicode are treated as unset.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// [`expand_env_with`]: #method.expand_env_with
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExpandEnvError`] if a `${` is not closed, or does not
+    /// hold one of the supported forms.
+    ///
+    /// [`ExpandEnvError`]: enum.ExpandEnvError.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("${STRING2_SURELY_UNSET:-fallback}");
+    /// assert_eq!(s.expand_env().unwrap(), "fallback");
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn expand_env(&self) -> Result<String2, ExpandEnvError> {
+        self.expand_env_with(|name| std::env::var(name).ok())
+    }
+}
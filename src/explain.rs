@@ -0,0 +1,199 @@
+use core::fmt;
+
+use crate::String2;
+#[cfg(feature = "unicode-normalization")]
+use crate::Form;
+
+// Chars of context shown on each side of the first difference.
+const CONTEXT: usize = 12;
+
+/// A description of where and how two strings differ.
+///
+/// This struct is created by the [`explain_difference`] method on
+/// `String2`. Its `Display` output is a short report meant for test
+/// failure messages.
+///
+/// [`explain_difference`]: struct.String2.html#method.explain_difference
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Difference {
+    index: usize,
+    left_char: Option<char>,
+    right_char: Option<char>,
+    left_excerpt: String2,
+    right_excerpt: String2,
+    excerpt_start: usize,
+    eq_ignore_case: bool,
+    #[cfg(feature = "unicode-normalization")]
+    eq_nfc: bool
+}
+
+impl Difference {
+    /// Returns the index of the first char at which the strings differ.
+    /// When one string is a prefix of the other, this is the length of the
+    /// shorter one.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the first differing char of the left string, or `None` if
+    /// it ends there.
+    #[inline]
+    pub fn left_char(&self) -> Option<char> {
+        self.left_char
+    }
+
+    /// Returns the first differing char of the right string, or `None` if
+    /// it ends there.
+    #[inline]
+    pub fn right_char(&self) -> Option<char> {
+        self.right_char
+    }
+
+    /// Returns an excerpt of the left string around the difference.
+    #[inline]
+    pub fn left_excerpt(&self) -> &String2 {
+        &self.left_excerpt
+    }
+
+    /// Returns an excerpt of the right string around the difference.
+    #[inline]
+    pub fn right_excerpt(&self) -> &String2 {
+        &self.right_excerpt
+    }
+
+    /// Returns the char index at which both excerpts start, not counting
+    /// the leading `…` they have when they do not start at `0`.
+    #[inline]
+    pub fn excerpt_start(&self) -> usize {
+        self.excerpt_start
+    }
+
+    /// Returns `true` if the strings are equal when case is ignored.
+    #[inline]
+    pub fn eq_ignore_case(&self) -> bool {
+        self.eq_ignore_case
+    }
+
+    /// Returns `true` if the strings are equal once both are normalized to
+    /// NFC.
+    ///
+    /// This method requires the `unicode-normalization` feature.
+    #[cfg(feature = "unicode-normalization")]
+    #[inline]
+    pub fn eq_nfc(&self) -> bool {
+        self.eq_nfc
+    }
+}
+
+fn describe(f: &mut fmt::Formatter, ch: Option<char>) -> fmt::Result {
+    match ch {
+        Some(ch) => write!(f, "{:?} (U+{:04X})", ch, ch as u32),
+        None => f.write_str("end of string")
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "strings differ at char {}: ", self.index)?;
+        describe(f, self.left_char)?;
+        f.write_str(" vs ")?;
+        describe(f, self.right_char)?;
+
+        writeln!(f)?;
+        writeln!(f, "   left: {:?}", self.left_excerpt)?;
+        write!(f, "  right: {:?}", self.right_excerpt)?;
+
+        #[cfg(feature = "unicode-normalization")]
+        {
+            if self.eq_nfc {
+                write!(f, "\n  note: equal after NFC normalization")?;
+            }
+        }
+
+        if self.eq_ignore_case {
+            write!(f, "\n  note: equal when ignoring case")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn excerpt(chars: &[char], start: usize, end: usize) -> String2 {
+    let end = end.min(chars.len());
+    let start = start.min(end);
+    let mut out = String2::with_capacity(end - start + 2);
+
+    if start > 0 {
+        out.push('…');
+    }
+    out.extend(&chars[start..end]);
+    if end < chars.len() {
+        out.push('…');
+    }
+
+    out
+}
+
+impl String2 {
+    /// Explains how this `String2` differs from `other`, or returns `None`
+    /// if they are equal.
+    ///
+    /// The [`Difference`] gives the index of the first differing char, the
+    /// chars themselves, short excerpts of both strings around that point,
+    /// and whether the strings would be equal ignoring case, or (with the
+    /// `unicode-normalization` feature) after NFC normalization. Its
+    /// `Display` output turns a bare "assertion failed" into something
+    /// actionable.
+    ///
+    /// [`Difference`]: struct.Difference.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let left = String2::from("Hello, world");
+    /// let right = String2::from("hello, world");
+    ///
+    /// let diff = left.explain_difference(&right).unwrap();
+    ///
+    /// assert_eq!(diff.index(), 0);
+    /// assert_eq!(diff.left_char(), Some('H'));
+    /// assert!(diff.eq_ignore_case());
+    /// assert_eq!(
+    ///     diff.to_string(),
+    ///     "strings differ at char 0: 'H' (U+0048) vs 'h' (U+0068)\n   \
+    ///         left: \"Hello, world\"\n  \
+    ///        right: \"hello, world\"\n  \
+    ///         note: equal when ignoring case"
+    /// );
+    ///
+    /// assert!(left.explain_difference(&left).is_none());
+    /// ```
+    pub fn explain_difference(&self, other: &String2) -> Option<Difference> {
+        let (a, b) = (&self.inner, &other.inner);
+        let index = a.iter().zip(b.iter()).take_while(|&(x, y)| x == y).count();
+
+        if index == a.len() && index == b.len() {
+            return None
+        }
+
+        let start = index.saturating_sub(CONTEXT);
+        let end = index + CONTEXT;
+
+        Some(Difference {
+            index,
+            left_char: a.get(index).cloned(),
+            right_char: b.get(index).cloned(),
+            left_excerpt: excerpt(a, start, end),
+            right_excerpt: excerpt(b, start, end),
+            excerpt_start: start,
+            eq_ignore_case: a.len() == b.len() && self.starts_with_ignore_case(other),
+            #[cfg(feature = "unicode-normalization")]
+            eq_nfc: self.eq_normalized(other, Form::Nfc)
+        })
+    }
+}
@@ -0,0 +1,55 @@
+use crate::String2;
+
+// The FNV-1a parameters for 128-bit hashes.
+const FNV128_OFFSET_BASIS: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+const FNV128_PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+
+impl String2 {
+    /// The version of the algorithm behind [`fingerprint128`].
+    ///
+    /// The algorithm of a version never changes. Should a new one be
+    /// needed, it will get a new version and a new method, so fingerprints
+    /// that have been stored stay valid.
+    ///
+    /// [`fingerprint128`]: #method.fingerprint128
+    pub const FINGERPRINT128_VERSION: u32 = 1;
+
+    /// Returns a 128-bit fingerprint of the chars of this `String2`.
+    ///
+    /// Unlike the `Hash` implementation, whose result depends on the hasher
+    /// and may change between Rust releases, the fingerprint is the same on
+    /// every platform, process and release, so it can be stored or sent
+    /// elsewhere, as a cache key or to find duplicates.
+    ///
+    /// Version 1 of the fingerprint, described by
+    /// [`FINGERPRINT128_VERSION`], is the 128-bit FNV-1a hash of the UTF-8
+    /// encoding of the string, so other programs can compute it too. It is
+    /// not a cryptographic hash: it is good at telling ordinary texts
+    /// apart, but collisions can be made on purpose.
+    ///
+    /// [`FINGERPRINT128_VERSION`]: #associatedconstant.FINGERPRINT128_VERSION
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("a").fingerprint128(), 0xd228cb696f1a8caf78912b704e4a8964);
+    /// assert_ne!(String2::from("ab").fingerprint128(), String2::from("ba").fingerprint128());
+    /// ```
+    pub fn fingerprint128(&self) -> u128 {
+        let mut hash = FNV128_OFFSET_BASIS;
+        let mut buf = [0; 4];
+
+        for ch in &self.inner {
+            for &byte in ch.encode_utf8(&mut buf).as_bytes() {
+                hash ^= u128::from(byte);
+                hash = hash.wrapping_mul(FNV128_PRIME);
+            }
+        }
+
+        hash
+    }
+}
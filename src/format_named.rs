@@ -0,0 +1,222 @@
+use core::error;
+use core::fmt::{self, Write};
+
+use crate::String2;
+
+/// The error returned by [`format_named`] for a template it cannot fill in.
+///
+/// Positions are char indices into the template.
+///
+/// [`format_named`]: struct.String2.html#method.format_named
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FormatError {
+    /// A placeholder names no argument.
+    UnknownPlaceholder {
+        /// The name between the braces.
+        name: String2,
+        /// The position of the opening brace.
+        idx: usize
+    },
+    /// A `{` opens a placeholder that is never closed.
+    Unclosed {
+        /// The position of the opening brace.
+        idx: usize
+    },
+    /// A `}` closes no placeholder and is not escaped as `}}`.
+    Unmatched {
+        /// The position of the closing brace.
+        idx: usize
+    },
+    /// A `plural` or `select` placeholder is not well formed, has no
+    /// `other` case, or the argument of a `plural` is not a number.
+    ///
+    /// This error is only returned when the `message-format` feature is
+    /// enabled, as `plural` and `select` are plain names otherwise.
+    Malformed {
+        /// The position of the opening brace.
+        idx: usize
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FormatError::UnknownPlaceholder { ref name, idx } => write!(f, "unknown placeholder {{{}}} at {}", name, idx),
+            FormatError::Unclosed { idx } => write!(f, "unclosed `{{` at {}", idx),
+            FormatError::Unmatched { idx } => write!(f, "unmatched `}}` at {}", idx),
+            FormatError::Malformed { idx } => write!(f, "malformed plural or select at {}", idx)
+        }
+    }
+}
+
+impl error::Error for FormatError {}
+
+impl String2 {
+    /// Fills in the named placeholders of this template with the matching
+    /// arguments.
+    ///
+    /// A placeholder is a name between braces, like `{name}`, and is
+    /// replaced by the [`Display`] form of the argument of that name. The
+    /// name is matched exactly, so `{ name }` is a different placeholder.
+    /// Literal braces are written doubled, as `{{` and `}}`. Unlike
+    /// `format!`, the template is checked when this runs, so it can come
+    /// from configuration files or translations.
+    ///
+    /// With the `message-format` feature, placeholders can also pick their
+    /// text from the argument, with a subset of the ICU MessageFormat
+    /// syntax:
+    ///
+    /// * `{count, plural, =0{none} one{# file} other{# files}}` picks the
+    ///   first case matching the number exactly, with `=`, or else its
+    ///   English plural category, `one` or `other`. Inside the cases, `#`
+    ///   stands for the number.
+    /// * `{gender, select, female{her} male{his} other{their}}` picks the
+    ///   case named like the argument.
+    ///
+    /// Both fall back to the `other` case, which is required, and the cases
+    /// are templates themselves, so they can hold further placeholders.
+    ///
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FormatError`] if a placeholder names no argument or a
+    /// brace is unbalanced, or if a `plural` or `select` placeholder is
+    /// malformed.
+    ///
+    /// [`FormatError`]: enum.FormatError.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Display` impl of an argument returns an error, like
+    /// `format!` does.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{FormatError, String2};
+    ///
+    /// let template = String2::from("Hello {name}, you have {count} new {{messages}}");
+    /// let s = template.format_named(&[("name", &"Ada"), ("count", &3)]).unwrap();
+    /// assert_eq!(s, "Hello Ada, you have 3 new {messages}");
+    ///
+    /// let err = String2::from("Bye {nmae}").format_named(&[("name", &"Ada")]).unwrap_err();
+    /// assert_eq!(err, FormatError::UnknownPlaceholder { name: String2::from("nmae"), idx: 4 });
+    ///
+    /// let err = String2::from("{name").format_named(&[("name", &"Ada")]).unwrap_err();
+    /// assert_eq!(err, FormatError::Unclosed { idx: 0 });
+    /// ```
+    ///
+    /// With the `message-format` feature:
+    ///
+    /// ```
+    /// # #[cfg(feature = "message-format")] {
+    /// use string2::String2;
+    ///
+    /// let template = String2::from(
+    ///     "{name} shared {n, plural, =0{no files} one{a file} other{# files}} with {gender, select, female{her} male{his} other{their}} team"
+    /// );
+    ///
+    /// let s = template.format_named(&[("name", &"Ada"), ("n", &3), ("gender", &"female")]).unwrap();
+    /// assert_eq!(s, "Ada shared 3 files with her team");
+    ///
+    /// let s = template.format_named(&[("name", &"Sam"), ("n", &1), ("gender", &"")]).unwrap();
+    /// assert_eq!(s, "Sam shared a file with their team");
+    /// # }
+    /// ```
+    pub fn format_named(&self, args: &[(&str, &dyn fmt::Display)]) -> Result<String2, FormatError> {
+        let mut out = String2::with_capacity(self.len());
+        format_into(&self.inner, 0, args, None, &mut out)?;
+        Ok(out)
+    }
+}
+
+// Formats `chars`, found at `base` in the template, into `out`. Inside the
+// cases of a `plural`, `number` is what `#` stands for.
+pub(crate) fn format_into(
+    chars: &[char],
+    base: usize,
+    args: &[(&str, &dyn fmt::Display)],
+    number: Option<&[char]>,
+    out: &mut String2
+) -> Result<(), FormatError> {
+    let mut idx = 0;
+    while idx < chars.len() {
+        let next = chars.get(idx + 1).cloned();
+
+        match chars[idx] {
+            '{' if next == Some('{') => {
+                out.push('{');
+                idx += 2;
+            }
+            '}' if next == Some('}') => {
+                out.push('}');
+                idx += 2;
+            }
+            '{' => {
+                let end = placeholder_end(chars, idx).ok_or(FormatError::Unclosed { idx: base + idx })?;
+                let name = &chars[idx + 1..end];
+
+                #[cfg(feature = "message-format")]
+                {
+                    if name.contains(&',') {
+                        crate::message_format::format_complex(name, base + idx, args, number, out)?;
+                        idx = end + 1;
+                        continue
+                    }
+                }
+
+                let value = lookup(args, name).ok_or_else(|| FormatError::UnknownPlaceholder {
+                    name: String2::from(name),
+                    idx: base + idx
+                })?;
+
+                write!(out, "{}", value).expect("a Display implementation returned an error unexpectedly");
+                idx = end + 1;
+            }
+            '}' => return Err(FormatError::Unmatched { idx: base + idx }),
+            ch => {
+                match number {
+                    Some(number) if ch == '#' => out.extend(number),
+                    _ => out.push(ch)
+                }
+                idx += 1;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Returns the position of the `}` closing the placeholder opened at
+// `start`. Plural and select placeholders nest their cases in braces.
+pub(crate) fn placeholder_end(chars: &[char], start: usize) -> Option<usize> {
+    if cfg!(feature = "message-format") {
+        let mut depth = 0;
+        for (idx, &ch) in chars.iter().enumerate().skip(start) {
+            match ch {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(idx)
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    } else {
+        let len = chars[start + 1..].iter().position(|&ch| ch == '{' || ch == '}')?;
+        if chars[start + 1 + len] == '}' { Some(start + 1 + len) } else { None }
+    }
+}
+
+// Returns the argument named exactly `name`.
+pub(crate) fn lookup<'a>(args: &[(&str, &'a dyn fmt::Display)], name: &[char]) -> Option<&'a dyn fmt::Display> {
+    args.iter()
+        .find(|(arg, _)| arg.chars().eq(name.iter().cloned()))
+        .map(|&(_, value)| value)
+}
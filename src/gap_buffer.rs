@@ -0,0 +1,286 @@
+use core::fmt;
+use core::fmt::Write;
+use alloc::vec::Vec;
+
+use crate::String2;
+
+// The smallest gap opened when the buffer has to grow.
+const MIN_GAP: usize = 16;
+
+/// A string with a gap at its cursor, for editing text one keystroke at a
+/// time.
+///
+/// Inserting into or deleting from the middle of a `String2` moves all the
+/// chars after the edit. A `GapBuffer2` keeps its unused capacity as a gap
+/// at the cursor instead, so inserting and deleting there only take time
+/// proportional to the chars inserted or deleted. Moving the cursor moves
+/// the chars between its old and new position across the gap.
+///
+/// Converting a `String2` into a `GapBuffer2` reuses its buffer, with the
+/// cursor at the end. Converting back moves the gap to the end and reuses
+/// the buffer too.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{GapBuffer2, String2};
+///
+/// let mut buffer = GapBuffer2::from(String2::from("Hello world"));
+/// assert_eq!(buffer.cursor(), 11);
+///
+/// buffer.move_cursor(5);
+/// buffer.insert_at_cursor(",");
+/// buffer.delete_after(6);
+/// buffer.insert_at_cursor(" there");
+/// buffer.delete_before(7);
+///
+/// assert_eq!(buffer.cursor(), 5);
+/// assert_eq!(String2::from(buffer), "Hello");
+/// ```
+#[derive(Clone)]
+pub struct GapBuffer2 {
+    // The chars before the gap, the gap itself filled with '\0', and the
+    // chars after the gap.
+    buf: Vec<char>,
+    gap_start: usize,
+    gap_end: usize
+}
+
+impl GapBuffer2 {
+    /// Creates a new empty `GapBuffer2`.
+    #[inline]
+    pub fn new() -> GapBuffer2 {
+        GapBuffer2 {
+            buf: Vec::new(),
+            gap_start: 0,
+            gap_end: 0
+        }
+    }
+
+    /// Creates a new empty `GapBuffer2` with a gap of at least `capacity`
+    /// chars.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> GapBuffer2 {
+        GapBuffer2 {
+            buf: vec!['\0'; capacity],
+            gap_start: 0,
+            gap_end: capacity
+        }
+    }
+
+    /// Returns the length of this buffer, in chars, leaving out the gap.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buf.len() - (self.gap_end - self.gap_start)
+    }
+
+    /// Returns `true` if this buffer has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the char index of the cursor.
+    #[inline]
+    pub fn cursor(&self) -> usize {
+        self.gap_start
+    }
+
+    /// Moves the cursor to char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of this buffer.
+    pub fn move_cursor(&mut self, idx: usize) {
+        assert!(idx <= self.len(), "cursor index {} is out of bounds", idx);
+
+        if idx < self.gap_start {
+            let moved = self.gap_start - idx;
+            self.buf.copy_within(idx..self.gap_start, self.gap_end - moved);
+            self.gap_start = idx;
+            self.gap_end -= moved;
+        } else if idx > self.gap_start {
+            let moved = idx - self.gap_start;
+            self.buf.copy_within(self.gap_end..self.gap_end + moved, self.gap_start);
+            self.gap_start = idx;
+            self.gap_end += moved;
+        }
+    }
+
+    /// Inserts a string slice at the cursor, and moves the cursor after it.
+    pub fn insert_at_cursor(&mut self, string: &str) {
+        let (lower, _) = string.chars().size_hint();
+        self.reserve(lower);
+
+        for ch in string.chars() {
+            self.insert_char_at_cursor(ch);
+        }
+    }
+
+    /// Inserts a char at the cursor, and moves the cursor after it.
+    #[inline]
+    pub fn insert_char_at_cursor(&mut self, ch: char) {
+        self.reserve(1);
+        self.buf[self.gap_start] = ch;
+        self.gap_start += 1;
+    }
+
+    /// Deletes up to `count` chars before the cursor, as the backspace key
+    /// does, and returns how many were deleted.
+    #[inline]
+    pub fn delete_before(&mut self, count: usize) -> usize {
+        let count = count.min(self.gap_start);
+        self.gap_start -= count;
+        count
+    }
+
+    /// Deletes up to `count` chars after the cursor, as the delete key
+    /// does, and returns how many were deleted.
+    #[inline]
+    pub fn delete_after(&mut self, count: usize) -> usize {
+        let count = count.min(self.buf.len() - self.gap_end);
+        self.gap_end += count;
+        count
+    }
+
+    /// Returns the char at char index `idx`, or `None` if it is out of
+    /// bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<char> {
+        if idx < self.gap_start {
+            Some(self.buf[idx])
+        } else {
+            self.buf.get(idx - self.gap_start + self.gap_end).cloned()
+        }
+    }
+
+    /// Returns the chars before the cursor and the chars after it.
+    #[inline]
+    pub fn as_slices(&self) -> (&[char], &[char]) {
+        (&self.buf[..self.gap_start], &self.buf[self.gap_end..])
+    }
+
+    /// Makes the gap at least `additional` chars wide.
+    pub fn reserve(&mut self, additional: usize) {
+        if self.gap_end - self.gap_start >= additional {
+            return
+        }
+
+        let after = self.buf.len() - self.gap_end;
+        let new_len = (self.len() + additional.max(MIN_GAP)).max(2 * self.buf.len());
+        self.buf.resize(new_len, '\0');
+        self.buf.copy_within(self.gap_end..self.gap_end + after, new_len - after);
+        self.gap_end = new_len - after;
+    }
+
+    /// Copies this buffer into a `String2`.
+    pub fn to_string2(&self) -> String2 {
+        let (before, after) = self.as_slices();
+
+        let mut out = String2::with_capacity(self.len());
+        out.extend(before);
+        out.extend(after);
+        out
+    }
+}
+
+impl Default for GapBuffer2 {
+    #[inline]
+    fn default() -> GapBuffer2 {
+        GapBuffer2::new()
+    }
+}
+
+impl<'a> From<&'a str> for GapBuffer2 {
+    #[inline]
+    fn from(string: &'a str) -> GapBuffer2 {
+        GapBuffer2::from(String2::from(string))
+    }
+}
+
+impl From<String2> for GapBuffer2 {
+    /// Reuses the buffer of `s`, with the cursor at its end and its spare
+    /// capacity as the gap.
+    #[inline]
+    fn from(s: String2) -> GapBuffer2 {
+        let mut buf = s.inner;
+        let len = buf.len();
+        let capacity = buf.capacity();
+        buf.resize(capacity, '\0');
+
+        GapBuffer2 {
+            buf,
+            gap_start: len,
+            gap_end: capacity
+        }
+    }
+}
+
+impl From<GapBuffer2> for String2 {
+    /// Moves the gap to the end of `buffer` and reuses its buffer.
+    #[inline]
+    fn from(mut buffer: GapBuffer2) -> String2 {
+        let len = buffer.len();
+        buffer.move_cursor(len);
+        buffer.buf.truncate(len);
+        String2::from(buffer.buf)
+    }
+}
+
+impl PartialEq for GapBuffer2 {
+    #[inline]
+    fn eq(&self, other: &GapBuffer2) -> bool {
+        let (a, b) = self.as_slices();
+        let (c, d) = other.as_slices();
+        self.len() == other.len() && a.iter().chain(b).eq(c.iter().chain(d))
+    }
+}
+
+impl Eq for GapBuffer2 {}
+
+impl PartialEq<String2> for GapBuffer2 {
+    #[inline]
+    fn eq(&self, other: &String2) -> bool {
+        let (before, after) = self.as_slices();
+        let chars = other.as_slice();
+        self.len() == chars.len() && chars[..before.len()] == *before && chars[before.len()..] == *after
+    }
+}
+
+impl PartialEq<str> for GapBuffer2 {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        let (before, after) = self.as_slices();
+        before.iter().chain(after).cloned().eq(other.chars())
+    }
+}
+
+impl<'a> PartialEq<&'a str> for GapBuffer2 {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self == *other
+    }
+}
+
+impl fmt::Display for GapBuffer2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.width().is_some() || f.precision().is_some() {
+            return crate::fmt_display(self.to_string2().as_slice(), f)
+        }
+
+        let (before, after) = self.as_slices();
+        for &ch in before.iter().chain(after) {
+            f.write_char(ch)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for GapBuffer2 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::fmt_debug(self.to_string2().as_slice(), f)
+    }
+}
@@ -0,0 +1,319 @@
+use core::fmt;
+use core::mem;
+use core::ops::{self, Range};
+use alloc::vec::Vec;
+
+use crate::String2;
+
+// A replacement of the chars `removed` at `at` by `inserted`. Every edit is
+// recorded as one, and undone by swapping the two.
+#[derive(Clone, Debug)]
+struct Edit {
+    at: usize,
+    removed: Vec<char>,
+    inserted: Vec<char>
+}
+
+impl Edit {
+    fn apply(&self, text: &mut String2) {
+        text.splice(self.at..self.at + self.removed.len(), self.inserted.iter().cloned());
+    }
+
+    fn revert(&self, text: &mut String2) {
+        text.splice(self.at..self.at + self.inserted.len(), self.removed.iter().cloned());
+    }
+}
+
+/// A `String2` that records its edits, so they can be undone and redone.
+///
+/// Every edit made through the methods of `EditableString2` is one undo
+/// step, unless it is made between [`begin_edit_group`] and
+/// [`end_edit_group`], in which case all the edits of the group are undone
+/// and redone together. Making an edit after undoing drops the edits that
+/// could have been redone.
+///
+/// An `EditableString2` dereferences to [`String2`], so all the
+/// non-mutating methods of `String2` are available on it.
+///
+/// [`begin_edit_group`]: #method.begin_edit_group
+/// [`end_edit_group`]: #method.end_edit_group
+/// [`String2`]: struct.String2.html
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::EditableString2;
+///
+/// let mut s = EditableString2::from("Hello");
+/// s.push_str(" world");
+///
+/// s.begin_edit_group();
+/// s.remove(0);
+/// s.insert(0, 'J');
+/// s.end_edit_group();
+/// assert_eq!(s.as_string2(), "Jello world");
+///
+/// assert!(s.undo());
+/// assert_eq!(s.as_string2(), "Hello world");
+/// assert!(s.undo());
+/// assert_eq!(s.as_string2(), "Hello");
+/// assert!(!s.undo());
+///
+/// assert!(s.redo());
+/// s.replace_range(6..11, "there");
+/// assert_eq!(s.as_string2(), "Hello there");
+/// assert!(!s.redo());
+/// ```
+#[derive(Clone, Default)]
+pub struct EditableString2 {
+    inner: String2,
+    undo: Vec<Vec<Edit>>,
+    redo: Vec<Vec<Edit>>,
+    // The edits of the open group, and how deeply groups are nested.
+    group: Vec<Edit>,
+    depth: usize
+}
+
+impl EditableString2 {
+    /// Creates a new empty `EditableString2`.
+    #[inline]
+    pub fn new() -> EditableString2 {
+        EditableString2::default()
+    }
+
+    /// Returns a reference to the underlying `String2`.
+    #[inline]
+    pub fn as_string2(&self) -> &String2 {
+        &self.inner
+    }
+
+    /// Converts this `EditableString2` into the underlying `String2`,
+    /// dropping its history.
+    #[inline]
+    pub fn into_string2(self) -> String2 {
+        self.inner
+    }
+
+    /// Appends a char to the end of this string.
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        let len = self.inner.len();
+        self.edit(len..len, vec![ch]);
+    }
+
+    /// Appends a string slice to the end of this string.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        let len = self.inner.len();
+        self.edit(len..len, string.chars().collect());
+    }
+
+    /// Inserts a char at char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of this string.
+    #[inline]
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        assert!(idx <= self.inner.len(), "insertion index {} is out of bounds", idx);
+        self.edit(idx..idx, vec![ch]);
+    }
+
+    /// Inserts a string slice at char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of this string.
+    #[inline]
+    pub fn insert_str(&mut self, idx: usize, string: &str) {
+        assert!(idx <= self.inner.len(), "insertion index {} is out of bounds", idx);
+        self.edit(idx..idx, string.chars().collect());
+    }
+
+    /// Removes the char at char index `idx` and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn remove(&mut self, idx: usize) -> char {
+        assert!(idx < self.inner.len(), "removal index {} is out of bounds", idx);
+        let ch = self.inner[idx];
+        self.edit(idx..idx + 1, Vec::new());
+        ch
+    }
+
+    /// Replaces the chars in `range` with the chars of `replace_with`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is after its end, or its end is past
+    /// the end of the string.
+    pub fn replace_range(&mut self, range: Range<usize>, replace_with: &str) {
+        assert!(range.start <= range.end, "range start {} is after its end {}", range.start, range.end);
+        assert!(range.end <= self.inner.len(), "range end {} is out of bounds of a string of length {}", range.end, self.inner.len());
+        self.edit(range, replace_with.chars().collect());
+    }
+
+    /// Starts a group of edits that are undone and redone as one step.
+    ///
+    /// Groups can be nested; only the outermost one makes an undo step.
+    #[inline]
+    pub fn begin_edit_group(&mut self) {
+        self.depth += 1;
+    }
+
+    /// Ends the group of edits started by the matching call to
+    /// [`begin_edit_group`]. Does nothing if no group is open.
+    ///
+    /// [`begin_edit_group`]: #method.begin_edit_group
+    pub fn end_edit_group(&mut self) {
+        if self.depth == 0 {
+            return
+        }
+
+        self.depth -= 1;
+        if self.depth == 0 && !self.group.is_empty() {
+            let group = mem::take(&mut self.group);
+            self.undo.push(group);
+        }
+    }
+
+    /// Undoes the last step, and returns `false` if there is nothing to
+    /// undo. Any open group is ended first.
+    pub fn undo(&mut self) -> bool {
+        self.close_groups();
+
+        match self.undo.pop() {
+            Some(step) => {
+                for edit in step.iter().rev() {
+                    edit.revert(&mut self.inner);
+                }
+                self.redo.push(step);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// Redoes the last undone step, and returns `false` if there is nothing
+    /// to redo.
+    pub fn redo(&mut self) -> bool {
+        self.close_groups();
+
+        match self.redo.pop() {
+            Some(step) => {
+                for edit in &step {
+                    edit.apply(&mut self.inner);
+                }
+                self.undo.push(step);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// Returns `true` if there is a step to undo, including in an open
+    /// group.
+    #[inline]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty() || !self.group.is_empty()
+    }
+
+    /// Returns `true` if there is a step to redo.
+    #[inline]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets all the steps that could be undone or redone, keeping the
+    /// text as it is.
+    #[inline]
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.group.clear();
+    }
+
+    fn edit(&mut self, range: Range<usize>, inserted: Vec<char>) {
+        if range.is_empty() && inserted.is_empty() {
+            return
+        }
+
+        let removed: Vec<char> = self.inner.splice(range.clone(), inserted.iter().cloned()).collect();
+        let edit = Edit {
+            at: range.start,
+            removed,
+            inserted
+        };
+
+        self.redo.clear();
+        if self.depth > 0 {
+            self.group.push(edit);
+        } else {
+            self.undo.push(vec![edit]);
+        }
+    }
+
+    fn close_groups(&mut self) {
+        if self.depth > 0 {
+            self.depth = 1;
+            self.end_edit_group();
+        }
+    }
+}
+
+impl ops::Deref for EditableString2 {
+    type Target = String2;
+
+    #[inline]
+    fn deref(&self) -> &String2 {
+        &self.inner
+    }
+}
+
+impl AsRef<String2> for EditableString2 {
+    #[inline]
+    fn as_ref(&self) -> &String2 {
+        &self.inner
+    }
+}
+
+impl<'a> From<&'a str> for EditableString2 {
+    #[inline]
+    fn from(s: &'a str) -> EditableString2 {
+        EditableString2::from(String2::from(s))
+    }
+}
+
+impl From<String2> for EditableString2 {
+    #[inline]
+    fn from(s: String2) -> EditableString2 {
+        EditableString2 {
+            inner: s,
+            ..EditableString2::default()
+        }
+    }
+}
+
+impl From<EditableString2> for String2 {
+    #[inline]
+    fn from(s: EditableString2) -> String2 {
+        s.inner
+    }
+}
+
+impl fmt::Display for EditableString2 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl fmt::Debug for EditableString2 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
@@ -0,0 +1,139 @@
+use core::time::Duration;
+
+use crate::String2;
+
+const BYTE_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+// Units of durations of a second or more, as their size in seconds.
+const DURATION_UNITS: [(u64, &str); 4] = [(86_400, "d"), (3_600, "h"), (60, "m"), (1, "s")];
+
+impl String2 {
+    /// Formats a number of bytes for humans, with binary (IEC) units.
+    ///
+    /// Counts below 1 KiB are written as is; larger ones are rounded to one
+    /// decimal in the largest unit they reach.
+    ///
+    /// This function requires the `humanize` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::humanize_bytes(512), "512 B");
+    /// assert_eq!(String2::humanize_bytes(1536), "1.5 KiB");
+    /// assert_eq!(String2::humanize_bytes(1_048_575), "1.0 MiB");
+    /// assert_eq!(String2::humanize_bytes(u64::MAX), "16.0 EiB");
+    /// ```
+    pub fn humanize_bytes(bytes: u64) -> String2 {
+        let mut out = String2::with_capacity(10);
+
+        let mut unit = 0;
+        while unit + 1 < BYTE_UNITS.len() && bytes >= 1 << (10 * (unit + 1)) {
+            unit += 1;
+        }
+
+        if unit == 0 {
+            out.push_u64(bytes);
+        } else {
+            // Tenths of the unit, rounded half up; this may carry into the
+            // next unit.
+            let scale = 1u128 << (10 * unit);
+            let mut tenths = (bytes as u128 * 10 + scale / 2) / scale;
+            if tenths == 10_240 && unit + 1 < BYTE_UNITS.len() {
+                tenths = 10;
+                unit += 1;
+            }
+
+            out.push_u64((tenths / 10) as u64);
+            out.push('.');
+            out.push_u64((tenths % 10) as u64);
+        }
+
+        out.push(' ');
+        out.push_str(BYTE_UNITS[unit]);
+        out
+    }
+
+    /// Formats a duration for humans, with its two most significant units.
+    ///
+    /// Durations of a second or more are written in days, hours, minutes
+    /// and seconds, leaving out the smaller parts; shorter ones are written
+    /// in whole milliseconds, microseconds or nanoseconds.
+    ///
+    /// This function requires the `humanize` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::humanize_duration(Duration::from_secs(3725)), "1h 2m");
+    /// assert_eq!(String2::humanize_duration(Duration::from_secs(3600)), "1h");
+    /// assert_eq!(String2::humanize_duration(Duration::from_millis(61_500)), "1m 1s");
+    /// assert_eq!(String2::humanize_duration(Duration::from_micros(2500)), "2ms");
+    /// assert_eq!(String2::humanize_duration(Duration::ZERO), "0s");
+    /// ```
+    pub fn humanize_duration(duration: Duration) -> String2 {
+        let mut out = String2::with_capacity(8);
+        let secs = duration.as_secs();
+
+        if secs == 0 {
+            let nanos = duration.subsec_nanos() as u64;
+            let (value, unit) = match nanos {
+                0 => (0, "s"),
+                1..=999 => (nanos, "ns"),
+                1_000..=999_999 => (nanos / 1_000, "µs"),
+                _ => (nanos / 1_000_000, "ms")
+            };
+
+            out.push_u64(value);
+            out.push_str(unit);
+            return out
+        }
+
+        let mut rest = secs;
+        let mut parts = DURATION_UNITS.iter()
+            .map(|&(size, unit)| {
+                let value = rest / size;
+                rest %= size;
+                (value, unit)
+            })
+            .skip_while(|&(value, _)| value == 0);
+
+        let (value, unit) = parts.next().unwrap();
+        out.push_u64(value);
+        out.push_str(unit);
+
+        if let Some((value, unit)) = parts.next().filter(|&(value, _)| value > 0) {
+            out.push(' ');
+            out.push_u64(value);
+            out.push_str(unit);
+        }
+
+        out
+    }
+
+    // Appends the decimal digits of `n`, without going through `fmt`.
+    fn push_u64(&mut self, mut n: u64) {
+        let mut digits = ['0'; 20];
+        let mut start = digits.len();
+
+        loop {
+            start -= 1;
+            digits[start] = (b'0' + (n % 10) as u8) as char;
+            n /= 10;
+            if n == 0 {
+                break
+            }
+        }
+
+        self.inner.extend_from_slice(&digits[start..]);
+        self.add_byte_len(digits.len() - start);
+    }
+}
@@ -0,0 +1,232 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::case::simple_fold;
+
+/// A Knuth–Liang hyphenator, which finds the points where words can be
+/// broken with a hyphen.
+///
+/// The rules of a language come as TeX hyphenation patterns, such as the
+/// `hyph-*.pat.txt` files of the `hyph-utf8` project, so any language with
+/// patterns can be plugged in. Each pattern is a run of letters with digits
+/// between them, like `hy3ph` or `.ach4`, where `.` marks the edge of a
+/// word; a word can be broken where the highest digit any matching pattern
+/// puts is odd. Words that the patterns get wrong can be listed as
+/// exceptions.
+///
+/// This type requires the `hyphenation` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{Hyphenator, String2};
+///
+/// let mut hyphenator = Hyphenator::new("hy3ph he2n hena4 hen5at 1na n2at 1tio 2io o2n");
+/// hyphenator.exceptions("ta-ble");
+///
+/// let word = String2::from("Hyphenation");
+/// assert_eq!(hyphenator.break_points(word.as_slice()), vec![2, 6]);
+/// assert_eq!(word.hyphenate(&hyphenator, '-'), "Hy-phen-ation");
+///
+/// assert_eq!(String2::from("table").hyphenate(&hyphenator, '-'), "ta-ble");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hyphenator {
+    // The letters of each pattern, folded, and the digits around them.
+    patterns: BTreeMap<Vec<char>, Vec<u8>>,
+    // The letters of each exception, folded, and its break points.
+    exceptions: BTreeMap<Vec<char>, Vec<usize>>,
+    max_pattern_len: usize,
+    left_min: usize,
+    right_min: usize
+}
+
+impl Hyphenator {
+    /// Creates a hyphenator from whitespace-separated TeX patterns.
+    ///
+    /// Breaks leave at least two chars before and three after them, as in
+    /// TeX for English; see [`min_lengths`] to change that.
+    ///
+    /// [`min_lengths`]: #method.min_lengths
+    pub fn new(patterns: &str) -> Hyphenator {
+        let mut hyphenator = Hyphenator {
+            patterns: BTreeMap::new(),
+            exceptions: BTreeMap::new(),
+            max_pattern_len: 0,
+            left_min: 2,
+            right_min: 3
+        };
+
+        for pattern in patterns.split_whitespace() {
+            let mut letters = Vec::new();
+            let mut values = vec![0];
+
+            for ch in pattern.chars() {
+                match ch.to_digit(10) {
+                    Some(digit) => *values.last_mut().unwrap() = digit as u8,
+                    None => {
+                        letters.push(simple_fold(ch));
+                        values.push(0);
+                    }
+                }
+            }
+
+            hyphenator.max_pattern_len = hyphenator.max_pattern_len.max(letters.len());
+            hyphenator.patterns.insert(letters, values);
+        }
+
+        hyphenator
+    }
+
+    /// Adds whitespace-separated exceptions, written with hyphens at their
+    /// break points, like `as-so-ciate`.
+    ///
+    /// An exception replaces the patterns for the word it spells, whatever
+    /// its case. Its break points are not limited by [`min_lengths`].
+    ///
+    /// [`min_lengths`]: #method.min_lengths
+    pub fn exceptions(&mut self, exceptions: &str) -> &mut Hyphenator {
+        for exception in exceptions.split_whitespace() {
+            let mut letters = Vec::new();
+            let mut points = Vec::new();
+
+            for ch in exception.chars() {
+                if ch == '-' {
+                    points.push(letters.len());
+                } else {
+                    letters.push(simple_fold(ch));
+                }
+            }
+
+            self.exceptions.insert(letters, points);
+        }
+
+        self
+    }
+
+    /// Sets the minimum number of chars kept before and after a break.
+    pub fn min_lengths(&mut self, left: usize, right: usize) -> &mut Hyphenator {
+        self.left_min = left.max(1);
+        self.right_min = right.max(1);
+        self
+    }
+
+    /// Returns the char indices in `word` where it can be broken, in
+    /// increasing order. A hyphen goes before the char at each index.
+    pub fn break_points(&self, word: &[char]) -> Vec<usize> {
+        let folded: Vec<char> = word.iter().map(|&ch| simple_fold(ch)).collect();
+
+        if let Some(points) = self.exceptions.get(&folded) {
+            return points.clone()
+        }
+        if word.len() < self.left_min + self.right_min {
+            return Vec::new()
+        }
+
+        // The word between edge markers, and the highest value put between
+        // each pair of its chars.
+        let mut dotted = Vec::with_capacity(folded.len() + 2);
+        dotted.push('.');
+        dotted.extend_from_slice(&folded);
+        dotted.push('.');
+        let mut values = vec![0u8; dotted.len() + 1];
+
+        for start in 0..dotted.len() {
+            let longest = self.max_pattern_len.min(dotted.len() - start);
+            for len in 1..=longest {
+                if let Some(pattern) = self.patterns.get(&dotted[start..start + len]) {
+                    for (value, &found) in values[start..].iter_mut().zip(pattern) {
+                        *value = (*value).max(found);
+                    }
+                }
+            }
+        }
+
+        // `values[idx + 1]` is the value before char `idx` of the word.
+        (self.left_min..=word.len() - self.right_min)
+            .filter(|&idx| values[idx + 1] % 2 == 1)
+            .collect()
+    }
+}
+
+impl String2 {
+    /// Inserts `hyphen` at every point where the words of this `String2`
+    /// can be broken, as found by `hyphenator`.
+    ///
+    /// Words are runs of alphabetic chars. Passing the soft hyphen,
+    /// `'\u{ad}'`, gives text that renderers break on their own, while
+    /// showing no hyphens where they do not break.
+    ///
+    /// This method requires the `hyphenation` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{Hyphenator, String2};
+    ///
+    /// let hyphenator = Hyphenator::new("hy3ph he2n hena4 hen5at 1na n2at 1tio 2io o2n");
+    ///
+    /// let s = String2::from("(hyphenation!)");
+    /// assert_eq!(s.hyphenate(&hyphenator, '\u{ad}'), "(hy\u{ad}phen\u{ad}ation!)");
+    /// ```
+    pub fn hyphenate(&self, hyphenator: &Hyphenator, hyphen: char) -> String2 {
+        let mut out = String2::with_capacity(self.len());
+
+        let mut idx = 0;
+        while idx < self.inner.len() {
+            let len = self.inner[idx..].iter().take_while(|ch| ch.is_alphabetic()).count();
+            if len == 0 {
+                out.push(self.inner[idx]);
+                idx += 1;
+                continue
+            }
+
+            let word = &self.inner[idx..idx + len];
+            let mut last = 0;
+            for point in hyphenator.break_points(word) {
+                out.extend(&word[last..point]);
+                out.push(hyphen);
+                last = point;
+            }
+            out.extend(&word[last..]);
+
+            idx += len;
+        }
+
+        out
+    }
+
+    /// Wraps this `String2` into lines of at most `width` chars, like
+    /// [`wrap`], but breaks words that do not fit at the end of a line with
+    /// a hyphen, where `hyphenator` allows it.
+    ///
+    /// Words with no break point that fits are moved to the next line, or
+    /// cut at the width if they do not fit on a line of their own.
+    ///
+    /// This method requires the `hyphenation` feature.
+    ///
+    /// [`wrap`]: #method.wrap
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{Hyphenator, String2};
+    ///
+    /// let hyphenator = Hyphenator::new("hy3ph he2n hena4 hen5at 1na n2at 1tio 2io o2n");
+    /// let s = String2::from("Automatic hyphenation");
+    ///
+    /// assert_eq!(s.wrap(15), vec!["Automatic", "hyphenation"]);
+    /// assert_eq!(s.wrap_hyphenated(15, &hyphenator), vec!["Automatic hy-", "phenation"]);
+    /// ```
+    #[inline]
+    pub fn wrap_hyphenated(&self, width: usize, hyphenator: &Hyphenator) -> Vec<String2> {
+        crate::wrap::wrap_with(&self.inner, width, |word| hyphenator.break_points(word))
+    }
+}
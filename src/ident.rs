@@ -0,0 +1,92 @@
+use unicode_xid::UnicodeXID;
+
+use crate::String2;
+
+#[inline]
+fn is_start(ch: char) -> bool {
+    ch == '_' || UnicodeXID::is_xid_start(ch)
+}
+
+#[inline]
+fn is_continue(ch: char) -> bool {
+    UnicodeXID::is_xid_continue(ch)
+}
+
+impl String2 {
+    /// Returns `true` if this `String2` is a valid identifier, per the
+    /// `XID_Start` and `XID_Continue` properties of UAX #31.
+    ///
+    /// As in Rust and most other languages, an underscore is also accepted
+    /// as the first char. An empty string is not an identifier.
+    ///
+    /// This method requires the `unicode-xid` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert!(String2::from("foo_bar2").is_xid_identifier());
+    /// assert!(String2::from("_private").is_xid_identifier());
+    /// assert!(String2::from("变量").is_xid_identifier());
+    ///
+    /// assert!(!String2::from("2fast").is_xid_identifier());
+    /// assert!(!String2::from("kebab-case").is_xid_identifier());
+    /// assert!(!String2::new().is_xid_identifier());
+    /// ```
+    pub fn is_xid_identifier(&self) -> bool {
+        match self.inner.split_first() {
+            Some((&first, rest)) => is_start(first) && rest.iter().all(|&c| is_continue(c)),
+            None => false
+        }
+    }
+
+    /// Turns this `String2` into a valid identifier, as checked by
+    /// [`is_xid_identifier`].
+    ///
+    /// Every char that may not appear in an identifier is replaced with an
+    /// underscore. If the first char may continue but not start an
+    /// identifier, like a digit, an underscore is put in front of it. An
+    /// empty string becomes `"_"`.
+    ///
+    /// This method requires the `unicode-xid` feature.
+    ///
+    /// [`is_xid_identifier`]: #method.is_xid_identifier
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("content-type").to_valid_identifier(), String2::from("content_type"));
+    /// assert_eq!(String2::from("3d model").to_valid_identifier(), String2::from("_3d_model"));
+    /// assert_eq!(String2::from("ok").to_valid_identifier(), String2::from("ok"));
+    /// assert_eq!(String2::new().to_valid_identifier(), String2::from("_"));
+    /// ```
+    pub fn to_valid_identifier(&self) -> String2 {
+        let mut ident = String2::with_capacity(self.len() + 1);
+
+        for (idx, &ch) in self.inner.iter().enumerate() {
+            if idx == 0 && !is_start(ch) {
+                ident.push('_');
+                if is_continue(ch) {
+                    ident.push(ch);
+                }
+            } else if is_continue(ch) {
+                ident.push(ch);
+            } else {
+                ident.push('_');
+            }
+        }
+
+        if ident.is_empty() {
+            ident.push('_');
+        }
+
+        ident
+    }
+}
@@ -0,0 +1,132 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::arc::ArcString2;
+use crate::view::View2;
+
+/// A copyable id of a string stored in an [`Interner`].
+///
+/// Two symbols of the same interner are equal exactly when their strings
+/// are, so comparing them is as cheap as comparing integers.
+///
+/// [`Interner`]: struct.Interner.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the index of this symbol, which counts the strings interned
+    /// before it.
+    #[inline]
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A pool that stores each distinct string once, and names it by a
+/// [`Symbol`].
+///
+/// Interning a string that is already in the pool returns the symbol it
+/// was given the first time, so parsers and compilers can turn the many
+/// repeated identifiers of a program into symbols, and compare those
+/// instead. The strings are kept until the interner is dropped.
+///
+/// [`Symbol`]: struct.Symbol.html
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::Interner;
+///
+/// let mut interner = Interner::new();
+/// let x = interner.intern("x");
+/// let y = interner.intern("y");
+///
+/// assert_eq!(interner.intern("x"), x);
+/// assert_ne!(x, y);
+/// assert_eq!(interner.resolve(y), "y");
+/// assert_eq!(interner.get("z"), None);
+/// assert_eq!(interner.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    // Both hold the same shared strings, so each is only stored once.
+    symbols: BTreeMap<ArcString2, Symbol>,
+    strings: Vec<ArcString2>
+}
+
+impl Interner {
+    /// Creates a new empty `Interner`.
+    #[inline]
+    pub fn new() -> Interner {
+        Interner::default()
+    }
+
+    /// Returns the number of distinct strings in this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if this interner holds no strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Returns the symbol of `string`, adding it to this interner first if
+    /// it is not there yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interner already holds `u32::MAX` strings.
+    #[inline]
+    pub fn intern(&mut self, string: &str) -> Symbol {
+        let chars: Vec<char> = string.chars().collect();
+        self.intern_chars(&chars)
+    }
+
+    /// Returns the symbol of the string made of `chars`, adding it to this
+    /// interner first if it is not there yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interner already holds `u32::MAX` strings.
+    pub fn intern_chars(&mut self, chars: &[char]) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(chars) {
+            return symbol
+        }
+
+        assert!(self.strings.len() < u32::MAX as usize, "too many strings in interner");
+        let symbol = Symbol(self.strings.len() as u32);
+        let string = String2::from(chars).into_shared();
+
+        self.strings.push(string.clone());
+        self.symbols.insert(string, symbol);
+
+        symbol
+    }
+
+    /// Returns the symbol of `string`, or `None` if it has not been
+    /// interned.
+    #[inline]
+    pub fn get(&self, string: &str) -> Option<Symbol> {
+        let chars: Vec<char> = string.chars().collect();
+        self.symbols.get(&chars[..]).cloned()
+    }
+
+    /// Returns the string named by `symbol`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `symbol` does not come from this interner.
+    #[inline]
+    pub fn resolve(&self, symbol: Symbol) -> View2<'_> {
+        match self.strings.get(symbol.index()) {
+            Some(string) => string.view(),
+            None => panic!("symbol {} does not belong to this interner", symbol.0)
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use core::cmp::Reverse;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::reading::words;
+
+// Lowercases a word, and straightens its apostrophes so that "don’t" and
+// "don't" count as the same word.
+fn normalize<I: IntoIterator<Item = char>>(word: I) -> Vec<char> {
+    word.into_iter()
+        .flat_map(char::to_lowercase)
+        .map(|ch| if ch == '’' { '\'' } else { ch })
+        .collect()
+}
+
+impl String2 {
+    /// Returns the `k` most frequent words of this `String2`, with how many
+    /// times each occurs, leaving out `stopwords`.
+    ///
+    /// Words are runs of alphanumeric chars, which may have apostrophes
+    /// inside them. They are lowercased before being counted and compared
+    /// with the stopwords, which are lowercased too. The words are returned
+    /// lowercased, from the most to the least frequent; words that occur
+    /// equally often are ordered by code point.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("The cat sat. The cat ran! A dog sat with the cat.");
+    /// let top = s.top_words(3, &["the", "a", "with"]);
+    ///
+    /// assert_eq!(top, vec![
+    ///     (String2::from("cat"), 3),
+    ///     (String2::from("sat"), 2),
+    ///     (String2::from("dog"), 1)
+    /// ]);
+    /// ```
+    pub fn top_words(&self, k: usize, stopwords: &[&str]) -> Vec<(String2, usize)> {
+        let stopwords: BTreeSet<Vec<char>> = stopwords.iter().map(|word| normalize(word.chars())).collect();
+
+        let mut counts = BTreeMap::new();
+        for word in words(&self.inner) {
+            let word = normalize(word.iter().cloned());
+            if !stopwords.contains(&word) {
+                *counts.entry(word).or_insert(0) += 1;
+            }
+        }
+
+        // The map iterates by code point, and the sort is stable.
+        let mut top: Vec<(Vec<char>, usize)> = counts.into_iter().collect();
+        top.sort_by_key(|&(_, count)| Reverse(count));
+        top.truncate(k);
+
+        top.into_iter().map(|(word, count)| (String2::from(word), count)).collect()
+    }
+}
@@ -46,9 +46,221 @@
 //! [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
 //! [`from`]: #method.from
 //! [`into`]: #method.into
-
-use std::ops;
-use std::fmt;
+//!
+//! # `no_std` support
+//!
+//! The crate only needs `core` and `alloc`. The `std` feature, enabled by
+//! default, adds the parts that need the standard library, such as
+//! [`Lines2`] and the `utf8-cache` feature. Disable the default features to
+//! use `String2` without `std`:
+//!
+//! ```toml
+//! [dependencies]
+//! string2 = { version = "0.1", default-features = false }
+//! ```
+//!
+//! [`Lines2`]: struct.Lines2.html
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate core;
+#[macro_use]
+extern crate alloc;
+
+#[cfg(feature = "unicode-normalization")]
+extern crate unicode_normalization;
+#[cfg(feature = "unicode-xid")]
+extern crate unicode_xid;
+#[cfg(feature = "unicode-width")]
+extern crate unicode_width;
+#[cfg(feature = "schemars")]
+extern crate schemars;
+#[cfg(feature = "sqlx")]
+extern crate sqlx;
+#[cfg(feature = "rusqlite")]
+extern crate rusqlite;
+#[cfg(feature = "tracing")]
+extern crate tracing;
+#[cfg(feature = "log")]
+extern crate log;
+#[cfg(feature = "bytes")]
+extern crate bytes;
+#[cfg(feature = "tokio")]
+extern crate tokio;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "rkyv")]
+extern crate rkyv;
+#[cfg(feature = "arbitrary")]
+extern crate arbitrary;
+// Renamed so that it does not clash with the `proptest` module.
+#[cfg(feature = "proptest")]
+extern crate proptest as proptest_crate;
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
+#[cfg(feature = "allocator_api")]
+extern crate allocator_api2;
+
+use core::ops;
+use core::fmt;
+use alloc::vec::{self, Vec};
+use alloc::string::String;
+use alloc::collections::TryReserveError;
+use core::hash;
+#[cfg(feature = "utf8-cache")]
+use core::borrow::Borrow;
+use core::str;
+use core::convert;
+use core::iter::{FromIterator, FusedIterator};
+use core::mem::MaybeUninit;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+#[cfg(feature = "utf8-cache")]
+use std::sync::OnceLock;
+
+#[macro_use]
+mod macros;
+mod pattern;
+mod split;
+mod needle;
+mod chars;
+mod dump;
+mod case;
+mod convert_case;
+mod prefix_list;
+mod bounded;
+mod nonempty;
+mod validated;
+mod sortable;
+mod shard;
+#[cfg(feature = "std")]
+mod read_lines;
+mod columns;
+mod line_ops;
+mod patch;
+mod diff;
+mod merge;
+mod edit_log;
+mod snapshot;
+mod explain;
+mod affixes;
+mod tile;
+mod longest;
+mod numbers;
+mod abbrev;
+mod plural;
+mod format_named;
+#[cfg(feature = "message-format")]
+mod message_format;
+#[cfg(feature = "hyphenation")]
+mod hyphenate;
+mod expand_env;
+mod paths;
+mod snippet;
+mod wrap;
+mod reading;
+mod keywords;
+mod typography;
+mod escape;
+mod small;
+mod array;
+mod rope;
+mod gap_buffer;
+mod piece_table;
+mod history;
+mod view;
+mod shared;
+mod arc;
+mod fingerprint;
+mod interner;
+mod bloom;
+#[cfg(feature = "unicode-normalization")]
+mod normalize;
+#[cfg(feature = "unicode-xid")]
+mod ident;
+#[cfg(feature = "unicode-width")]
+mod width;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(any(feature = "sqlx", feature = "rusqlite"))]
+mod db;
+#[cfg(any(feature = "tracing", feature = "log"))]
+mod logging;
+#[cfg(feature = "bytes")]
+mod bytes_impls;
+#[cfg(feature = "tokio")]
+mod async_io;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "rkyv")]
+mod rkyv_impls;
+#[cfg(feature = "humanize")]
+mod humanize;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls;
+#[cfg(feature = "allocator_api")]
+mod allocator;
+
+pub use pattern::{CharPattern, CharSeq};
+pub use needle::{Needle2, FindIter};
+pub use chars::{Chars, CharIndices};
+pub use prefix_list::{PrefixCompressedList, PrefixCompressedIter};
+pub use bounded::{BoundedString2, LengthError};
+pub use nonempty::{NonEmptyString2, EmptyError};
+pub use validated::ValidationError;
+#[cfg(feature = "std")]
+pub use read_lines::Lines2;
+pub use line_ops::LineOrder;
+pub use wrap::Alignment;
+pub use patch::PatchError;
+pub use merge::{Merge3, Conflict};
+pub use edit_log::{EditLog2, EditOp, CharId, UnknownCharError};
+pub use snapshot::Snapshot2;
+pub use explain::Difference;
+pub use affixes::{Prefixes, Suffixes};
+pub use longest::String2IterExt;
+pub use convert_case::IdentifierWords;
+pub use plural::PluralRules;
+pub use format_named::FormatError;
+pub use expand_env::ExpandEnvError;
+pub use small::SmallString2;
+pub use array::ArrayString2;
+pub use rope::{Rope2, RopeChars, RopeChunks};
+pub use gap_buffer::GapBuffer2;
+pub use piece_table::{PieceChars, PieceSnapshot, PieceTable2};
+pub use history::EditableString2;
+pub use view::{View2, ViewSplit};
+pub use shared::SharedString2;
+pub use arc::ArcString2;
+pub use escape::{NulCharError, ShellDialect, SqlDialect};
+pub use interner::{Interner, Symbol};
+pub use bloom::StringBloom;
+#[cfg(feature = "unicode-normalization")]
+pub use normalize::Form;
+#[cfg(feature = "tokio")]
+pub use async_io::{ReadToString2, WriteUtf8};
+#[cfg(feature = "serde")]
+pub use serde_impls::{as_chars, as_code_points};
+#[cfg(feature = "rkyv")]
+pub use rkyv_impls::ArchivedString2;
+#[cfg(feature = "allocator_api")]
+pub use allocator::String2In;
+#[cfg(feature = "hyphenation")]
+pub use hyphenate::Hyphenator;
+pub use split::{SplitInclusive, SplitKeepDelimiter, DelimiterMode, Lines, LinesWithTerminators};
+
+// Paths used by the exported macros, which must work in `no_std` crates
+// and without an `extern crate core` in 2015 edition ones.
+#[doc(hidden)]
+pub mod __private {
+    pub use core::{convert, fmt, mem, ops, option, result};
+}
 
 /// A UTF-8 encoded, growable string.
 ///
@@ -200,9 +412,25 @@ use std::fmt;
 /// ```
 ///
 /// Here, there's no need to allocate more memory inside the loop.
-#[derive(Clone, Eq, Ord)]
+#[derive(Clone, Eq)]
 pub struct String2 {
-    inner: Vec<char>
+    inner: Vec<char>,
+    // The length of the UTF-8 encoding of `inner`, or `UNKNOWN_BYTE_LEN`
+    // after the chars were handed out mutably.
+    byte_len: usize,
+    // The UTF-8 encoding of `inner`, built on first use and dropped by any
+    // change to the chars. Every change goes through `set_byte_len`.
+    #[cfg(feature = "utf8-cache")]
+    utf8: OnceLock<Box<str>>
+}
+
+// Marks `byte_len` as stale; it is counted again on the next tracked edit.
+const UNKNOWN_BYTE_LEN: usize = usize::MAX;
+
+// The length of the UTF-8 encoding of `chars`, in bytes.
+#[inline]
+fn utf8_len(chars: &[char]) -> usize {
+    chars.iter().map(|ch| ch.len_utf8()).sum()
 }
 
 impl String2 {
@@ -228,9 +456,7 @@ impl String2 {
     /// ```
     #[inline]
     pub fn new() -> String2 {
-        String2 {
-            inner: Vec::new()
-        }
+        String2::from_parts(Vec::new(), 0)
     }
 
     /// Creates a new empty `String2` with a particular capacity.
@@ -274,9 +500,7 @@ impl String2 {
     /// ```
     #[inline]
     pub fn with_capacity(capacity: usize) -> String2 {
-        String2 {
-            inner: Vec::with_capacity(capacity)
-        }
+        String2::from_parts(Vec::with_capacity(capacity), 0)
     }
 
     /// Returns this `String2`'s capacity, in bytes.
@@ -347,6 +571,7 @@ impl String2 {
     /// ```
     #[inline]
     pub fn reserve(&mut self, additional: usize) {
+        self.settle_byte_len();
         self.inner.reserve(additional);
     }
 
@@ -397,9 +622,80 @@ impl String2 {
     /// ```
     #[inline]
     pub fn reserve_exact(&mut self, additional: usize) {
+        self.settle_byte_len();
         self.inner.reserve_exact(additional);
     }
 
+    /// Tries to reserve capacity for at least `additional` more chars.
+    ///
+    /// This is the fallible form of [`reserve`]: instead of panicking or
+    /// aborting, it returns an error if the capacity overflows or the
+    /// allocator reports a failure, which lets a server reject an
+    /// oversized input instead of going down with it.
+    ///
+    /// [`reserve`]: #method.reserve
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryReserveError`] if the capacity overflows or the
+    /// allocation fails. The string is left unchanged.
+    ///
+    /// [`TryReserveError`]: https://doc.rust-lang.org/std/collections/struct.TryReserveError.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("ab");
+    ///
+    /// s.try_reserve(10).unwrap();
+    /// assert!(s.capacity() >= 12);
+    ///
+    /// assert!(s.try_reserve(usize::MAX).is_err());
+    /// assert_eq!(s, "ab");
+    /// ```
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.settle_byte_len();
+        self.inner.try_reserve(additional)
+    }
+
+    /// Tries to reserve capacity for exactly `additional` more chars.
+    ///
+    /// This is the fallible form of [`reserve_exact`].
+    ///
+    /// [`reserve_exact`]: #method.reserve_exact
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryReserveError`] if the capacity overflows or the
+    /// allocation fails. The string is left unchanged.
+    ///
+    /// [`TryReserveError`]: https://doc.rust-lang.org/std/collections/struct.TryReserveError.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::new();
+    ///
+    /// s.try_reserve_exact(10).unwrap();
+    /// assert!(s.capacity() >= 10);
+    ///
+    /// assert!(s.try_reserve_exact(usize::MAX / 2).is_err());
+    /// ```
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.settle_byte_len();
+        self.inner.try_reserve_exact(additional)
+    }
+
     /// Shrinks the capacity of this `String2` to match its length.
     ///
     /// # Examples
@@ -419,9 +715,40 @@ impl String2 {
     /// ```
     #[inline]
     pub fn shrink_to_fit(&mut self) {
+        self.settle_byte_len();
         self.inner.shrink_to_fit();
     }
 
+    /// Shrinks the capacity of this `String2` with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both the length and
+    /// the supplied value. If the current capacity is less than the lower
+    /// limit, this is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("foo");
+    ///
+    /// s.reserve(100);
+    /// assert!(s.capacity() >= 100);
+    ///
+    /// s.shrink_to(10);
+    /// assert!(s.capacity() >= 10);
+    ///
+    /// s.shrink_to(0);
+    /// assert!(s.capacity() >= 3);
+    /// ```
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.settle_byte_len();
+        self.inner.shrink_to(min_capacity);
+    }
+
     /// Converts a `String2` to a raw pointer.
     /// As `String2` are a vector of chars, the raw pointer points to a char.
     /// This pointer will be pointing to the first byte of the `String2`.
@@ -483,12 +810,13 @@ impl String2 {
     /// ```
     #[inline]
     pub unsafe fn from_raw_parts(buf: *mut char, length: usize, capacity: usize) -> String2 {
-        String2 {
-            inner: Vec::from_raw_parts(buf, length, capacity)
-        }
+        String2::from(Vec::from_raw_parts(buf, length, capacity))
     }
 
-    /// Converts a `String2` into a byte vector.
+    /// Returns the UTF-8 encoding of this `String2` as a byte vector.
+    ///
+    /// The chars are encoded straight into a vector allocated once with
+    /// the exact length needed.
     ///
     /// # Examples
     ///
@@ -504,8 +832,176 @@ impl String2 {
     /// ```
     #[inline]
     pub fn as_bytes(&self) -> Vec<u8> {
-        let s: String = self.clone().into();
-        s.into_bytes()
+        let mut bytes = Vec::new();
+        self.encode_utf8_into(&mut bytes);
+        bytes
+    }
+
+    /// Appends the UTF-8 encoding of this `String2` to `buf`.
+    ///
+    /// `buf` grows at most once, so encoding many strings into the same
+    /// buffer avoids any intermediate allocation.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut buf = b"key=".to_vec();
+    /// String2::from("välue").encode_utf8_into(&mut buf);
+    ///
+    /// assert_eq!(buf, "key=välue".as_bytes());
+    /// ```
+    pub fn encode_utf8_into(&self, buf: &mut Vec<u8>) {
+        #[cfg(feature = "utf8-cache")]
+        buf.extend_from_slice(self.as_str().as_bytes());
+
+        #[cfg(not(feature = "utf8-cache"))]
+        {
+            buf.reserve(self.byte_len());
+
+            for ch in &self.inner {
+                buf.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+        }
+    }
+
+    /// Returns the UTF-8 encoding of this `String2` as a string slice.
+    ///
+    /// The encoding is built on the first call and kept until the string
+    /// is changed, so later calls, and `Display`, `as_bytes` and the
+    /// conversions to `String`, do not encode the chars again.
+    ///
+    /// This method requires the `utf8-cache` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("grüße");
+    /// assert_eq!(s.as_str(), "grüße");
+    ///
+    /// s.push('!');
+    /// assert_eq!(s.as_str(), "grüße!");
+    /// ```
+    #[cfg(feature = "utf8-cache")]
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.utf8.get_or_init(|| {
+            let mut out = String::with_capacity(self.byte_len());
+            out.extend(&self.inner);
+            out.into_boxed_str()
+        })
+    }
+
+    /// Returns the length of the UTF-8 encoding of this `String2`, in bytes.
+    ///
+    /// The count is kept up to date as the string is edited, so this is
+    /// cheap, which makes it suitable for enforcing byte-size limits.
+    ///
+    /// The exception is after chars were handed out mutably, through
+    /// [`as_mut_slice`], [`as_mut_vec`], [`get_mut`], [`set_len`] or
+    /// indexing, as the string cannot see what was written. Until the next
+    /// call of another method taking `&mut self`, which counts the bytes
+    /// again once, every call of `byte_len` counts them, in O(n) time.
+    ///
+    /// [`as_mut_slice`]: #method.as_mut_slice
+    /// [`as_mut_vec`]: #method.as_mut_vec
+    /// [`get_mut`]: #method.get_mut
+    /// [`set_len`]: #method.set_len
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("héllo");
+    /// assert_eq!(s.len(), 5);
+    /// assert_eq!(s.byte_len(), 6);
+    ///
+    /// s.push('😀');
+    /// assert_eq!(s.byte_len(), 10);
+    ///
+    /// s[0] = 'ħ';
+    /// assert_eq!(s.byte_len(), 11);
+    /// assert_eq!(s.byte_len(), String::from(&s).len());
+    /// ```
+    #[inline]
+    pub fn byte_len(&self) -> usize {
+        if self.byte_len == UNKNOWN_BYTE_LEN {
+            utf8_len(&self.inner)
+        } else {
+            self.byte_len
+        }
+    }
+
+    // Counts the bytes again if the chars were handed out mutably. Called
+    // by every method taking `&mut self` that does not update the count
+    // itself, so that the count is only recomputed once.
+    #[inline]
+    fn settle_byte_len(&mut self) {
+        if self.byte_len == UNKNOWN_BYTE_LEN {
+            // The chars are unchanged, so the cached encoding stays valid.
+            self.byte_len = utf8_len(&self.inner);
+        }
+    }
+
+    // Records that `n` bytes were added. Must be called after the chars
+    // were changed, so that a stale count is taken from the new chars.
+    #[inline]
+    fn add_byte_len(&mut self, n: usize) {
+        if self.byte_len == UNKNOWN_BYTE_LEN {
+            self.recount_byte_len();
+        } else {
+            let byte_len = self.byte_len + n;
+            self.set_byte_len(byte_len);
+        }
+    }
+
+    // Records that `n` bytes were removed, like `add_byte_len`.
+    #[inline]
+    fn sub_byte_len(&mut self, n: usize) {
+        if self.byte_len == UNKNOWN_BYTE_LEN {
+            self.recount_byte_len();
+        } else {
+            let byte_len = self.byte_len - n;
+            self.set_byte_len(byte_len);
+        }
+    }
+
+    #[inline]
+    fn from_parts(inner: Vec<char>, byte_len: usize) -> String2 {
+        String2 {
+            inner,
+            byte_len,
+            #[cfg(feature = "utf8-cache")]
+            utf8: OnceLock::new()
+        }
+    }
+
+    // Updates the byte count after a change to the chars, which also makes
+    // the cached UTF-8 encoding stale.
+    #[inline]
+    fn set_byte_len(&mut self, byte_len: usize) {
+        self.byte_len = byte_len;
+        #[cfg(feature = "utf8-cache")]
+        {
+            self.utf8 = OnceLock::new();
+        }
+    }
+
+    // Counts the bytes again, after `inner` was changed directly.
+    #[inline]
+    pub(crate) fn recount_byte_len(&mut self) {
+        let byte_len = utf8_len(&self.inner);
+        self.set_byte_len(byte_len);
     }
 
     /// Converts a `String2` into a char slice.
@@ -533,6 +1029,11 @@ impl String2 {
     ///
     /// This consumes the `String2`, so we do not need to copy its contents.
     ///
+    /// Until the next call of another method taking `&mut self`, every call
+    /// of [`byte_len`] counts the bytes again, in O(n) time.
+    ///
+    /// [`byte_len`]: #method.byte_len
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -550,6 +1051,7 @@ impl String2 {
     /// ```
     #[inline]
     pub fn as_mut_slice(&mut self) -> &mut [char] {
+        self.set_byte_len(UNKNOWN_BYTE_LEN);
         self.inner.as_mut_slice()
     }
 
@@ -578,6 +1080,11 @@ impl String2 {
     ///
     /// This consumes the `String2`, so we do not need to copy its contents.
     ///
+    /// Until the next call of another method taking `&mut self`, every call
+    /// of [`byte_len`] counts the bytes again, in O(n) time.
+    ///
+    /// [`byte_len`]: #method.byte_len
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -595,14 +1102,135 @@ impl String2 {
     /// ```
     #[inline]
     pub fn as_mut_vec(&mut self) -> &mut Vec<char> {
+        self.set_byte_len(UNKNOWN_BYTE_LEN);
         &mut self.inner
     }
 
+    /// Converts a `String2` into a boxed char slice.
+    ///
+    /// This drops any excess capacity, so the box holds exactly the chars
+    /// of the string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::with_capacity(10);
+    /// s.push_str("hello");
+    ///
+    /// let chars: Box<[char]> = s.into_boxed_slice();
+    ///
+    /// assert_eq!(&['h', 'e', 'l', 'l', 'o'], &chars[..]);
+    /// ```
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[char]> {
+        self.inner.into_boxed_slice()
+    }
+
+    /// Consumes and leaks the `String2`, returning a mutable reference to
+    /// its chars.
+    ///
+    /// The chars live for the rest of the program, which suits strings
+    /// built once at startup and read until exit. The memory is never
+    /// freed, and the excess capacity is not given back, so call
+    /// [`shrink_to_fit`] first if it matters.
+    ///
+    /// [`shrink_to_fit`]: #method.shrink_to_fit
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let name: &'static mut [char] = String2::from("string2").leak();
+    /// name[0] = 'S';
+    ///
+    /// assert_eq!(String2::from(&name[..]), "String2");
+    /// ```
+    #[inline]
+    pub fn leak<'a>(self) -> &'a mut [char] {
+        self.inner.leak()
+    }
+
+    /// Returns the spare capacity of this `String2`, past its length, as a
+    /// slice of `MaybeUninit<char>`.
+    ///
+    /// Together with [`set_len`], this lets decoders and FFI code write
+    /// chars straight into the buffer, without first filling it with
+    /// placeholder chars.
+    ///
+    /// [`set_len`]: #method.set_len
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("ab");
+    /// s.reserve(2);
+    ///
+    /// let spare = s.spare_capacity_mut();
+    /// spare[0].write('c');
+    /// spare[1].write('d');
+    ///
+    /// unsafe {
+    ///     s.set_len(4);
+    /// }
+    ///
+    /// assert_eq!(s, "abcd");
+    /// assert_eq!(s.byte_len(), 4);
+    /// ```
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<char>] {
+        self.settle_byte_len();
+        self.inner.spare_capacity_mut()
+    }
+
+    /// Sets the length of this `String2` to `new_len` chars.
+    ///
+    /// This is a low-level operation that keeps none of the invariants of
+    /// the type; it is meant to follow writes through
+    /// [`spare_capacity_mut`]. To shorten the string, use [`truncate`].
+    ///
+    /// Until the next call of another method taking `&mut self`, every call
+    /// of [`byte_len`] counts the bytes again, in O(n) time.
+    ///
+    /// [`spare_capacity_mut`]: #method.spare_capacity_mut
+    /// [`truncate`]: #method.truncate
+    /// [`byte_len`]: #method.byte_len
+    ///
+    /// # Safety
+    ///
+    /// * `new_len` must be less than or equal to [`capacity`].
+    /// * The chars at `old_len..new_len` must be initialized.
+    ///
+    /// [`capacity`]: #method.capacity
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        self.inner.set_len(new_len);
+        self.set_byte_len(UNKNOWN_BYTE_LEN);
+    }
+
     #[inline]
-    pub fn retain<F>(&mut self, f: F)
+    pub fn retain<F>(&mut self, mut f: F)
         where F: FnMut(&char) -> bool
     {
-        self.inner.retain(f)
+        let mut removed = 0;
+        self.inner.retain(|ch| {
+            let keep = f(ch);
+            if !keep {
+                removed += ch.len_utf8();
+            }
+            keep
+        });
+        self.sub_byte_len(removed);
     }
 
     #[inline]
@@ -610,39 +1238,58 @@ impl String2 {
         self.inner.get(idx)
     }
 
+    /// Returns a mutable reference to the char at char index `idx`, or
+    /// `None` if it is out of bounds.
+    ///
+    /// Until the next call of another method taking `&mut self`, every call
+    /// of [`byte_len`] counts the bytes again, in O(n) time.
+    ///
+    /// [`byte_len`]: #method.byte_len
     #[inline]
     pub fn get_mut(&mut self, idx: usize) -> Option<&mut char> {
+        self.set_byte_len(UNKNOWN_BYTE_LEN);
         self.inner.get_mut(idx)
     }
 
     #[inline]
     pub fn truncate(&mut self, new_len: usize) {
-        self.inner.truncate(new_len);
+        if new_len < self.inner.len() {
+            let removed = utf8_len(&self.inner[new_len..]);
+            self.inner.truncate(new_len);
+            self.sub_byte_len(removed);
+        }
     }
 
     #[inline]
     pub fn push(&mut self, ch: char) {
         self.inner.push(ch);
+        self.add_byte_len(ch.len_utf8());
     }
 
     #[inline]
     pub fn push_str(&mut self, string: &str) {
-        self.inner.extend(string.chars())
+        self.inner.extend(string.chars());
+        self.add_byte_len(string.len());
     }
 
     #[inline]
     pub fn pop(&mut self) -> Option<char> {
-        self.inner.pop()
+        let ch = self.inner.pop()?;
+        self.sub_byte_len(ch.len_utf8());
+        Some(ch)
     }
 
     #[inline]
     pub fn remove(&mut self, idx: usize) -> char {
-        self.inner.remove(idx)
+        let ch = self.inner.remove(idx);
+        self.sub_byte_len(ch.len_utf8());
+        ch
     }
 
     #[inline]
     pub fn insert(&mut self, idx: usize, ch: char) {
         self.inner.insert(idx, ch);
+        self.add_byte_len(ch.len_utf8());
     }
 
     #[inline]
@@ -650,9 +1297,62 @@ impl String2 {
         
     }
 
+    /// Replaces the specified range in this `String2` with the chars of
+    /// `replace_with`, and returns an iterator over the removed chars.
+    ///
+    /// `replace_with` does not need to be the same length as `range`. The
+    /// range is removed even if the iterator is not consumed until the end.
+    /// The byte length of the string is kept up to date, as long as the
+    /// returned iterator is dropped rather than leaked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the
+    /// end point is greater than the length of the string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("α is alpha, β is beta");
+    /// let beta_offset = 12;
+    ///
+    /// let removed: String2 = s.splice(..beta_offset, "Α is capital alpha; ".chars()).collect::<Vec<_>>().into();
+    ///
+    /// assert_eq!(removed, String2::from("α is alpha, "));
+    /// assert_eq!(s, String2::from("Α is capital alpha; β is beta"));
+    /// assert_eq!(s.byte_len(), "Α is capital alpha; β is beta".len());
+    /// ```
+    #[inline]
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter>
+        where R: ops::RangeBounds<usize>, I: IntoIterator<Item = char>
+    {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        let removed = utf8_len(&self.inner[bounds]);
+        let byte_len = self.byte_len() - removed;
+        self.set_byte_len(byte_len);
+
+        // The inserted chars are counted as `Vec::splice` takes them, which
+        // it does before the iterator is dropped.
+        let replace_with = SpliceBytes {
+            iter: replace_with.into_iter(),
+            byte_len: &mut self.byte_len
+        };
+
+        Splice {
+            inner: self.inner.splice(bounds, replace_with)
+        }
+    }
+
     #[inline]
     pub fn append(&mut self, other: &mut Self) {
-        self.inner.append(&mut other.inner)
+        let added = other.byte_len();
+        self.inner.append(&mut other.inner);
+        other.set_byte_len(0);
+        self.add_byte_len(added);
     }
 
     #[inline]
@@ -667,85 +1367,563 @@ impl String2 {
 
     #[inline]
     pub fn split_off(&mut self, at: usize) -> String2 {
-        let other = self.inner.split_off(at);
+        let other = String2::from(self.inner.split_off(at));
+        self.sub_byte_len(other.byte_len);
 
-        String2 {
-            inner: other
-        }
+        other
     }
 
     #[inline]
     pub fn split_at(&self, mid: usize) -> (String2, String2) {
         let (a, b) = self.inner.split_at(mid);
 
-        (String2 { inner: a.to_vec() }, String2 { inner: b.to_vec() })
+        (String2::from(a), String2::from(b))
     }
 
+    /// Returns a char slice of this `String2` with leading and trailing
+    /// whitespace removed.
+    ///
+    /// Whitespace is defined according to the terms of the Unicode Derived
+    /// Core Property `White_Space`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("\u{3000} Hello\tworld\t\n");
+    ///
+    /// assert_eq!(&['H', 'e', 'l', 'l', 'o', '\t', 'w', 'o', 'r', 'l', 'd'][..], s.trim());
+    /// ```
     #[inline]
-    pub fn clear(&mut self) {
-        self.inner.clear()
+    pub fn trim(&self) -> &[char] {
+        let start = self.trim_start_len();
+        let end = self.len() - self.trim_end_len();
+
+        if start >= end {
+            &[]
+        } else {
+            &self.inner[start..end]
+        }
     }
 
-    #[inline]
-    pub fn iter(self) -> StrIterator {
+    /// Returns a char slice of this `String2` with leading whitespace
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("  hi ");
+    ///
+    /// assert_eq!(&['h', 'i', ' '][..], s.trim_start());
+    /// ```
+    #[inline]
+    pub fn trim_start(&self) -> &[char] {
+        &self.inner[self.trim_start_len()..]
+    }
+
+    /// Returns a char slice of this `String2` with trailing whitespace
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("  hi ");
+    ///
+    /// assert_eq!(&[' ', ' ', 'h', 'i'][..], s.trim_end());
+    /// ```
+    #[inline]
+    pub fn trim_end(&self) -> &[char] {
+        &self.inner[..self.len() - self.trim_end_len()]
+    }
+
+    /// Removes leading and trailing whitespace from this `String2`, in
+    /// place.
+    ///
+    /// This does not allocate, and does not change the capacity.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("\t hello world \n");
+    /// s.trim_in_place();
+    ///
+    /// assert_eq!(String2::from("hello world"), s);
+    /// ```
+    #[inline]
+    pub fn trim_in_place(&mut self) {
+        self.trim_end_in_place();
+        self.trim_start_in_place();
+    }
+
+    /// Removes leading whitespace from this `String2`, in place.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("  hi ");
+    /// s.trim_start_in_place();
+    ///
+    /// assert_eq!(String2::from("hi "), s);
+    /// ```
+    #[inline]
+    pub fn trim_start_in_place(&mut self) {
+        let start = self.trim_start_len();
+        let removed = utf8_len(&self.inner[..start]);
+        self.inner.drain(..start);
+        self.sub_byte_len(removed);
+    }
+
+    /// Removes trailing whitespace from this `String2`, in place.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("  hi ");
+    /// s.trim_end_in_place();
+    ///
+    /// assert_eq!(String2::from("  hi"), s);
+    /// ```
+    #[inline]
+    pub fn trim_end_in_place(&mut self) {
+        let end = self.len() - self.trim_end_len();
+        self.truncate(end);
+    }
+
+    // Number of leading whitespace chars.
+    #[inline]
+    fn trim_start_len(&self) -> usize {
+        self.inner.iter().take_while(|c| c.is_whitespace()).count()
+    }
+
+    // Number of trailing whitespace chars.
+    #[inline]
+    fn trim_end_len(&self) -> usize {
+        self.inner.iter().rev().take_while(|c| c.is_whitespace()).count()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.set_byte_len(0);
+    }
+
+    /// Converts this `String2` into a consuming iterator over its chars.
+    ///
+    /// The iterator knows its exact length and can be walked from both
+    /// ends.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut iter = String2::from("abc").iter();
+    ///
+    /// assert_eq!(iter.len(), 3);
+    /// assert_eq!(iter.next_back(), Some('c'));
+    /// assert_eq!(iter.rev().collect::<Vec<_>>(), ['b', 'a']);
+    /// ```
+    #[inline]
+    pub fn iter(self) -> StrIterator {
         self.into_iter()
     }
+
+    /// Returns a borrowing iterator over the chars of this `String2`.
+    ///
+    /// Unlike [`iter`], this does not consume the string. Iterating over a
+    /// `&String2` in a `for` loop does the same.
+    ///
+    /// [`iter`]: #method.iter
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("abc");
+    ///
+    /// assert_eq!(s.chars().rev().collect::<Vec<_>>(), ['c', 'b', 'a']);
+    ///
+    /// let mut upper = String2::new();
+    /// for c in &s {
+    ///     upper.push(c.to_ascii_uppercase());
+    /// }
+    ///
+    /// assert_eq!(String2::from("ABC"), upper);
+    /// assert_eq!(String2::from("abc"), s);
+    /// ```
+    #[inline]
+    pub fn chars(&self) -> Chars<'_> {
+        Chars::new(&self.inner)
+    }
+
+    /// Returns an iterator over the chars of this `String2`, and their
+    /// positions.
+    ///
+    /// The positions are char indices, so they can be passed directly to
+    /// methods like [`insert`] and [`remove`].
+    ///
+    /// [`insert`]: #method.insert
+    /// [`remove`]: #method.remove
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("héllo");
+    /// let mut indices = s.char_indices();
+    ///
+    /// assert_eq!(Some((0, 'h')), indices.next());
+    /// assert_eq!(Some((1, 'é')), indices.next());
+    /// assert_eq!(Some((4, 'o')), indices.next_back());
+    /// ```
+    ///
+    /// Using a found position to edit the string:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("key=value");
+    ///
+    /// if let Some((idx, _)) = s.char_indices().find(|&(_, c)| c == '=') {
+    ///     s.remove(idx);
+    ///     s.insert(idx, ':');
+    /// }
+    ///
+    /// assert_eq!(String2::from("key:value"), s);
+    /// ```
+    #[inline]
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        CharIndices::new(&self.inner)
+    }
+
+    /// An iterator over the slices of this `String2`, separated by chars
+    /// matched by a pattern. Differs from a plain split in that the matched
+    /// char is kept at the end of each slice, so the pieces can be joined
+    /// back into the original string.
+    ///
+    /// If the last char of the string is matched, it is considered the
+    /// terminator of the preceding slice, and no empty slice is yielded
+    /// after it.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("Mary had\nlittle lamb\n");
+    /// let v: Vec<String2> = s.split_inclusive('\n').map(String2::from).collect();
+    ///
+    /// assert_eq!(v, [String2::from("Mary had\n"), String2::from("little lamb\n")]);
+    /// ```
+    ///
+    /// The pieces reassemble into the original string:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("a,b,,c");
+    /// let joined: Vec<char> = s.split_inclusive(',').flat_map(|p| p.to_vec()).collect();
+    ///
+    /// assert_eq!(String2::from(joined), s);
+    /// ```
+    #[inline]
+    pub fn split_inclusive<P: CharPattern>(&self, pat: P) -> SplitInclusive<'_, P> {
+        SplitInclusive::new(&self.inner, pat)
+    }
+
+    /// An iterator over the pieces of this `String2`, separated by chars
+    /// matched by a pattern, where the delimiters are kept instead of being
+    /// dropped.
+    ///
+    /// With [`DelimiterMode::Preceding`] each delimiter ends the piece before
+    /// it, exactly as [`split_inclusive`] does. With
+    /// [`DelimiterMode::Separate`] each delimiter is yielded on its own.
+    /// Either way, no empty pieces are yielded and concatenating the pieces
+    /// gives back the original string, which makes this suitable for
+    /// lossless tokenization.
+    ///
+    /// [`DelimiterMode::Preceding`]: enum.DelimiterMode.html#variant.Preceding
+    /// [`DelimiterMode::Separate`]: enum.DelimiterMode.html#variant.Separate
+    /// [`split_inclusive`]: #method.split_inclusive
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{String2, DelimiterMode};
+    ///
+    /// let s = String2::from("1+22*3");
+    /// let ops = ['+', '*'];
+    ///
+    /// let tokens: Vec<String2> = s.split_keep_delimiter(&ops, DelimiterMode::Separate)
+    ///     .map(String2::from)
+    ///     .collect();
+    ///
+    /// assert_eq!(tokens, [
+    ///     String2::from("1"),
+    ///     String2::from("+"),
+    ///     String2::from("22"),
+    ///     String2::from("*"),
+    ///     String2::from("3")
+    /// ]);
+    ///
+    /// let pieces: Vec<String2> = s.split_keep_delimiter(&ops, DelimiterMode::Preceding)
+    ///     .map(String2::from)
+    ///     .collect();
+    ///
+    /// assert_eq!(pieces, [String2::from("1+"), String2::from("22*"), String2::from("3")]);
+    /// ```
+    #[inline]
+    pub fn split_keep_delimiter<P: CharPattern>(&self, pat: P, mode: DelimiterMode) -> SplitKeepDelimiter<'_, P> {
+        SplitKeepDelimiter::new(&self.inner, pat, mode)
+    }
+
+    /// An iterator over the lines of this `String2`, as char slices.
+    ///
+    /// Lines are split at line endings that are either newlines (`\n`) or
+    /// sequences of a carriage return followed by a line feed (`\r\n`).
+    /// The line terminators are not included in the yielded slices, and the
+    /// final line ending is optional.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let text = String2::from("foo\r\nbar\n\nbaz\n");
+    /// let mut lines = text.lines();
+    ///
+    /// assert_eq!(Some(&['f', 'o', 'o'][..]), lines.next());
+    /// assert_eq!(Some(&['b', 'a', 'r'][..]), lines.next());
+    /// assert_eq!(Some(&[][..]), lines.next());
+    /// assert_eq!(Some(&['b', 'a', 'z'][..]), lines.next());
+    ///
+    /// assert_eq!(None, lines.next());
+    /// ```
+    #[inline]
+    pub fn lines(&self) -> Lines<'_> {
+        Lines::new(&self.inner)
+    }
+
+    /// An iterator over the lines of this `String2`, as char slices, with
+    /// their line endings kept.
+    ///
+    /// Concatenating the yielded slices gives back the original string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let text = String2::from("foo\r\nbar");
+    /// let mut lines = text.lines_with_terminators();
+    ///
+    /// assert_eq!(Some(&['f', 'o', 'o', '\r', '\n'][..]), lines.next());
+    /// assert_eq!(Some(&['b', 'a', 'r'][..]), lines.next());
+    ///
+    /// assert_eq!(None, lines.next());
+    /// ```
+    #[inline]
+    pub fn lines_with_terminators(&self) -> LinesWithTerminators<'_> {
+        LinesWithTerminators::new(&self.inner)
+    }
+
+    /// Creates a new `String2` by repeating this string `n` times.
+    ///
+    /// The result is allocated once, with its final size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity would overflow.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("ab").repeat(3), String2::from("ababab"));
+    /// assert_eq!(String2::from("ab") * 2, String2::from("abab"));
+    /// ```
+    #[inline]
+    pub fn repeat(&self, n: usize) -> String2 {
+        String2::from_parts(self.inner.repeat(n), self.byte_len() * n)
+    }
 }
 
 impl<'a> From<&'a str> for String2 {
     #[inline]
     fn from(string: &'a str) -> String2 {
-        String2 {
-            inner: string.chars().collect()
-        }
+        String2::from_parts(string.chars().collect(), string.len())
     }
 }
 
 impl From<String> for String2 {
     #[inline]
     fn from(string: String) -> String2 {
-        String2 {
-            inner: string.chars().collect()
-        }
+        String2::from_parts(string.chars().collect(), string.len())
     }
 }
 
 impl From<Vec<char>> for String2 {
     #[inline]
     fn from(s: Vec<char>) -> String2 {
-        String2 {
-            inner: s
-        }
+        let byte_len = utf8_len(&s);
+        String2::from_parts(s, byte_len)
     }
 }
 
 impl<'a> From<&'a [char]> for String2 {
     #[inline]
     fn from(s: &'a [char]) -> String2 {
-        String2 {
-            inner: s.to_vec()
-        }
+        String2::from(s.to_vec())
     }
 }
 
 impl<'a> From<&'a mut [char]> for String2 {
     #[inline]
     fn from(s: &'a mut [char]) -> String2 {
-        String2 {
-            inner: s.to_vec()
+        String2::from(s.to_vec())
+    }
+}
+
+impl str::FromStr for String2 {
+    type Err = convert::Infallible;
+    #[inline]
+    fn from_str(s: &str) -> Result<String2, convert::Infallible> {
+        Ok(String2::from(s))
+    }
+}
+
+impl From<String2> for String {
+    #[inline]
+    fn from(#[allow(unused_mut)] mut s: String2) -> String {
+        #[cfg(feature = "utf8-cache")]
+        {
+            if let Some(utf8) = s.utf8.take() {
+                return utf8.into_string()
+            }
         }
+
+        String::from(&s)
     }
 }
 
-impl Into<String> for String2 {
-    fn into(self) -> String {
-        self.inner.iter().map(|c| c.encode_utf8(&mut [0; 4]).to_string()).collect()
+impl<'a> From<&'a String2> for String {
+    fn from(s: &'a String2) -> String {
+        #[cfg(feature = "utf8-cache")]
+        {
+            if let Some(utf8) = s.utf8.get() {
+                return String::from(&**utf8)
+            }
+        }
+
+        let mut out = String::with_capacity(s.byte_len());
+        out.extend(&s.inner);
+        out
     }
 }
 
-impl<'a> Into<String> for &'a String2 {
-    fn into(self) -> String {
-        self.inner.iter().map(|c| c.encode_utf8(&mut [0; 4]).to_string()).collect()
+impl From<String2> for Box<str> {
+    /// Encodes the chars into a buffer of exactly the right size, which
+    /// becomes the box without being copied.
+    #[inline]
+    fn from(s: String2) -> Box<str> {
+        String::from(s).into_boxed_str()
+    }
+}
+
+// Encodes `chars` as UTF-8 into `buf`, which must be exactly as long as the
+// encoding, so that every byte of it is initialized.
+fn encode_utf8_uninit(chars: &[char], buf: &mut [MaybeUninit<u8>]) {
+    let mut len = 0;
+    for ch in chars {
+        for &byte in ch.encode_utf8(&mut [0; 4]).as_bytes() {
+            buf[len].write(byte);
+            len += 1;
+        }
+    }
+    assert_eq!(len, buf.len(), "UTF-8 buffer was not filled");
+}
+
+impl From<String2> for Arc<str> {
+    /// Encodes the chars straight into the shared allocation, without an
+    /// intermediate `String`.
+    fn from(s: String2) -> Arc<str> {
+        let mut bytes = Arc::<[u8]>::new_uninit_slice(s.byte_len());
+        encode_utf8_uninit(&s.inner, Arc::get_mut(&mut bytes).unwrap());
+
+        // SAFETY: `encode_utf8_uninit` initialized every byte, with the
+        // UTF-8 encoding of the chars, and `[u8]` and `str` have the same
+        // layout.
+        unsafe { Arc::from_raw(Arc::into_raw(bytes.assume_init()) as *const str) }
+    }
+}
+
+impl From<String2> for Rc<str> {
+    /// Encodes the chars straight into the shared allocation, without an
+    /// intermediate `String`.
+    fn from(s: String2) -> Rc<str> {
+        let mut bytes = Rc::<[u8]>::new_uninit_slice(s.byte_len());
+        encode_utf8_uninit(&s.inner, Rc::get_mut(&mut bytes).unwrap());
+
+        // SAFETY: `encode_utf8_uninit` initialized every byte, with the
+        // UTF-8 encoding of the chars, and `[u8]` and `str` have the same
+        // layout.
+        unsafe { Rc::from_raw(Rc::into_raw(bytes.assume_init()) as *const str) }
+    }
+}
+
+impl From<String2> for Arc<[char]> {
+    #[inline]
+    fn from(s: String2) -> Arc<[char]> {
+        Arc::from(s.inner)
+    }
+}
+
+impl From<String2> for Rc<[char]> {
+    #[inline]
+    fn from(s: String2) -> Rc<[char]> {
+        Rc::from(s.inner)
     }
 }
 
@@ -767,8 +1945,17 @@ impl IntoIterator for String2 {
     }
 }
 
+impl<'a> IntoIterator for &'a String2 {
+    type Item = char;
+    type IntoIter = Chars<'a>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.chars()
+    }
+}
+
 pub struct StrIterator {
-    inner: ::std::vec::IntoIter<char>
+    inner: ::alloc::vec::IntoIter<char>
 }
 
 impl Iterator for StrIterator {
@@ -777,6 +1964,171 @@ impl Iterator for StrIterator {
     fn next(&mut self) -> Option<char> {
         self.inner.next()
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl DoubleEndedIterator for StrIterator {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        self.inner.next_back()
+    }
+}
+
+impl ExactSizeIterator for StrIterator {}
+
+impl FusedIterator for StrIterator {}
+
+/// An iterator over the chars removed by [`splice`].
+///
+/// This struct is created by the [`splice`] method on `String2`.
+///
+/// [`splice`]: struct.String2.html#method.splice
+#[derive(Debug)]
+pub struct Splice<'a, I: Iterator<Item = char> + 'a> {
+    inner: vec::Splice<'a, SpliceBytes<'a, I>>
+}
+
+impl<'a, I: Iterator<Item = char>> Iterator for Splice<'a, I> {
+    type Item = char;
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, I: Iterator<Item = char>> DoubleEndedIterator for Splice<'a, I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, I: Iterator<Item = char>> ExactSizeIterator for Splice<'a, I> {}
+
+// Adds the UTF-8 length of the chars it yields to the byte count of the
+// string being spliced.
+#[derive(Debug)]
+struct SpliceBytes<'a, I> {
+    iter: I,
+    byte_len: &'a mut usize
+}
+
+impl<'a, I: Iterator<Item = char>> Iterator for SpliceBytes<'a, I> {
+    type Item = char;
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        let ch = self.iter.next()?;
+        *self.byte_len += ch.len_utf8();
+        Some(ch)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl FromIterator<char> for String2 {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> String2 {
+        let mut s = String2::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl<'a> FromIterator<&'a char> for String2 {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a char>>(iter: I) -> String2 {
+        let mut s = String2::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl<'a> FromIterator<&'a str> for String2 {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> String2 {
+        let mut s = String2::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl FromIterator<String> for String2 {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> String2 {
+        let mut s = String2::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl FromIterator<String2> for String2 {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = String2>>(iter: I) -> String2 {
+        let mut s = String2::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl Extend<char> for String2 {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let start = self.inner.len();
+        self.inner.extend(iter);
+        let added = utf8_len(&self.inner[start..]);
+        self.add_byte_len(added);
+    }
+}
+
+impl<'a> Extend<&'a char> for String2 {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned())
+    }
+}
+
+impl<'a> Extend<&'a str> for String2 {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(s);
+        }
+    }
+}
+
+impl Extend<String> for String2 {
+    #[inline]
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(&s);
+        }
+    }
+}
+
+impl Extend<String2> for String2 {
+    #[inline]
+    fn extend<I: IntoIterator<Item = String2>>(&mut self, iter: I) {
+        for mut s in iter {
+            self.append(&mut s);
+        }
+    }
 }
 
 impl AsRef<String2> for String2 {
@@ -803,6 +2155,7 @@ impl AsRef<[char]> for String2 {
 impl AsMut<[char]> for String2 {
     #[inline]
     fn as_mut(&mut self) -> &mut [char] {
+        self.set_byte_len(UNKNOWN_BYTE_LEN);
         &mut self.inner
     }
 }
@@ -813,7 +2166,7 @@ impl ops::Add for String2 {
     fn add(self, other: String2) -> String2 {
         let mut self2 = self;
         let mut other = other;
-        self2.inner.append(&mut other.inner);
+        self2.append(&mut other);
         self2
     }
 }
@@ -827,7 +2180,7 @@ impl ops::Add<char> for String2 {
     }
 }
 
-impl<'a> ops::Add<&'a str> for String2 {
+impl ops::Add<&str> for String2 {
     type Output = String2;
     #[inline]
     fn add(mut self, other: &str) -> String2 {
@@ -836,11 +2189,30 @@ impl<'a> ops::Add<&'a str> for String2 {
     }
 }
 
+impl ops::Add<String2> for char {
+    type Output = String2;
+    #[inline]
+    fn add(self, mut other: String2) -> String2 {
+        other.insert(0, self);
+        other
+    }
+}
+
+impl ops::Add<String2> for &str {
+    type Output = String2;
+    #[inline]
+    fn add(self, mut other: String2) -> String2 {
+        other.inner.splice(0..0, self.chars());
+        other.add_byte_len(self.len());
+        other
+    }
+}
+
 impl ops::AddAssign for String2 {
     #[inline]
     fn add_assign(&mut self, other: String2) {
         let mut other = other;
-        self.inner.append(other.inner.as_mut())
+        self.append(&mut other)
     }
 }
 
@@ -851,13 +2223,29 @@ impl ops::AddAssign<char> for String2 {
     }
 }
 
-impl<'a> ops::AddAssign<&'a str> for String2 {
+impl ops::AddAssign<&str> for String2 {
     #[inline]
     fn add_assign(&mut self, other: &str) {
         self.push_str(other)
     }
 }
 
+impl ops::Mul<usize> for String2 {
+    type Output = String2;
+    #[inline]
+    fn mul(self, n: usize) -> String2 {
+        self.repeat(n)
+    }
+}
+
+impl ops::Mul<usize> for &String2 {
+    type Output = String2;
+    #[inline]
+    fn mul(self, n: usize) -> String2 {
+        self.repeat(n)
+    }
+}
+
 impl PartialEq for String2 {
     #[inline]
     fn eq(&self, other: &String2) -> bool {
@@ -865,10 +2253,203 @@ impl PartialEq for String2 {
     }
 }
 
+impl PartialEq<str> for String2 {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.inner.iter().cloned().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for String2 {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.inner.iter().cloned().eq(other.chars())
+    }
+}
+
+impl PartialEq<String> for String2 {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.inner.iter().cloned().eq(other.chars())
+    }
+}
+
+impl PartialEq<[char]> for String2 {
+    #[inline]
+    fn eq(&self, other: &[char]) -> bool {
+        self.inner[..] == other[..]
+    }
+}
+
+impl PartialEq<String2> for str {
+    #[inline]
+    fn eq(&self, other: &String2) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<String2> for &str {
+    #[inline]
+    fn eq(&self, other: &String2) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<String2> for String {
+    #[inline]
+    fn eq(&self, other: &String2) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<String2> for [char] {
+    #[inline]
+    fn eq(&self, other: &String2) -> bool {
+        other == self
+    }
+}
+
+impl hash::Hash for String2 {
+    /// Hashes the UTF-8 encoding of the string by handing it to the `Hash`
+    /// implementation of `str`, so a `String2` hashes equal to the `str` or
+    /// `String` with the same contents, with any hasher.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    /// use string2::String2;
+    ///
+    /// fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     value.hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// assert_eq!(hash(&String2::from("abc")), hash("abc"));
+    /// assert_eq!(hash(&String2::from("grüße")), hash("grüße"));
+    /// ```
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        #[cfg(feature = "utf8-cache")]
+        self.as_str().hash(state);
+
+        #[cfg(not(feature = "utf8-cache"))]
+        {
+            // Short strings are encoded on the stack.
+            let mut buf = [0u8; 256];
+
+            if self.inner.len() * 4 <= buf.len() {
+                let mut len = 0;
+                for ch in &self.inner {
+                    len += ch.encode_utf8(&mut buf[len..]).len();
+                }
+                // The bytes are the UTF-8 encoding of chars, so they are
+                // valid.
+                str::from_utf8(&buf[..len]).unwrap().hash(state);
+            } else {
+                String::from(self).as_str().hash(state);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "utf8-cache")]
+impl Borrow<str> for String2 {
+    /// Lends out the cached UTF-8 encoding, so that maps and sets keyed by
+    /// `String2` can be looked up with a `&str`.
+    ///
+    /// This requires the `utf8-cache` feature. Without it, a `String2` has
+    /// no `str` to lend out, and lookups need a `String2` key.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use string2::String2;
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert(String2::from("key"), 1);
+    ///
+    /// assert_eq!(map.get("key"), Some(&1));
+    /// ```
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
 impl PartialOrd for String2 {
     #[inline]
-    fn partial_cmp(&self, other: &String2) -> Option<::std::cmp::Ordering> {
-        PartialOrd::partial_cmp(&self.inner, &other.inner)
+    fn partial_cmp(&self, other: &String2) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialOrd<str> for String2 {
+    #[inline]
+    fn partial_cmp(&self, other: &str) -> Option<::core::cmp::Ordering> {
+        Some(self.inner.iter().cloned().cmp(other.chars()))
+    }
+}
+
+impl PartialOrd<&str> for String2 {
+    #[inline]
+    fn partial_cmp(&self, other: &&str) -> Option<::core::cmp::Ordering> {
+        Some(self.inner.iter().cloned().cmp(other.chars()))
+    }
+}
+
+impl PartialOrd<String> for String2 {
+    #[inline]
+    fn partial_cmp(&self, other: &String) -> Option<::core::cmp::Ordering> {
+        Some(self.inner.iter().cloned().cmp(other.chars()))
+    }
+}
+
+impl PartialOrd<[char]> for String2 {
+    #[inline]
+    fn partial_cmp(&self, other: &[char]) -> Option<::core::cmp::Ordering> {
+        Some(self.inner[..].cmp(other))
+    }
+}
+
+impl PartialOrd<String2> for str {
+    #[inline]
+    fn partial_cmp(&self, other: &String2) -> Option<::core::cmp::Ordering> {
+        other.partial_cmp(self).map(::core::cmp::Ordering::reverse)
+    }
+}
+
+impl PartialOrd<String2> for &str {
+    #[inline]
+    fn partial_cmp(&self, other: &String2) -> Option<::core::cmp::Ordering> {
+        other.partial_cmp(self).map(::core::cmp::Ordering::reverse)
+    }
+}
+
+impl PartialOrd<String2> for String {
+    #[inline]
+    fn partial_cmp(&self, other: &String2) -> Option<::core::cmp::Ordering> {
+        other.partial_cmp(self).map(::core::cmp::Ordering::reverse)
+    }
+}
+
+impl PartialOrd<String2> for [char] {
+    #[inline]
+    fn partial_cmp(&self, other: &String2) -> Option<::core::cmp::Ordering> {
+        other.partial_cmp(self).map(::core::cmp::Ordering::reverse)
+    }
+}
+
+impl Ord for String2 {
+    #[inline]
+    fn cmp(&self, other: &String2) -> ::core::cmp::Ordering {
+        Ord::cmp(&self.inner, &other.inner)
     }
 }
 
@@ -915,6 +2496,7 @@ impl ops::Index<ops::RangeFull> for String2 {
 impl ops::IndexMut<usize> for String2 {
     #[inline]
     fn index_mut(&mut self, idx: usize) -> &mut char {
+        self.set_byte_len(UNKNOWN_BYTE_LEN);
         &mut self.inner[idx]
     }
 }
@@ -922,6 +2504,7 @@ impl ops::IndexMut<usize> for String2 {
 impl ops::IndexMut<ops::Range<usize>> for String2 {
     #[inline]
     fn index_mut(&mut self, range: ops::Range<usize>) -> &mut [char] {
+        self.set_byte_len(UNKNOWN_BYTE_LEN);
         self.inner.index_mut(range)
     }
 }
@@ -929,6 +2512,7 @@ impl ops::IndexMut<ops::Range<usize>> for String2 {
 impl ops::IndexMut<ops::RangeFrom<usize>> for String2 {
     #[inline]
     fn index_mut(&mut self, range: ops::RangeFrom<usize>) -> &mut [char] {
+        self.set_byte_len(UNKNOWN_BYTE_LEN);
         self.inner.index_mut(range)
     }
 }
@@ -936,6 +2520,7 @@ impl ops::IndexMut<ops::RangeFrom<usize>> for String2 {
 impl ops::IndexMut<ops::RangeTo<usize>> for String2 {
     #[inline]
     fn index_mut(&mut self, range: ops::RangeTo<usize>) -> &mut [char] {
+        self.set_byte_len(UNKNOWN_BYTE_LEN);
         self.inner.index_mut(range)
     }
 }
@@ -943,22 +2528,103 @@ impl ops::IndexMut<ops::RangeTo<usize>> for String2 {
 impl ops::IndexMut<ops::RangeFull> for String2 {
     #[inline]
     fn index_mut(&mut self, range: ops::RangeFull) -> &mut [char] {
+        self.set_byte_len(UNKNOWN_BYTE_LEN);
         self.inner.index_mut(range)
     }
 }
 
+impl fmt::Write for String2 {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+// Writes `chars` straight to the formatter. Like `str`, this honors the
+// precision as a maximum number of chars, and the width, fill and
+// alignment, with the width counted in chars.
+pub(crate) fn fmt_display(chars: &[char], f: &mut fmt::Formatter) -> fmt::Result {
+    use core::fmt::Write;
+
+    let chars = match f.precision() {
+        Some(max) => &chars[..max.min(chars.len())],
+        None => chars
+    };
+
+    let pad = f.width().map_or(0, |width| width.saturating_sub(chars.len()));
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (pad, 0),
+        Some(fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+        Some(fmt::Alignment::Left) | None => (0, pad)
+    };
+    let fill = f.fill();
+
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    for &ch in chars {
+        f.write_char(ch)?;
+    }
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+// Writes `chars` quoted and escaped like `str`, straight to the formatter.
+pub(crate) fn fmt_debug(chars: &[char], f: &mut fmt::Formatter) -> fmt::Result {
+    use core::fmt::Write;
+
+    f.write_char('"')?;
+
+    for &ch in chars {
+        // Unlike a `char`, a string does not escape single quotes.
+        if ch == '\'' {
+            f.write_char(ch)?;
+        } else {
+            for esc in ch.escape_debug() {
+                f.write_char(esc)?;
+            }
+        }
+    }
+
+    f.write_char('"')
+}
+
+#[cfg(not(feature = "utf8-cache"))]
+impl fmt::Display for String2 {
+    /// Writes the chars straight to the formatter. Like `str`, this honors
+    /// the precision as a maximum number of chars, and the width, fill and
+    /// alignment, with the width counted in chars.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_display(&self.inner, f)
+    }
+}
+
+#[cfg(feature = "utf8-cache")]
 impl fmt::Display for String2 {
+    /// Pads the cached UTF-8 encoding, which honors the precision, width,
+    /// fill and alignment just like `str`.
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let s: String = self.into();
-        fmt::Display::fmt(&s, f)
+        f.pad(self.as_str())
     }
 }
 
 impl fmt::Debug for String2 {
+    /// Writes the string quoted and escaped like `str`, straight to the
+    /// formatter.
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let s: String = self.into();
-        fmt::Debug::fmt(&s, f)
+        fmt_debug(&self.inner, f)
     }
 }
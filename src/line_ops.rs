@@ -0,0 +1,209 @@
+use core::cmp::Ordering;
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::case::simple_fold;
+
+/// How [`sort_lines`] orders the lines of a `String2`.
+///
+/// [`sort_lines`]: struct.String2.html#method.sort_lines
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LineOrder {
+    /// Lines are compared char by char, by code point.
+    Lexical,
+    /// Lines are compared char by char, ignoring case.
+    CaseInsensitive,
+    /// Runs of ASCII digits are compared by their numeric value, so
+    /// `"file2"` sorts before `"file10"`; other chars are compared by code
+    /// point.
+    Natural
+}
+
+fn natural_cmp(a: &[char], b: &[char]) -> Ordering {
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        if a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+            let (start_a, start_b) = (i, j);
+            while i < a.len() && a[i].is_ascii_digit() {
+                i += 1;
+            }
+            while j < b.len() && b[j].is_ascii_digit() {
+                j += 1;
+            }
+
+            let num_a = trim_zeros(&a[start_a..i]);
+            let num_b = trim_zeros(&b[start_b..j]);
+
+            // Equal numbers with more leading zeros sort last.
+            let ord = num_a.len().cmp(&num_b.len())
+                .then_with(|| num_a.cmp(num_b))
+                .then_with(|| (i - start_a).cmp(&(j - start_b)));
+
+            if ord != Ordering::Equal {
+                return ord
+            }
+        } else {
+            let ord = a[i].cmp(&b[j]);
+            if ord != Ordering::Equal {
+                return ord
+            }
+
+            i += 1;
+            j += 1;
+        }
+    }
+
+    (a.len() - i).cmp(&(b.len() - j))
+}
+
+fn trim_zeros(digits: &[char]) -> &[char] {
+    let zeros = digits.iter().take_while(|&&c| c == '0').count();
+    &digits[zeros..]
+}
+
+impl String2 {
+    /// Returns a new `String2` with the lines of this one sorted in the
+    /// given order.
+    ///
+    /// The sort is stable. The lines are joined with the first line ending
+    /// found in the string (`\n` or `\r\n`), and the result ends with one
+    /// if the original did.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{String2, LineOrder};
+    ///
+    /// let text = String2::from("b10\nB2\na1\n");
+    ///
+    /// assert_eq!(text.sort_lines(LineOrder::Lexical), "B2\na1\nb10\n");
+    /// assert_eq!(text.sort_lines(LineOrder::CaseInsensitive), "a1\nb10\nB2\n");
+    ///
+    /// let files = String2::from("file10\nfile2\nfile1");
+    ///
+    /// assert_eq!(files.sort_lines(LineOrder::Natural), "file1\nfile2\nfile10");
+    /// ```
+    pub fn sort_lines(&self, order: LineOrder) -> String2 {
+        match order {
+            LineOrder::Lexical => self.sort_lines_by(|a, b| a.cmp(b)),
+            LineOrder::CaseInsensitive => self.sort_lines_by(|a, b| {
+                a.iter().map(|&c| simple_fold(c)).cmp(b.iter().map(|&c| simple_fold(c)))
+            }),
+            LineOrder::Natural => self.sort_lines_by(natural_cmp)
+        }
+    }
+
+    /// Returns a new `String2` with the lines of this one sorted with the
+    /// comparator function `compare`.
+    ///
+    /// The lines are passed without their line endings, and are joined as
+    /// in [`sort_lines`].
+    ///
+    /// [`sort_lines`]: #method.sort_lines
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let text = String2::from("ccc\na\nbb");
+    ///
+    /// assert_eq!(text.sort_lines_by(|a, b| b.len().cmp(&a.len())), "ccc\nbb\na");
+    /// ```
+    pub fn sort_lines_by<F>(&self, mut compare: F) -> String2
+        where F: FnMut(&[char], &[char]) -> Ordering
+    {
+        let mut lines: Vec<&[char]> = self.lines().collect();
+        lines.sort_by(|a, b| compare(a, b));
+        self.join_lines(&lines)
+    }
+
+    /// Returns a new `String2` with consecutive duplicate lines of this one
+    /// removed, as with the `uniq` command.
+    ///
+    /// The lines are joined as in [`sort_lines`].
+    ///
+    /// [`sort_lines`]: #method.sort_lines
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let text = String2::from("a\r\na\r\nb\r\na\r\n");
+    ///
+    /// assert_eq!(text.dedup_lines(), "a\r\nb\r\na\r\n");
+    /// ```
+    pub fn dedup_lines(&self) -> String2 {
+        let mut lines: Vec<&[char]> = self.lines().collect();
+        lines.dedup();
+        self.join_lines(&lines)
+    }
+
+    /// Returns a new `String2` with the lines of this one in a random order
+    /// derived from `seed`.
+    ///
+    /// The same seed always gives the same order, so the result is
+    /// reproducible. The lines are joined as in [`sort_lines`].
+    ///
+    /// [`sort_lines`]: #method.sort_lines
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{String2, LineOrder};
+    ///
+    /// let text = String2::from("1\n2\n3\n4\n5");
+    /// let shuffled = text.shuffle_lines(42);
+    ///
+    /// assert_eq!(shuffled, text.shuffle_lines(42));
+    /// assert_eq!(shuffled.sort_lines(LineOrder::Lexical), text);
+    /// ```
+    pub fn shuffle_lines(&self, seed: u64) -> String2 {
+        let mut lines: Vec<&[char]> = self.lines().collect();
+        let mut state = seed;
+
+        for i in (1..lines.len()).rev() {
+            // splitmix64
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^= z >> 31;
+
+            lines.swap(i, (z % (i as u64 + 1)) as usize);
+        }
+
+        self.join_lines(&lines)
+    }
+
+    fn join_lines(&self, lines: &[&[char]]) -> String2 {
+        let terminator: &[char] = match self.inner.iter().position(|&c| c == '\n') {
+            Some(idx) if idx > 0 && self.inner[idx - 1] == '\r' => &['\r', '\n'],
+            _ => &['\n']
+        };
+
+        let mut out = Vec::with_capacity(self.inner.len());
+        for (idx, line) in lines.iter().enumerate() {
+            if idx > 0 {
+                out.extend_from_slice(terminator);
+            }
+            out.extend_from_slice(line);
+        }
+
+        if self.inner.last() == Some(&'\n') {
+            out.extend_from_slice(terminator);
+        }
+
+        String2::from(out)
+    }
+}
@@ -0,0 +1,70 @@
+//! Structured logging support: `String2` values can be attached to `tracing`
+//! events and spans and to `log` records as fields.
+
+#[cfg(feature = "tracing")]
+mod tracing_impls {
+    use tracing::field::{display, DisplayValue};
+
+    use crate::String2;
+
+    impl String2 {
+        /// Wraps this `String2` so it can be recorded as a field value of a
+        /// `tracing` event or span.
+        ///
+        /// `tracing::Value` is sealed and cannot be implemented outside of
+        /// `tracing`, so the string is recorded through its `Display` impl
+        /// instead, and only formatted if a subscriber is interested in the
+        /// event.
+        ///
+        /// This method requires the `tracing` feature.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # extern crate tracing;
+        /// # extern crate string2;
+        /// use string2::String2;
+        ///
+        /// let user = String2::from("ferris");
+        ///
+        /// tracing::info!(user = user.as_tracing_value(), "logged in");
+        /// ```
+        #[inline]
+        pub fn as_tracing_value(&self) -> DisplayValue<&String2> {
+            display(self)
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+mod log_impls {
+    use log::kv::{ToValue, Value};
+
+    use crate::String2;
+
+    /// Records a `String2` as a `log` key-value through its `Display` impl.
+    ///
+    /// This impl requires the `log` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # extern crate log;
+    /// # extern crate string2;
+    /// use string2::String2;
+    ///
+    /// let user = String2::from("ferris");
+    ///
+    /// log::info!(user = user; "logged in");
+    /// ```
+    impl ToValue for String2 {
+        #[inline]
+        fn to_value(&self) -> Value<'_> {
+            Value::from_display(self)
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use core::cmp::Ordering;
+
+use crate::String2;
+
+/// Extension methods for iterators over `String2`s or `&String2`s, for
+/// picking the longest or shortest one.
+///
+/// This is mostly useful to size columns when formatting tables.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{String2, String2IterExt};
+///
+/// let names = vec![String2::from("Ada"), String2::from("Grace"), String2::from("Alan")];
+///
+/// assert_eq!(names.iter().longest(), Some(&names[1]));
+/// assert_eq!(names.iter().shortest(), Some(&names[0]));
+/// ```
+pub trait String2IterExt: Iterator + Sized
+    where Self::Item: AsRef<String2>
+{
+    /// Returns the string with the most chars, or `None` if the iterator
+    /// is empty.
+    ///
+    /// If several strings are equally long, the first one is returned.
+    #[inline]
+    fn longest(self) -> Option<Self::Item> {
+        select_by(self, String2::len, Ordering::Greater)
+    }
+
+    /// Returns the string with the fewest chars, or `None` if the iterator
+    /// is empty.
+    ///
+    /// If several strings are equally short, the first one is returned.
+    #[inline]
+    fn shortest(self) -> Option<Self::Item> {
+        select_by(self, String2::len, Ordering::Less)
+    }
+
+    /// Returns the string that takes the most terminal columns, or `None`
+    /// if the iterator is empty.
+    ///
+    /// If several strings are equally wide, the first one is returned.
+    ///
+    /// This method requires the `unicode-width` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{String2, String2IterExt};
+    ///
+    /// let cells = vec![String2::from("abcd"), String2::from("東京")];
+    ///
+    /// assert_eq!(cells.iter().longest(), Some(&cells[0]));
+    /// assert_eq!(cells.iter().widest().unwrap().display_width(), 4);
+    /// ```
+    #[cfg(feature = "unicode-width")]
+    #[inline]
+    fn widest(self) -> Option<Self::Item> {
+        select_by(self, String2::display_width, Ordering::Greater)
+    }
+
+    /// Returns the string that takes the fewest terminal columns, or
+    /// `None` if the iterator is empty.
+    ///
+    /// If several strings are equally narrow, the first one is returned.
+    ///
+    /// This method requires the `unicode-width` feature.
+    #[cfg(feature = "unicode-width")]
+    #[inline]
+    fn narrowest(self) -> Option<Self::Item> {
+        select_by(self, String2::display_width, Ordering::Less)
+    }
+}
+
+impl<I> String2IterExt for I
+    where I: Iterator, I::Item: AsRef<String2>
+{}
+
+// Returns the first item with the greatest key if `wanted` is `Greater`, or
+// with the least key if it is `Less`.
+fn select_by<I, F>(iter: I, key: F, wanted: Ordering) -> Option<I::Item>
+    where I: Iterator, I::Item: AsRef<String2>, F: Fn(&String2) -> usize
+{
+    let mut best: Option<(I::Item, usize)> = None;
+
+    for item in iter {
+        let item_key = key(item.as_ref());
+
+        match best {
+            Some((_, best_key)) if item_key.cmp(&best_key) != wanted => {}
+            _ => best = Some((item, item_key))
+        }
+    }
+
+    best.map(|(item, _)| item)
+}
@@ -0,0 +1,81 @@
+// Implements the comparison, hashing and formatting traits of a string type
+// that stores its contents as chars and has an `as_slice` method. Equality
+// with the type itself is left to the caller, as some types compare across
+// capacities or can short-circuit on shared storage.
+//
+// The generic parameters of the type are given in brackets:
+//
+//     impl_char_string_traits!([const N: usize] SmallString2<N>);
+macro_rules! impl_char_string_traits {
+    ([$($gen:tt)*] $ty:ty) => {
+        impl<$($gen)*> Eq for $ty {}
+
+        impl<$($gen)*> PartialEq<$crate::String2> for $ty {
+            #[inline]
+            fn eq(&self, other: &$crate::String2) -> bool {
+                self.as_slice() == other.as_slice()
+            }
+        }
+
+        impl<$($gen)*> PartialEq<$ty> for $crate::String2 {
+            #[inline]
+            fn eq(&self, other: &$ty) -> bool {
+                self.as_slice() == other.as_slice()
+            }
+        }
+
+        impl<$($gen)*> PartialEq<str> for $ty {
+            #[inline]
+            fn eq(&self, other: &str) -> bool {
+                self.as_slice().iter().cloned().eq(other.chars())
+            }
+        }
+
+        impl<'a, $($gen)*> PartialEq<&'a str> for $ty {
+            #[inline]
+            fn eq(&self, other: &&'a str) -> bool {
+                self == *other
+            }
+        }
+
+        impl<$($gen)*> PartialOrd for $ty {
+            #[inline]
+            fn partial_cmp(&self, other: &$ty) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<$($gen)*> Ord for $ty {
+            #[inline]
+            fn cmp(&self, other: &$ty) -> ::core::cmp::Ordering {
+                self.as_slice().cmp(other.as_slice())
+            }
+        }
+
+        impl<$($gen)*> ::core::hash::Hash for $ty {
+            /// Hashes the chars as a `[char]` does.
+            ///
+            /// This differs from the hash of a `String2` with the same
+            /// contents, which follows `str`, even though the two compare
+            /// equal, so they should not be mixed as keys of one map.
+            #[inline]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(self.as_slice(), state)
+            }
+        }
+
+        impl<$($gen)*> ::core::fmt::Display for $ty {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                $crate::fmt_display(self.as_slice(), f)
+            }
+        }
+
+        impl<$($gen)*> ::core::fmt::Debug for $ty {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                $crate::fmt_debug(self.as_slice(), f)
+            }
+        }
+    };
+}
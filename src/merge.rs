@@ -0,0 +1,278 @@
+use core::ops::Range;
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::diff::matching_pairs;
+
+/// A region where both sides of a three-way merge changed the base in
+/// different ways.
+///
+/// Ranges are in lines for [`merge3`], and in chars for [`merge3_chars`].
+///
+/// [`merge3`]: struct.String2.html#method.merge3
+/// [`merge3_chars`]: struct.String2.html#method.merge3_chars
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Conflict {
+    base_range: Range<usize>,
+    base: String2,
+    ours: String2,
+    theirs: String2
+}
+
+impl Conflict {
+    /// Returns the range of the base that both sides changed.
+    #[inline]
+    pub fn base_range(&self) -> Range<usize> {
+        self.base_range.clone()
+    }
+
+    /// Returns the text of the base in the conflicting region.
+    #[inline]
+    pub fn base(&self) -> &String2 {
+        &self.base
+    }
+
+    /// Returns our version of the conflicting region.
+    #[inline]
+    pub fn ours(&self) -> &String2 {
+        &self.ours
+    }
+
+    /// Returns their version of the conflicting region.
+    #[inline]
+    pub fn theirs(&self) -> &String2 {
+        &self.theirs
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Region {
+    Resolved(Vec<char>),
+    Conflict(Conflict)
+}
+
+/// The result of a three-way merge.
+///
+/// This struct is created by the [`merge3`] and [`merge3_chars`] methods on
+/// `String2`.
+///
+/// [`merge3`]: struct.String2.html#method.merge3
+/// [`merge3_chars`]: struct.String2.html#method.merge3_chars
+#[derive(Clone, Debug)]
+pub struct Merge3 {
+    regions: Vec<Region>
+}
+
+impl Merge3 {
+    /// Returns `true` if the merge has no conflicts.
+    #[inline]
+    pub fn is_clean(&self) -> bool {
+        self.conflicts().next().is_none()
+    }
+
+    /// Returns an iterator over the conflicts of the merge, in order.
+    pub fn conflicts(&self) -> impl Iterator<Item = &Conflict> {
+        self.regions.iter().filter_map(|region| match *region {
+            Region::Conflict(ref conflict) => Some(conflict),
+            Region::Resolved(_) => None
+        })
+    }
+
+    /// Returns the merged string, or the list of conflicts if there are
+    /// any.
+    pub fn into_result(self) -> Result<String2, Vec<Conflict>> {
+        if self.is_clean() {
+            return Ok(self.with_markers())
+        }
+
+        Err(self.regions.into_iter().filter_map(|region| match region {
+            Region::Conflict(conflict) => Some(conflict),
+            Region::Resolved(_) => None
+        }).collect())
+    }
+
+    /// Returns the merged string, with each conflict written between
+    /// conflict markers as Git does:
+    ///
+    /// ```text
+    /// <<<<<<< ours
+    /// our version
+    /// =======
+    /// their version
+    /// >>>>>>> theirs
+    /// ```
+    ///
+    /// Each marker starts on a line of its own.
+    pub fn with_markers(&self) -> String2 {
+        let mut out = Vec::new();
+
+        fn marker(out: &mut Vec<char>, marker: &str) {
+            if out.last().is_some_and(|&c| c != '\n') {
+                out.push('\n');
+            }
+            out.extend(marker.chars());
+        }
+
+        for region in &self.regions {
+            match *region {
+                Region::Resolved(ref chars) => out.extend_from_slice(chars),
+                Region::Conflict(ref conflict) => {
+                    marker(&mut out, "<<<<<<< ours\n");
+                    out.extend_from_slice(&conflict.ours.inner);
+                    marker(&mut out, "=======\n");
+                    out.extend_from_slice(&conflict.theirs.inner);
+                    marker(&mut out, ">>>>>>> theirs\n");
+                }
+            }
+        }
+
+        String2::from(out)
+    }
+}
+
+fn concat(units: &[&[char]]) -> Vec<char> {
+    units.iter().flat_map(|unit| unit.iter().cloned()).collect()
+}
+
+// The diff3 algorithm: walks the base, ours and theirs in step, keeping the
+// base units that both sides left in place, and resolving the unstable
+// chunks in between.
+fn merge(base: &[&[char]], ours: &[&[char]], theirs: &[&[char]]) -> Merge3 {
+    let mut in_ours = vec![None; base.len()];
+    for (i, j) in matching_pairs(base, ours) {
+        in_ours[i] = Some(j);
+    }
+    let mut in_theirs = vec![None; base.len()];
+    for (i, j) in matching_pairs(base, theirs) {
+        in_theirs[i] = Some(j);
+    }
+
+    let mut regions = Vec::new();
+    let mut stable = Vec::new();
+    let (mut b, mut o, mut t) = (0, 0, 0);
+
+    loop {
+        // Extend the stable chunk while all three sides agree.
+        while b < base.len() && in_ours[b] == Some(o) && in_theirs[b] == Some(t) {
+            stable.extend_from_slice(base[b]);
+            b += 1;
+            o += 1;
+            t += 1;
+        }
+
+        // The next base unit that both sides kept ends the unstable chunk.
+        let next = (b..base.len()).find(|&i| in_ours[i].is_some() && in_theirs[i].is_some());
+        let (end_b, end_o, end_t) = match next {
+            Some(i) => (i, in_ours[i].unwrap(), in_theirs[i].unwrap()),
+            None => (base.len(), ours.len(), theirs.len())
+        };
+
+        if b == end_b && o == end_o && t == end_t {
+            break
+        }
+
+        let (chunk_b, chunk_o, chunk_t) = (&base[b..end_b], &ours[o..end_o], &theirs[t..end_t]);
+
+        if chunk_o == chunk_b || chunk_o == chunk_t {
+            stable.extend(concat(chunk_t));
+        } else if chunk_t == chunk_b {
+            stable.extend(concat(chunk_o));
+        } else {
+            if !stable.is_empty() {
+                regions.push(Region::Resolved(stable));
+                stable = Vec::new();
+            }
+
+            regions.push(Region::Conflict(Conflict {
+                base_range: b..end_b,
+                base: String2::from(concat(chunk_b)),
+                ours: String2::from(concat(chunk_o)),
+                theirs: String2::from(concat(chunk_t))
+            }));
+        }
+
+        b = end_b;
+        o = end_o;
+        t = end_t;
+    }
+
+    if !stable.is_empty() {
+        regions.push(Region::Resolved(stable));
+    }
+
+    Merge3 {
+        regions
+    }
+}
+
+impl String2 {
+    /// Merges the changes that `ours` and `theirs` made to `base`, line by
+    /// line.
+    ///
+    /// Changes made by only one side, or identically by both, are taken. A
+    /// region changed by both sides in different ways is a [`Conflict`].
+    /// The returned [`Merge3`] gives either the merged string or the
+    /// conflicts, or the merged string with conflict markers.
+    ///
+    /// [`Conflict`]: struct.Conflict.html
+    /// [`Merge3`]: struct.Merge3.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let base = String2::from("a\nb\nc\n");
+    /// let ours = String2::from("A\nb\nc\n");
+    /// let theirs = String2::from("a\nb\nC\n");
+    ///
+    /// let merged = String2::merge3(&base, &ours, &theirs);
+    /// assert_eq!(merged.into_result().unwrap(), "A\nb\nC\n");
+    ///
+    /// let theirs = String2::from("X\nb\nc\n");
+    /// let merged = String2::merge3(&base, &ours, &theirs);
+    ///
+    /// assert!(!merged.is_clean());
+    /// assert_eq!(merged.conflicts().next().unwrap().base_range(), 0..1);
+    /// assert_eq!(merged.with_markers(), "<<<<<<< ours\nA\n=======\nX\n>>>>>>> theirs\nb\nc\n");
+    /// ```
+    pub fn merge3(base: &String2, ours: &String2, theirs: &String2) -> Merge3 {
+        let base: Vec<&[char]> = base.lines_with_terminators().collect();
+        let ours: Vec<&[char]> = ours.lines_with_terminators().collect();
+        let theirs: Vec<&[char]> = theirs.lines_with_terminators().collect();
+
+        merge(&base, &ours, &theirs)
+    }
+
+    /// Merges the changes that `ours` and `theirs` made to `base`, char by
+    /// char.
+    ///
+    /// This works like [`merge3`], but changes on the same line merge
+    /// cleanly as long as they touch different chars.
+    ///
+    /// [`merge3`]: #method.merge3
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let base = String2::from("let x = 1;");
+    /// let ours = String2::from("let y = 1;");
+    /// let theirs = String2::from("let x = 2;");
+    ///
+    /// let merged = String2::merge3_chars(&base, &ours, &theirs);
+    /// assert_eq!(merged.into_result().unwrap(), "let y = 2;");
+    /// ```
+    pub fn merge3_chars(base: &String2, ours: &String2, theirs: &String2) -> Merge3 {
+        let base: Vec<&[char]> = base.inner.chunks(1).collect();
+        let ours: Vec<&[char]> = ours.inner.chunks(1).collect();
+        let theirs: Vec<&[char]> = theirs.inner.chunks(1).collect();
+
+        merge(&base, &ours, &theirs)
+    }
+}
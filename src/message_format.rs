@@ -0,0 +1,116 @@
+use core::fmt::{self, Write};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::format_named::{format_into, lookup, placeholder_end, FormatError};
+
+// A case of a plural or select: its key, and its message with the position
+// of the message in the cases.
+type Case<'a> = (&'a [char], &'a [char], usize);
+
+fn trim(chars: &[char]) -> &[char] {
+    let start = chars.iter().position(|ch| !ch.is_whitespace()).unwrap_or(chars.len());
+    let end = chars.iter().rposition(|ch| !ch.is_whitespace()).map_or(start, |idx| idx + 1);
+    &chars[start..end]
+}
+
+fn parse_number(chars: &[char]) -> Option<f64> {
+    chars.iter().collect::<String>().parse().ok()
+}
+
+fn is(chars: &[char], s: &str) -> bool {
+    chars.iter().cloned().eq(s.chars())
+}
+
+// Parses `key{message}` cases, separated by optional whitespace.
+fn parse_cases(chars: &[char]) -> Option<Vec<Case<'_>>> {
+    let mut cases = Vec::new();
+
+    let mut idx = 0;
+    loop {
+        while idx < chars.len() && chars[idx].is_whitespace() {
+            idx += 1;
+        }
+        if idx == chars.len() {
+            return Some(cases)
+        }
+
+        let key_start = idx;
+        while idx < chars.len() && !chars[idx].is_whitespace() && chars[idx] != '{' {
+            idx += 1;
+        }
+        let key = &chars[key_start..idx];
+
+        while idx < chars.len() && chars[idx].is_whitespace() {
+            idx += 1;
+        }
+        if key.is_empty() || chars.get(idx) != Some(&'{') {
+            return None
+        }
+
+        let end = placeholder_end(chars, idx)?;
+        cases.push((key, &chars[idx + 1..end], idx + 1));
+        idx = end + 1;
+    }
+}
+
+// The English plural category of a number: `one` for exactly 1, without
+// decimals, and `other` for the rest.
+fn plural_category(number: &[char]) -> &'static str {
+    let digits = number.strip_prefix(&['-']).unwrap_or(number);
+    if digits == ['1'] { "one" } else { "other" }
+}
+
+// Formats a `{name, plural, ...}` or `{name, select, ...}` placeholder, whose
+// content between the braces is `content` and which opens at `idx`.
+pub(crate) fn format_complex(
+    content: &[char],
+    idx: usize,
+    args: &[(&str, &dyn fmt::Display)],
+    number: Option<&[char]>,
+    out: &mut String2
+) -> Result<(), FormatError> {
+    let malformed = FormatError::Malformed { idx };
+
+    let mut parts = content.splitn(3, |&ch| ch == ',');
+    let (name, kind, cases) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(name), Some(kind), Some(cases)) => (name, kind, cases),
+        _ => return Err(malformed)
+    };
+    // Past the opening brace and the two commas.
+    let cases_base = idx + 1 + name.len() + 1 + kind.len() + 1;
+
+    let name = trim(name);
+    let value = lookup(args, name).ok_or_else(|| FormatError::UnknownPlaceholder {
+        name: String2::from(name),
+        idx
+    })?;
+
+    let mut text = String2::new();
+    write!(text, "{}", value).expect("a Display implementation returned an error unexpectedly");
+    let text = trim(&text.inner);
+
+    let cases = parse_cases(cases).ok_or_else(|| malformed.clone())?;
+    let find = |key: &str| cases.iter().find(|&&(case, _, _)| is(case, key));
+
+    let (case, number) = match trim(kind) {
+        kind if is(kind, "plural") => {
+            let n = parse_number(text).ok_or_else(|| malformed.clone())?;
+
+            let exact = cases.iter().find(|&&(case, _, _)| {
+                case.strip_prefix(&['=']).is_some_and(|key| parse_number(key) == Some(n))
+            });
+
+            (exact.or_else(|| find(plural_category(text))), Some(text))
+        }
+        kind if is(kind, "select") => {
+            let chosen = cases.iter().find(|&&(case, _, _)| case == text);
+            (chosen, number)
+        }
+        _ => return Err(malformed)
+    };
+
+    let &(_, message, offset) = case.or_else(|| find("other")).ok_or(malformed)?;
+    format_into(message, cases_base + offset, args, number, out)
+}
@@ -0,0 +1,224 @@
+use alloc::vec::Vec;
+
+use crate::String2;
+
+/// A precomputed char-level searcher.
+///
+/// `Needle2` builds its search table once, at construction, so searching the
+/// same pattern in many haystacks only pays for the setup a single time.
+/// Matching runs in linear time in the length of the haystack.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{String2, Needle2};
+///
+/// let needle = Needle2::new("lamb");
+///
+/// let a = String2::from("Mary had a little lamb");
+/// let b = String2::from("little lamb, little lamb");
+///
+/// assert_eq!(needle.find_in(&a), Some(18));
+/// assert_eq!(needle.find_iter_in(&b).collect::<Vec<_>>(), [7, 20]);
+/// assert_eq!(needle.replace_in(&b, "dog"), String2::from("little dog, little dog"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Needle2 {
+    pattern: Vec<char>,
+    // `table[i]` is the length of the longest proper prefix of
+    // `pattern[..=i]` that is also a suffix of it.
+    table: Vec<usize>
+}
+
+impl Needle2 {
+    /// Creates a new `Needle2`, precomputing its search table.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::Needle2;
+    ///
+    /// let needle = Needle2::new("abc");
+    ///
+    /// assert_eq!(needle.len(), 3);
+    /// ```
+    pub fn new<P: Into<String2>>(pattern: P) -> Needle2 {
+        let pattern = pattern.into().as_vec();
+        let mut table = vec![0; pattern.len()];
+
+        let mut k = 0;
+        for i in 1..pattern.len() {
+            while k > 0 && pattern[i] != pattern[k] {
+                k = table[k - 1];
+            }
+
+            if pattern[i] == pattern[k] {
+                k += 1;
+            }
+
+            table[i] = k;
+        }
+
+        Needle2 {
+            pattern,
+            table
+        }
+    }
+
+    /// Returns the length of the pattern, in chars.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    /// Returns `true` if the pattern is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pattern.is_empty()
+    }
+
+    /// Returns the pattern as a char slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[char] {
+        &self.pattern
+    }
+
+    /// Returns the char index of the first match of the pattern in
+    /// `haystack`, or `None` if it does not occur.
+    ///
+    /// An empty pattern matches at index `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{String2, Needle2};
+    ///
+    /// let needle = Needle2::new("ab");
+    ///
+    /// assert_eq!(needle.find_in(&String2::from("aaab")), Some(2));
+    /// assert_eq!(needle.find_in(&String2::from("ba")), None);
+    /// ```
+    #[inline]
+    pub fn find_in<H: AsRef<[char]> + ?Sized>(&self, haystack: &H) -> Option<usize> {
+        self.find_iter_in(haystack).next()
+    }
+
+    /// An iterator over the char indices of the non-overlapping matches of
+    /// the pattern in `haystack`.
+    ///
+    /// An empty pattern matches before every char and at the end.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{String2, Needle2};
+    ///
+    /// let needle = Needle2::new("aa");
+    /// let s = String2::from("aaaaa");
+    ///
+    /// assert_eq!(needle.find_iter_in(&s).collect::<Vec<_>>(), [0, 2]);
+    /// ```
+    #[inline]
+    pub fn find_iter_in<'n, 'h, H>(&'n self, haystack: &'h H) -> FindIter<'n, 'h>
+        where H: AsRef<[char]> + ?Sized
+    {
+        FindIter {
+            needle: self,
+            haystack: haystack.as_ref(),
+            position: 0,
+            finished: false
+        }
+    }
+
+    /// Replaces all non-overlapping matches of the pattern in `haystack`
+    /// with `to`, returning the result as a new `String2`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{String2, Needle2};
+    ///
+    /// let needle = Needle2::new("--");
+    /// let s = String2::from("a--b--c");
+    ///
+    /// assert_eq!(needle.replace_in(&s, "+"), String2::from("a+b+c"));
+    /// ```
+    pub fn replace_in<H: AsRef<[char]> + ?Sized>(&self, haystack: &H, to: &str) -> String2 {
+        let haystack = haystack.as_ref();
+        let mut result = String2::with_capacity(haystack.len());
+        let mut last = 0;
+
+        for start in self.find_iter_in(haystack) {
+            result.as_mut_vec().extend_from_slice(&haystack[last..start]);
+            result.push_str(to);
+            last = start + self.pattern.len();
+        }
+
+        result.as_mut_vec().extend_from_slice(&haystack[last..]);
+        result
+    }
+}
+
+/// An iterator over the match positions of a [`Needle2`] in a haystack.
+///
+/// This struct is created by the [`find_iter_in`] method on [`Needle2`].
+///
+/// [`Needle2`]: struct.Needle2.html
+/// [`find_iter_in`]: struct.Needle2.html#method.find_iter_in
+pub struct FindIter<'n, 'h> {
+    needle: &'n Needle2,
+    haystack: &'h [char],
+    position: usize,
+    finished: bool
+}
+
+impl<'n, 'h> Iterator for FindIter<'n, 'h> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.finished {
+            return None
+        }
+
+        let pattern = &self.needle.pattern;
+        let table = &self.needle.table;
+
+        if pattern.is_empty() {
+            let position = self.position;
+            if position >= self.haystack.len() {
+                self.finished = true;
+            }
+            self.position += 1;
+            return Some(position)
+        }
+
+        let mut k = 0;
+        for i in self.position..self.haystack.len() {
+            while k > 0 && self.haystack[i] != pattern[k] {
+                k = table[k - 1];
+            }
+
+            if self.haystack[i] == pattern[k] {
+                k += 1;
+            }
+
+            if k == pattern.len() {
+                self.position = i + 1;
+                return Some(i + 1 - k)
+            }
+        }
+
+        self.finished = true;
+        None
+    }
+}
@@ -0,0 +1,247 @@
+use core::convert::TryFrom;
+use core::error;
+use core::fmt;
+use core::ops;
+
+use crate::String2;
+
+/// The error returned when an operation would leave a [`NonEmptyString2`]
+/// without any chars.
+///
+/// [`NonEmptyString2`]: struct.NonEmptyString2.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmptyError;
+
+impl fmt::Display for EmptyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("string would be empty")
+    }
+}
+
+impl error::Error for EmptyError {}
+
+/// A `String2` that always holds at least one char.
+///
+/// It can only be created from a non-empty string, and the operations that
+/// remove chars return an [`EmptyError`] instead of removing the last one,
+/// so code receiving a `NonEmptyString2` never has to handle the empty case.
+///
+/// A `NonEmptyString2` dereferences to [`String2`], so all the non-mutating
+/// methods of `String2` are available on it.
+///
+/// [`EmptyError`]: struct.EmptyError.html
+/// [`String2`]: struct.String2.html
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use string2::{String2, NonEmptyString2};
+///
+/// let mut name = NonEmptyString2::try_from("ab").unwrap();
+///
+/// assert_eq!(name.pop(), Ok('b'));
+/// assert!(name.pop().is_err());
+///
+/// assert_eq!(name.first(), 'a');
+/// assert!(NonEmptyString2::try_from(String2::new()).is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NonEmptyString2 {
+    inner: String2
+}
+
+impl NonEmptyString2 {
+    /// Creates a new `NonEmptyString2` holding a single char.
+    #[inline]
+    pub fn new(ch: char) -> NonEmptyString2 {
+        NonEmptyString2 {
+            inner: String2::from(vec![ch])
+        }
+    }
+
+    /// Creates a `NonEmptyString2` from a `String2`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmptyError`] if `s` is empty.
+    ///
+    /// [`EmptyError`]: struct.EmptyError.html
+    #[inline]
+    pub fn from_string2(s: String2) -> Result<NonEmptyString2, EmptyError> {
+        if s.is_empty() {
+            return Err(EmptyError)
+        }
+
+        Ok(NonEmptyString2 {
+            inner: s
+        })
+    }
+
+    /// Returns the first char of this string.
+    #[inline]
+    pub fn first(&self) -> char {
+        self.inner[0]
+    }
+
+    /// Returns the last char of this string.
+    #[inline]
+    pub fn last(&self) -> char {
+        self.inner[self.inner.len() - 1]
+    }
+
+    /// Returns a reference to the underlying `String2`.
+    #[inline]
+    pub fn as_string2(&self) -> &String2 {
+        &self.inner
+    }
+
+    /// Converts this `NonEmptyString2` into the underlying `String2`.
+    #[inline]
+    pub fn into_string2(self) -> String2 {
+        self.inner
+    }
+
+    /// Appends a char to the end of this string.
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        self.inner.push(ch);
+    }
+
+    /// Appends a string slice to the end of this string.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.inner.push_str(string);
+    }
+
+    /// Inserts a char at char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the string's length.
+    #[inline]
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        self.inner.insert(idx, ch);
+    }
+
+    /// Removes the last char and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmptyError`] if it is the only char left.
+    ///
+    /// [`EmptyError`]: struct.EmptyError.html
+    #[inline]
+    pub fn pop(&mut self) -> Result<char, EmptyError> {
+        if self.inner.len() == 1 {
+            return Err(EmptyError)
+        }
+
+        Ok(self.inner.pop().unwrap())
+    }
+
+    /// Removes the char at char index `idx` and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmptyError`] if it is the only char left.
+    ///
+    /// [`EmptyError`]: struct.EmptyError.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> Result<char, EmptyError> {
+        if self.inner.len() == 1 && idx == 0 {
+            return Err(EmptyError)
+        }
+
+        Ok(self.inner.remove(idx))
+    }
+
+    /// Shortens this string to `new_len` chars.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmptyError`] if `new_len` is `0`.
+    ///
+    /// [`EmptyError`]: struct.EmptyError.html
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) -> Result<(), EmptyError> {
+        if new_len == 0 {
+            return Err(EmptyError)
+        }
+
+        self.inner.truncate(new_len);
+        Ok(())
+    }
+
+    /// Returns a mutable char slice of this string. Its chars can be
+    /// changed, but not its length.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [char] {
+        self.inner.as_mut_slice()
+    }
+}
+
+impl ops::Deref for NonEmptyString2 {
+    type Target = String2;
+    #[inline]
+    fn deref(&self) -> &String2 {
+        &self.inner
+    }
+}
+
+impl AsRef<String2> for NonEmptyString2 {
+    #[inline]
+    fn as_ref(&self) -> &String2 {
+        &self.inner
+    }
+}
+
+impl AsRef<[char]> for NonEmptyString2 {
+    #[inline]
+    fn as_ref(&self) -> &[char] {
+        self.inner.as_ref()
+    }
+}
+
+impl TryFrom<String2> for NonEmptyString2 {
+    type Error = EmptyError;
+    #[inline]
+    fn try_from(s: String2) -> Result<NonEmptyString2, EmptyError> {
+        NonEmptyString2::from_string2(s)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NonEmptyString2 {
+    type Error = EmptyError;
+    #[inline]
+    fn try_from(s: &'a str) -> Result<NonEmptyString2, EmptyError> {
+        NonEmptyString2::from_string2(String2::from(s))
+    }
+}
+
+impl From<NonEmptyString2> for String2 {
+    #[inline]
+    fn from(s: NonEmptyString2) -> String2 {
+        s.inner
+    }
+}
+
+impl fmt::Display for NonEmptyString2 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl fmt::Debug for NonEmptyString2 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
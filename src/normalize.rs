@@ -0,0 +1,76 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::String2;
+use crate::case::full_fold;
+use crate::pattern::CharSeq;
+
+/// A Unicode normalization form used for comparing strings with
+/// [`eq_normalized`].
+///
+/// [`eq_normalized`]: struct.String2.html#method.eq_normalized
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Form {
+    /// Canonical equivalence: `"é"` as one char equals `"e\u{301}"`.
+    Nfc,
+    /// Compatibility equivalence: additionally, `"ﬁ"` equals `"fi"` and
+    /// `"①"` equals `"1"`.
+    Nfkc,
+    /// Canonical equivalence after full case folding, so `"ß"` equals
+    /// `"SS"` and `"ﬀ"` equals `"FF"`.
+    NfcCaseFold,
+    /// Compatibility equivalence after case folding, as recommended for
+    /// comparing identifiers by UAX #31.
+    NfkcCaseFold
+}
+
+impl String2 {
+    /// Returns `true` if this `String2` and `other` are equal after both are
+    /// normalized to `form`.
+    ///
+    /// The normalization is streamed over both sides and compared char by
+    /// char, so no normalized copy of either string is allocated, and the
+    /// comparison stops at the first difference.
+    ///
+    /// This method requires the `unicode-normalization` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{String2, Form};
+    ///
+    /// let composed = String2::from("caf\u{e9}");
+    ///
+    /// assert!(composed.eq_normalized("cafe\u{301}", Form::Nfc));
+    /// assert!(!composed.eq_normalized("CAFE\u{301}", Form::Nfc));
+    /// assert!(composed.eq_normalized("CAFE\u{301}", Form::NfcCaseFold));
+    ///
+    /// let ligature = String2::from("\u{fb01}le");
+    ///
+    /// assert!(!ligature.eq_normalized("file", Form::Nfc));
+    /// assert!(ligature.eq_normalized("file", Form::Nfkc));
+    /// assert!(ligature.eq_normalized("FILE", Form::NfkcCaseFold));
+    /// assert!(ligature.eq_normalized("FILE", Form::NfcCaseFold));
+    ///
+    /// assert!(String2::from("stra\u{df}e").eq_normalized("STRASSE", Form::NfcCaseFold));
+    /// assert!(String2::from("\u{149}").eq_normalized("\u{2bc}N", Form::NfcCaseFold));
+    /// ```
+    pub fn eq_normalized<S: CharSeq>(&self, other: S, form: Form) -> bool {
+        let this = self.inner.iter().cloned();
+        let other = other.char_seq();
+
+        match form {
+            Form::Nfc => this.nfc().eq(other.nfc()),
+            Form::Nfkc => this.nfkc().eq(other.nfkc()),
+            Form::NfcCaseFold => {
+                this.nfd().flat_map(full_fold).nfc()
+                    .eq(other.nfd().flat_map(full_fold).nfc())
+            }
+            Form::NfkcCaseFold => {
+                this.nfkd().flat_map(full_fold).nfkc()
+                    .eq(other.nfkd().flat_map(full_fold).nfkc())
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::vec::Vec;
+
+use crate::String2;
+
+impl String2 {
+    /// Inserts `sep` between groups of `group_size` digits in the integer
+    /// part of the number this `String2` holds, counting from the right.
+    ///
+    /// A leading `+` or `-` sign and anything from the first char that is
+    /// not an ASCII digit on, such as a `.` and the fractional digits, are
+    /// left as they are. The separator is not tied to any locale. The
+    /// chars are moved in a single backward pass, with at most one
+    /// reallocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `group_size` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("1234567");
+    /// s.group_digits(',', 3);
+    /// assert_eq!(s, "1,234,567");
+    ///
+    /// let mut s = String2::from("-9876543.21");
+    /// s.group_digits('\u{202f}', 3);
+    /// assert_eq!(s, "-9\u{202f}876\u{202f}543.21");
+    ///
+    /// let mut s = String2::from("100");
+    /// s.group_digits('_', 4);
+    /// assert_eq!(s, "100");
+    /// ```
+    pub fn group_digits(&mut self, sep: char, group_size: usize) {
+        assert!(group_size > 0, "group size must not be zero");
+
+        let len = self.inner.len();
+        let start = match self.inner.first() {
+            Some('+') | Some('-') => 1,
+            _ => 0
+        };
+        let end = start + self.inner[start..].iter().take_while(|ch| ch.is_ascii_digit()).count();
+
+        let seps = (end - start).saturating_sub(1) / group_size;
+        if seps == 0 {
+            return
+        }
+
+        self.inner.resize(len + seps, sep);
+        self.inner.copy_within(end..len, end + seps);
+
+        let mut write = end + seps;
+        for (count, read) in (start..end).rev().enumerate() {
+            if count > 0 && count % group_size == 0 {
+                write -= 1;
+                self.inner[write] = sep;
+            }
+
+            write -= 1;
+            self.inner[write] = self.inner[read];
+        }
+
+        self.add_byte_len(seps * sep.len_utf8());
+    }
+}
+
+const ROMAN: [(u32, &str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"),
+    (100, "C"), (90, "XC"), (50, "L"), (40, "XL"),
+    (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I")
+];
+
+impl String2 {
+    /// Returns a copy of the number this `String2` holds, followed by its
+    /// English ordinal suffix: `st`, `nd`, `rd` or `th`.
+    ///
+    /// Only the last two chars are looked at, so grouped numbers work too.
+    /// If the string does not end with an ASCII digit, it is returned
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("1").to_ordinal_suffix(), "1st");
+    /// assert_eq!(String2::from("22").to_ordinal_suffix(), "22nd");
+    /// assert_eq!(String2::from("113").to_ordinal_suffix(), "113th");
+    /// assert_eq!(String2::from("1,003").to_ordinal_suffix(), "1,003rd");
+    /// assert_eq!(String2::from("n").to_ordinal_suffix(), "n");
+    /// ```
+    pub fn to_ordinal_suffix(&self) -> String2 {
+        let mut out = self.clone();
+
+        let mut digits = self.inner.iter().rev().map(|ch| ch.to_digit(10));
+        let suffix = match (digits.next(), digits.next()) {
+            (None, _) | (Some(None), _) => return out,
+            (Some(_), Some(Some(1))) => "th",
+            (Some(Some(1)), _) => "st",
+            (Some(Some(2)), _) => "nd",
+            (Some(Some(3)), _) => "rd",
+            _ => "th"
+        };
+
+        out.push_str(suffix);
+        out
+    }
+
+    /// Formats `n` as an uppercase Roman numeral, or returns `None` if it
+    /// is not between 1 and 3999.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::to_roman(1994).unwrap(), "MCMXCIV");
+    /// assert_eq!(String2::to_roman(0), None);
+    /// assert_eq!(String2::to_roman(4000), None);
+    /// ```
+    pub fn to_roman(mut n: u32) -> Option<String2> {
+        if n == 0 || n > 3999 {
+            return None
+        }
+
+        let mut out = String2::new();
+        for &(value, numeral) in &ROMAN {
+            while n >= value {
+                out.push_str(numeral);
+                n -= value;
+            }
+        }
+
+        Some(out)
+    }
+
+    /// Parses this `String2` as a Roman numeral, in upper or lower case.
+    ///
+    /// Only numerals in the standard form that [`to_roman`] produces are
+    /// accepted, so `"IIII"` or `"IC"` give `None`.
+    ///
+    /// [`to_roman`]: #method.to_roman
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("MCMXCIV").from_roman(), Some(1994));
+    /// assert_eq!(String2::from("xiv").from_roman(), Some(14));
+    /// assert_eq!(String2::from("IIII").from_roman(), None);
+    /// assert_eq!(String2::from("").from_roman(), None);
+    /// ```
+    pub fn from_roman(&self) -> Option<u32> {
+        let upper: Vec<char> = self.inner.iter().map(|ch| ch.to_ascii_uppercase()).collect();
+
+        let mut rest = &upper[..];
+        let mut n = 0;
+        for &(value, numeral) in &ROMAN {
+            // No numeral repeats more than three times in the standard form.
+            for _ in 0..3 {
+                let len = numeral.len();
+                if rest.len() < len || !rest[..len].iter().cloned().eq(numeral.chars()) {
+                    break
+                }
+
+                rest = &rest[len..];
+                n += value;
+            }
+        }
+
+        if n == 0 || !rest.is_empty() {
+            return None
+        }
+
+        // Reject what the greedy reading accepts but the standard form does
+        // not contain, like "IVI" or "XCX".
+        match String2::to_roman(n) {
+            Some(ref canonical) if canonical.inner == upper => Some(n),
+            _ => None
+        }
+    }
+}
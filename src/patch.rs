@@ -0,0 +1,232 @@
+use core::error;
+use core::fmt;
+use alloc::vec::Vec;
+
+use crate::String2;
+
+/// The error returned when a patch cannot be applied by
+/// [`apply_unified_diff`].
+///
+/// [`apply_unified_diff`]: struct.String2.html#method.apply_unified_diff
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PatchError {
+    /// The patch is not a valid unified diff. Holds the 1-based number of
+    /// the offending line of the patch.
+    Malformed {
+        /// The line of the patch, from `1`.
+        line: usize
+    },
+    /// A hunk does not match the string: its context or removed lines
+    /// differ from the lines found at its position.
+    Rejected {
+        /// The hunk, from `1`.
+        hunk: usize,
+        /// The line of the string where the hunk was expected, from `1`.
+        line: usize
+    }
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PatchError::Malformed { line } => write!(f, "malformed patch at line {}", line),
+            PatchError::Rejected { hunk, line } => write!(f, "hunk {} does not apply at line {}", hunk, line)
+        }
+    }
+}
+
+impl error::Error for PatchError {}
+
+#[derive(Default)]
+struct Hunk {
+    // 0-based index of the first line the hunk replaces.
+    start: usize,
+    old: Vec<Vec<char>>,
+    new: Vec<Vec<char>>
+}
+
+// Parses `-start[,count]` or `+start[,count]`.
+fn parse_range(s: &str, sign: char) -> Option<(usize, usize)> {
+    let s = s.strip_prefix(sign)?;
+    let mut parts = s.splitn(2, ',');
+    let start = parts.next()?.parse().ok()?;
+    let count = match parts.next() {
+        Some(count) => count.parse().ok()?,
+        None => 1
+    };
+
+    Some((start, count))
+}
+
+// Parses a hunk header, `@@ -l,s +l,s @@`, into the old and new ranges.
+fn parse_header(line: &str) -> Option<((usize, usize), (usize, usize))> {
+    let mut parts = line.strip_prefix("@@ ")?.split(' ');
+    let old = parse_range(parts.next()?, '-')?;
+    let new = parse_range(parts.next()?, '+')?;
+
+    if parts.next()? != "@@" {
+        return None
+    }
+
+    Some((old, new))
+}
+
+fn parse(patch: &str) -> Result<Vec<Hunk>, PatchError> {
+    let mut lines = patch.split_inclusive('\n').enumerate().peekable();
+    let mut hunks = Vec::new();
+
+    while let Some((idx, line)) = lines.next() {
+        // Anything outside of a hunk, such as the `---` and `+++` file
+        // headers, is ignored.
+        if !line.starts_with("@@") {
+            continue
+        }
+
+        let malformed = PatchError::Malformed { line: idx + 1 };
+        let ((old_start, old_count), (_, new_count)) = parse_header(line.trim_end()).ok_or(malformed)?;
+
+        let mut hunk = Hunk {
+            start: if old_count == 0 { old_start } else { old_start.saturating_sub(1) },
+            ..Hunk::default()
+        };
+        // Whether the last line went to the old side, the new side or both,
+        // for the "\ No newline at end of file" marker.
+        let mut last = (false, false);
+
+        while let Some(&(idx, line)) = lines.peek() {
+            let complete = hunk.old.len() == old_count && hunk.new.len() == new_count;
+            if complete && !line.starts_with('\\') {
+                break
+            }
+            lines.next();
+
+            let mut content: Vec<char> = line.chars().skip(1).collect();
+            if !line.ends_with('\n') {
+                content.push('\n');
+            }
+
+            let (old, new) = match line.chars().next() {
+                Some(' ') => (true, true),
+                Some('\n') | Some('\r') => {
+                    // A context line whose leading space was stripped.
+                    content = line.chars().collect();
+                    (true, true)
+                }
+                Some('-') => (true, false),
+                Some('+') => (false, true),
+                Some('\\') => {
+                    if last.0 {
+                        hunk.old.last_mut().unwrap().pop();
+                    }
+                    if last.1 {
+                        hunk.new.last_mut().unwrap().pop();
+                    }
+                    last = (false, false);
+                    continue
+                }
+                _ => return Err(PatchError::Malformed { line: idx + 1 })
+            };
+
+            if old {
+                hunk.old.push(content.clone());
+            }
+            if new {
+                hunk.new.push(content);
+            }
+            last = (old, new);
+
+            if hunk.old.len() > old_count || hunk.new.len() > new_count {
+                return Err(PatchError::Malformed { line: idx + 1 })
+            }
+        }
+
+        if hunk.old.len() != old_count || hunk.new.len() != new_count {
+            return Err(PatchError::Malformed { line: idx + 1 })
+        }
+
+        hunks.push(hunk);
+    }
+
+    if hunks.is_empty() && !patch.trim().is_empty() {
+        return Err(PatchError::Malformed { line: 1 })
+    }
+
+    Ok(hunks)
+}
+
+impl String2 {
+    /// Applies a patch in the unified diff format, as produced by
+    /// `diff -u` or `git diff`, and returns the patched string.
+    ///
+    /// Lines before the first hunk, such as file headers, are ignored. Each
+    /// hunk must apply exactly at the line it names: its context and removed
+    /// lines, including their line endings, are checked against the string,
+    /// and the whole patch is rejected if any of them differs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PatchError::Malformed`] if `patch` cannot be parsed, and
+    /// [`PatchError::Rejected`] if a hunk does not match the string.
+    ///
+    /// [`PatchError::Malformed`]: enum.PatchError.html#variant.Malformed
+    /// [`PatchError::Rejected`]: enum.PatchError.html#variant.Rejected
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{String2, PatchError};
+    ///
+    /// let text = String2::from("one\ntwo\nthree\n");
+    /// let patch = "\
+    /// --- a/numbers.txt
+    /// +++ b/numbers.txt
+    /// @@ -1,3 +1,3 @@
+    ///  one
+    /// -two
+    /// +2
+    ///  three
+    /// ";
+    ///
+    /// assert_eq!(text.apply_unified_diff(patch).unwrap(), "one\n2\nthree\n");
+    ///
+    /// let other = String2::from("one\nTWO\nthree\n");
+    /// assert_eq!(other.apply_unified_diff(patch), Err(PatchError::Rejected { hunk: 1, line: 1 }));
+    /// ```
+    pub fn apply_unified_diff(&self, patch: &str) -> Result<String2, PatchError> {
+        let hunks = parse(patch)?;
+        let lines: Vec<&[char]> = self.lines_with_terminators().collect();
+
+        let mut out = Vec::with_capacity(self.inner.len());
+        let mut cursor = 0;
+
+        for (idx, hunk) in hunks.iter().enumerate() {
+            let rejected = PatchError::Rejected { hunk: idx + 1, line: hunk.start + 1 };
+
+            if hunk.start < cursor {
+                return Err(rejected)
+            }
+
+            let end = hunk.start + hunk.old.len();
+            if end > lines.len() || !lines[hunk.start..end].iter().eq(&hunk.old) {
+                return Err(rejected)
+            }
+
+            for line in &lines[cursor..hunk.start] {
+                out.extend_from_slice(line);
+            }
+            for line in &hunk.new {
+                out.extend_from_slice(line);
+            }
+
+            cursor = end;
+        }
+
+        for line in &lines[cursor..] {
+            out.extend_from_slice(line);
+        }
+
+        Ok(String2::from(out))
+    }
+}
@@ -0,0 +1,98 @@
+use crate::String2;
+
+fn is_separator(ch: char) -> bool {
+    ch == '/' || ch == '\\'
+}
+
+// Whether `path` is absolute on Unix or Windows: it starts at a root, like
+// `/usr` or `\\server`, or with a drive, like `C:`.
+fn is_absolute(path: &str) -> bool {
+    let mut chars = path.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(first), _) if is_separator(first) => true,
+        (Some(drive), Some(':')) => drive.is_ascii_alphabetic(),
+        _ => false
+    }
+}
+
+impl String2 {
+    /// Replaces a leading `~` in this path with `home`.
+    ///
+    /// Only a `~` that makes up the whole string or is followed by a `/` or
+    /// `\` is replaced; `~user` forms and tildes elsewhere are left alone.
+    /// The work is purely textual, for showing paths to users: nothing is
+    /// looked up on the file system.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("~/notes.txt").expand_tilde("/home/ada"), "/home/ada/notes.txt");
+    /// assert_eq!(String2::from("~").expand_tilde("/home/ada/"), "/home/ada/");
+    /// assert_eq!(String2::from("~/src").expand_tilde("/home/ada/"), "/home/ada/src");
+    /// assert_eq!(String2::from("~bob/src").expand_tilde("/home/ada"), "~bob/src");
+    /// ```
+    pub fn expand_tilde(&self, home: &str) -> String2 {
+        let rest = match self.inner.split_first() {
+            Some(('~', rest)) if rest.first().is_none_or(|&ch| is_separator(ch)) => rest,
+            _ => return self.clone()
+        };
+
+        let mut out = String2::with_capacity(home.len() + rest.len());
+        out.push_str(home);
+
+        // Do not double the separator when `home` ends with one.
+        let rest = match rest.split_first() {
+            Some((_, after)) if home.ends_with(is_separator) => after,
+            _ => rest
+        };
+        out.extend(rest);
+
+        out
+    }
+
+    /// Joins `other` onto this path for display, like `Path::join` does.
+    ///
+    /// If `other` is absolute, starting with a separator or a drive letter
+    /// like `C:`, it replaces this path. Otherwise a separator is added in
+    /// between, unless this path is empty or already ends with one. The
+    /// separator is `\` if this path only uses backslashes, and `/`
+    /// otherwise.
+    ///
+    /// The work is purely textual: `.` and `..` are kept as they are, and
+    /// nothing is looked up on the file system.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("/etc").join_path_display("hosts"), "/etc/hosts");
+    /// assert_eq!(String2::from("/").join_path_display("etc"), "/etc");
+    /// assert_eq!(String2::from(r"C:\Users").join_path_display("ada"), r"C:\Users\ada");
+    /// assert_eq!(String2::from("/etc").join_path_display("/var/log"), "/var/log");
+    /// assert_eq!(String2::from("").join_path_display("notes"), "notes");
+    /// ```
+    pub fn join_path_display(&self, other: &str) -> String2 {
+        if self.is_empty() || is_absolute(other) {
+            return String2::from(other)
+        }
+
+        let mut out = String2::with_capacity(self.len() + 1 + other.len());
+        out.extend(&self.inner[..]);
+
+        if !self.inner.last().is_some_and(|&ch| is_separator(ch)) {
+            let backslashes = self.inner.contains(&'\\') && !self.inner.contains(&'/');
+            out.push(if backslashes { '\\' } else { '/' });
+        }
+        out.push_str(other);
+
+        out
+    }
+}
@@ -0,0 +1,125 @@
+use core::iter::Cloned;
+use core::slice;
+use core::str;
+use alloc::string::String;
+
+use crate::String2;
+use crate::chars::Chars;
+
+/// A char matcher used by the searching and splitting methods of `String2`.
+///
+/// It is implemented for a single [`char`], for slices and arrays of chars
+/// (matching any of them), and for closures taking a [`char`] and returning
+/// a `bool`.
+///
+/// [`char`]: https://doc.rust-lang.org/std/primitive.char.html
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{String2, CharPattern};
+///
+/// let mut digit = |c: char| c.is_ascii_digit();
+///
+/// assert!(digit.matches('7'));
+/// assert!(!digit.matches('x'));
+///
+/// assert!(['a', 'b'].matches('b'));
+/// assert!('-'.matches('-'));
+/// ```
+pub trait CharPattern {
+    /// Returns `true` if `ch` is matched by this pattern.
+    fn matches(&mut self, ch: char) -> bool;
+}
+
+impl CharPattern for char {
+    #[inline]
+    fn matches(&mut self, ch: char) -> bool {
+        *self == ch
+    }
+}
+
+impl CharPattern for &[char] {
+    #[inline]
+    fn matches(&mut self, ch: char) -> bool {
+        self.contains(&ch)
+    }
+}
+
+impl<const N: usize> CharPattern for [char; N] {
+    #[inline]
+    fn matches(&mut self, ch: char) -> bool {
+        self.contains(&ch)
+    }
+}
+
+impl<const N: usize> CharPattern for &[char; N] {
+    #[inline]
+    fn matches(&mut self, ch: char) -> bool {
+        self.contains(&ch)
+    }
+}
+
+impl<F> CharPattern for F
+    where F: FnMut(char) -> bool
+{
+    #[inline]
+    fn matches(&mut self, ch: char) -> bool {
+        self(ch)
+    }
+}
+
+/// A borrowed sequence of chars that a `String2` can be compared against
+/// without allocating.
+///
+/// It is implemented for `&str`, `&String`, `&String2`, and char slices and
+/// arrays.
+pub trait CharSeq {
+    /// The iterator over the chars of the sequence.
+    type Iter: DoubleEndedIterator<Item = char>;
+
+    /// Returns an iterator over the chars of the sequence.
+    fn char_seq(self) -> Self::Iter;
+}
+
+impl<'a> CharSeq for &'a str {
+    type Iter = str::Chars<'a>;
+    #[inline]
+    fn char_seq(self) -> Self::Iter {
+        self.chars()
+    }
+}
+
+impl<'a> CharSeq for &'a String {
+    type Iter = str::Chars<'a>;
+    #[inline]
+    fn char_seq(self) -> Self::Iter {
+        self.chars()
+    }
+}
+
+impl<'a> CharSeq for &'a String2 {
+    type Iter = Chars<'a>;
+    #[inline]
+    fn char_seq(self) -> Self::Iter {
+        self.chars()
+    }
+}
+
+impl<'a> CharSeq for &'a [char] {
+    type Iter = Cloned<slice::Iter<'a, char>>;
+    #[inline]
+    fn char_seq(self) -> Self::Iter {
+        self.iter().cloned()
+    }
+}
+
+impl<'a, const N: usize> CharSeq for &'a [char; N] {
+    type Iter = Cloned<slice::Iter<'a, char>>;
+    #[inline]
+    fn char_seq(self) -> Self::Iter {
+        self.iter().cloned()
+    }
+}
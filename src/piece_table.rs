@@ -0,0 +1,429 @@
+use core::fmt;
+use core::fmt::Write;
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::slice;
+use core::sync::atomic::{AtomicU64, Ordering};
+use alloc::vec::Vec;
+
+use crate::String2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Source {
+    Original,
+    Added
+}
+
+// The source of the ids that tie snapshots to the table they come from.
+static NEXT_TABLE_ID: AtomicU64 = AtomicU64::new(0);
+
+#[inline]
+fn next_table_id() -> u64 {
+    NEXT_TABLE_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+// A run of chars of one of the buffers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Piece {
+    source: Source,
+    start: usize,
+    len: usize
+}
+
+/// A piece table: a text stored as its original version, the text added
+/// by edits, and a list of pieces of both that make up the current
+/// version.
+///
+/// Edits never change or remove chars from the two buffers, they only
+/// split and rearrange pieces, so an edit takes time proportional to the
+/// number of pieces rather than to the length of the text. Consecutive
+/// inserts, as when typing, extend the same piece. The text is only put
+/// together on demand, with [`to_string2`] or [`chars`].
+///
+/// Since earlier versions of the text stay in the buffers, undoing an edit
+/// only needs the old list of pieces: [`snapshot`] saves it and
+/// [`restore`] puts it back.
+///
+/// [`to_string2`]: #method.to_string2
+/// [`chars`]: #method.chars
+/// [`snapshot`]: #method.snapshot
+/// [`restore`]: #method.restore
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{PieceTable2, String2};
+///
+/// let mut table = PieceTable2::new(String2::from("Hello world"));
+/// let before = table.snapshot();
+///
+/// table.insert(5, ",");
+/// table.insert(12, " wide");
+/// assert_eq!(table, "Hello, world wide");
+///
+/// table.remove(0..7);
+/// assert_eq!(table, "world wide");
+/// assert_eq!(table.original(), "Hello world");
+///
+/// table.restore(&before);
+/// assert_eq!(table.to_string2(), "Hello world");
+/// ```
+pub struct PieceTable2 {
+    // Unique to each table, clones included, as their add buffers diverge.
+    id: u64,
+    original: String2,
+    added: Vec<char>,
+    pieces: Vec<Piece>,
+    len: usize
+}
+
+impl Clone for PieceTable2 {
+    /// Clones the table, which gets an identity of its own, so snapshots of
+    /// this table cannot be restored into the clone.
+    fn clone(&self) -> PieceTable2 {
+        PieceTable2 {
+            id: next_table_id(),
+            original: self.original.clone(),
+            added: self.added.clone(),
+            pieces: self.pieces.clone(),
+            len: self.len
+        }
+    }
+}
+
+/// The pieces of a [`PieceTable2`] at some point, to go back to with
+/// [`restore`].
+///
+/// This struct is created by the [`snapshot`] method on `PieceTable2`.
+///
+/// [`PieceTable2`]: struct.PieceTable2.html
+/// [`restore`]: struct.PieceTable2.html#method.restore
+/// [`snapshot`]: struct.PieceTable2.html#method.snapshot
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PieceSnapshot {
+    table: u64,
+    pieces: Vec<Piece>,
+    len: usize
+}
+
+impl PieceTable2 {
+    /// Creates a piece table whose text is `original`.
+    pub fn new(original: String2) -> PieceTable2 {
+        let len = original.len();
+        let pieces = if len == 0 {
+            Vec::new()
+        } else {
+            vec![Piece {
+                source: Source::Original,
+                start: 0,
+                len
+            }]
+        };
+
+        PieceTable2 {
+            id: next_table_id(),
+            original,
+            added: Vec::new(),
+            pieces,
+            len
+        }
+    }
+
+    /// Returns the length of the current text, in chars.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the current text has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the original text, before any edit.
+    #[inline]
+    pub fn original(&self) -> &String2 {
+        &self.original
+    }
+
+    /// Returns the char at char index `idx` of the current text, or `None`
+    /// if it is out of bounds.
+    pub fn get(&self, idx: usize) -> Option<char> {
+        if idx >= self.len {
+            return None
+        }
+
+        let (piece, offset) = self.locate(idx);
+        Some(self.chars_of(&self.pieces[piece])[offset])
+    }
+
+    /// Inserts a string slice at char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the current text.
+    pub fn insert(&mut self, idx: usize, string: &str) {
+        assert!(idx <= self.len, "insertion index {} is out of bounds", idx);
+
+        let start = self.added.len();
+        self.added.extend(string.chars());
+        let len = self.added.len() - start;
+        if len == 0 {
+            return
+        }
+        self.len += len;
+
+        let new = Piece {
+            source: Source::Added,
+            start,
+            len
+        };
+
+        let (piece, offset) = self.locate(idx);
+        if offset == 0 {
+            // Typing at the end of the previous insert extends its piece.
+            if let Some(prev) = piece.checked_sub(1).map(|prev| &mut self.pieces[prev]) {
+                if prev.source == Source::Added && prev.start + prev.len == start {
+                    prev.len += len;
+                    return
+                }
+            }
+            self.pieces.insert(piece, new);
+        } else {
+            let old = self.pieces[piece];
+            self.pieces[piece].len = offset;
+            self.pieces.splice(piece + 1..piece + 1, [new, Piece {
+                source: old.source,
+                start: old.start + offset,
+                len: old.len - offset
+            }]);
+        }
+    }
+
+    /// Removes the chars in `range` of the current text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is after its end, or its end is past
+    /// the end of the current text.
+    pub fn remove(&mut self, range: Range<usize>) {
+        assert!(range.start <= range.end, "range start {} is after its end {}", range.start, range.end);
+        assert!(range.end <= self.len, "range end {} is out of bounds of a text of length {}", range.end, self.len);
+
+        if range.start == range.end {
+            return
+        }
+
+        let mut pieces = Vec::with_capacity(self.pieces.len() + 1);
+        let mut piece_start = 0;
+        for &piece in &self.pieces {
+            let piece_end = piece_start + piece.len;
+
+            // The parts of the piece before and after the range.
+            if piece_start < range.start {
+                pieces.push(Piece {
+                    len: piece.len.min(range.start - piece_start),
+                    ..piece
+                });
+            }
+            if piece_end > range.end {
+                let skip = range.end.saturating_sub(piece_start);
+                pieces.push(Piece {
+                    source: piece.source,
+                    start: piece.start + skip,
+                    len: piece.len - skip
+                });
+            }
+
+            piece_start = piece_end;
+        }
+
+        self.pieces = pieces;
+        self.len -= range.end - range.start;
+    }
+
+    /// Saves the current pieces, to go back to them later with
+    /// [`restore`].
+    ///
+    /// [`restore`]: #method.restore
+    #[inline]
+    pub fn snapshot(&self) -> PieceSnapshot {
+        PieceSnapshot {
+            table: self.id,
+            pieces: self.pieces.clone(),
+            len: self.len
+        }
+    }
+
+    /// Puts back the pieces saved by [`snapshot`], undoing the edits made
+    /// since. The text added by those edits stays in the add buffer, so
+    /// later snapshots can be restored too.
+    ///
+    /// [`snapshot`]: #method.snapshot
+    ///
+    /// # Panics
+    ///
+    /// Panics if `snapshot` was taken from another table, including a clone
+    /// of this one, whose add buffer may hold different text.
+    ///
+    /// ```should_panic
+    /// use string2::{PieceTable2, String2};
+    ///
+    /// let mut other = PieceTable2::new(String2::from("abcde"));
+    /// other.insert(1, "XY");
+    ///
+    /// let mut table = PieceTable2::new(String2::from("vwxyz"));
+    /// table.insert(0, "QRST");
+    /// table.restore(&other.snapshot());
+    /// ```
+    pub fn restore(&mut self, snapshot: &PieceSnapshot) {
+        assert!(snapshot.table == self.id, "snapshot was taken from another piece table");
+
+        self.pieces.clone_from(&snapshot.pieces);
+        self.len = snapshot.len;
+    }
+
+    /// Returns an iterator over the chars of the current text.
+    #[inline]
+    pub fn chars(&self) -> PieceChars<'_> {
+        PieceChars {
+            table: self,
+            pieces: self.pieces.iter(),
+            chunk: [].iter()
+        }
+    }
+
+    /// Puts the current text together into a `String2`.
+    pub fn to_string2(&self) -> String2 {
+        let mut out = String2::with_capacity(self.len);
+        for piece in &self.pieces {
+            out.extend(self.chars_of(piece));
+        }
+        out
+    }
+
+    // Returns the piece holding char `idx` and the offset of the char in
+    // it, or the number of pieces and zero if `idx` is the length.
+    fn locate(&self, idx: usize) -> (usize, usize) {
+        let mut piece_start = 0;
+        for (i, piece) in self.pieces.iter().enumerate() {
+            if idx < piece_start + piece.len {
+                return (i, idx - piece_start)
+            }
+            piece_start += piece.len;
+        }
+
+        (self.pieces.len(), 0)
+    }
+
+    fn chars_of(&self, piece: &Piece) -> &[char] {
+        let buffer = match piece.source {
+            Source::Original => self.original.as_slice(),
+            Source::Added => &self.added
+        };
+        &buffer[piece.start..piece.start + piece.len]
+    }
+}
+
+impl<'a> From<&'a str> for PieceTable2 {
+    #[inline]
+    fn from(string: &'a str) -> PieceTable2 {
+        PieceTable2::new(String2::from(string))
+    }
+}
+
+impl From<String2> for PieceTable2 {
+    #[inline]
+    fn from(s: String2) -> PieceTable2 {
+        PieceTable2::new(s)
+    }
+}
+
+impl From<PieceTable2> for String2 {
+    #[inline]
+    fn from(table: PieceTable2) -> String2 {
+        table.to_string2()
+    }
+}
+
+impl PartialEq for PieceTable2 {
+    #[inline]
+    fn eq(&self, other: &PieceTable2) -> bool {
+        self.len == other.len && self.chars().eq(other.chars())
+    }
+}
+
+impl Eq for PieceTable2 {}
+
+impl PartialEq<String2> for PieceTable2 {
+    #[inline]
+    fn eq(&self, other: &String2) -> bool {
+        self.len == other.len() && self.chars().eq(other.as_slice().iter().cloned())
+    }
+}
+
+impl PartialEq<str> for PieceTable2 {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<'a> PartialEq<&'a str> for PieceTable2 {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self == *other
+    }
+}
+
+impl fmt::Display for PieceTable2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.width().is_some() || f.precision().is_some() {
+            return crate::fmt_display(self.to_string2().as_slice(), f)
+        }
+
+        for ch in self.chars() {
+            f.write_char(ch)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for PieceTable2 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::fmt_debug(self.to_string2().as_slice(), f)
+    }
+}
+
+/// An iterator over the chars of the current text of a [`PieceTable2`].
+///
+/// This struct is created by the [`chars`] method on `PieceTable2`.
+///
+/// [`PieceTable2`]: struct.PieceTable2.html
+/// [`chars`]: struct.PieceTable2.html#method.chars
+#[derive(Clone)]
+pub struct PieceChars<'a> {
+    table: &'a PieceTable2,
+    pieces: slice::Iter<'a, Piece>,
+    chunk: slice::Iter<'a, char>
+}
+
+impl<'a> Iterator for PieceChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(&ch) = self.chunk.next() {
+                return Some(ch)
+            }
+            let piece = self.pieces.next()?;
+            self.chunk = self.table.chars_of(piece).iter();
+        }
+    }
+}
+
+impl<'a> FusedIterator for PieceChars<'a> {}
@@ -0,0 +1,219 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::String2;
+use crate::case::simple_fold;
+
+const IRREGULAR: [(&str, &str); 30] = [
+    ("man", "men"), ("woman", "women"), ("child", "children"),
+    ("person", "people"), ("mouse", "mice"), ("goose", "geese"),
+    ("tooth", "teeth"), ("foot", "feet"), ("ox", "oxen"),
+    ("calf", "calves"), ("half", "halves"), ("knife", "knives"),
+    ("leaf", "leaves"), ("life", "lives"), ("loaf", "loaves"),
+    ("shelf", "shelves"), ("thief", "thieves"), ("wife", "wives"),
+    ("wolf", "wolves"), ("echo", "echoes"), ("hero", "heroes"),
+    ("potato", "potatoes"), ("tomato", "tomatoes"), ("veto", "vetoes"),
+    ("analysis", "analyses"), ("axis", "axes"), ("crisis", "crises"),
+    ("criterion", "criteria"), ("index", "indices"), ("matrix", "matrices")
+];
+
+const UNCOUNTABLE: [&str; 12] = [
+    "deer", "equipment", "fish", "information", "money", "news",
+    "rice", "series", "sheep", "species", "feedback", "software"
+];
+
+// Checked in order, so longer suffixes come before the shorter ones they
+// end with.
+const SUFFIXES: [(&str, &str); 12] = [
+    ("ay", "ays"), ("ey", "eys"), ("oy", "oys"), ("uy", "uys"), ("y", "ies"),
+    ("ch", "ches"), ("sh", "shes"), ("ss", "sses"), ("s", "ses"),
+    ("x", "xes"), ("z", "zes"), ("", "s")
+];
+
+/// A table of rules for making English nouns plural.
+///
+/// [`PluralRules::english`] gives the built-in table, which covers the
+/// common irregular and uncountable nouns and the spelling rules for
+/// suffixes like `-y` and `-ch`. Entries added with [`irregular`],
+/// [`uncountable`] and [`suffix`] take precedence over the ones already in
+/// the table, so the built-in rules can be overridden.
+///
+/// Only the last word of a phrase is made plural, and the case of the
+/// result follows the case of that word.
+///
+/// [`PluralRules::english`]: #method.english
+/// [`irregular`]: #method.irregular
+/// [`uncountable`]: #method.uncountable
+/// [`suffix`]: #method.suffix
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{PluralRules, String2};
+///
+/// let mut rules = PluralRules::english();
+/// rules.irregular("octopus", "octopodes").uncountable("aircraft");
+///
+/// assert_eq!(rules.plural_of(&String2::from("octopus")), "octopodes");
+/// assert_eq!(rules.plural_of(&String2::from("Fighter aircraft")), "Fighter aircraft");
+/// assert_eq!(rules.plural_of(&String2::from("Bus")), "Buses");
+/// ```
+#[derive(Clone, Debug)]
+pub struct PluralRules {
+    // Lowercase singulars and plurals.
+    irregular: Vec<(String2, String2)>,
+    uncountable: Vec<String2>,
+    // Lowercase suffixes and their replacements.
+    suffixes: Vec<(String2, String2)>
+}
+
+impl PluralRules {
+    /// Creates a table with no rules, where every noun takes an `s`.
+    #[inline]
+    pub fn new() -> PluralRules {
+        PluralRules {
+            irregular: Vec::new(),
+            uncountable: Vec::new(),
+            suffixes: Vec::new()
+        }
+    }
+
+    /// Creates a table with the built-in English rules.
+    pub fn english() -> PluralRules {
+        PluralRules {
+            irregular: IRREGULAR.iter()
+                .map(|&(singular, plural)| (String2::from(singular), String2::from(plural)))
+                .collect(),
+            uncountable: UNCOUNTABLE.iter().map(|&word| String2::from(word)).collect(),
+            suffixes: SUFFIXES.iter()
+                .map(|&(suffix, replacement)| (String2::from(suffix), String2::from(replacement)))
+                .collect()
+        }
+    }
+
+    /// Adds an irregular noun and its plural.
+    pub fn irregular(&mut self, singular: &str, plural: &str) -> &mut PluralRules {
+        self.irregular.insert(0, (fold(singular), fold(plural)));
+        self
+    }
+
+    /// Adds a noun that is the same in the plural.
+    pub fn uncountable(&mut self, word: &str) -> &mut PluralRules {
+        self.uncountable.insert(0, fold(word));
+        self
+    }
+
+    /// Adds a rule replacing `suffix`, at the end of a word, with
+    /// `replacement` in the plural.
+    pub fn suffix(&mut self, suffix: &str, replacement: &str) -> &mut PluralRules {
+        self.suffixes.insert(0, (fold(suffix), fold(replacement)));
+        self
+    }
+
+    /// Returns the plural of `noun`.
+    pub fn plural_of(&self, noun: &String2) -> String2 {
+        let start = noun.inner.iter().rposition(|ch| !ch.is_alphabetic()).map_or(0, |idx| idx + 1);
+        let (head, word) = noun.inner.split_at(start);
+        let folded: Vec<char> = word.iter().map(|&ch| simple_fold(ch)).collect();
+
+        let mut out = String2::from(head);
+
+        if word.is_empty() || self.uncountable.iter().any(|w| w.inner == folded) {
+            out.extend(word);
+            return out
+        }
+
+        if let Some((_, plural)) = self.irregular.iter().find(|(singular, _)| singular.inner == folded) {
+            push_in_case_of(word, &plural.inner, true, &mut out);
+            return out
+        }
+
+        let rule = self.suffixes.iter().find(|(suffix, _)| folded.ends_with(&suffix.inner));
+        let (suffix_len, replacement): (usize, &[char]) = match rule {
+            Some((suffix, replacement)) => (suffix.len(), &replacement.inner),
+            None => (0, &['s'])
+        };
+
+        let stem = &word[..word.len() - suffix_len];
+        out.extend(stem);
+        push_in_case_of(word, replacement, stem.is_empty(), &mut out);
+        out
+    }
+}
+
+impl Default for PluralRules {
+    #[inline]
+    fn default() -> PluralRules {
+        PluralRules::english()
+    }
+}
+
+fn fold(s: &str) -> String2 {
+    s.chars().map(simple_fold).collect()
+}
+
+// Pushes the lowercase `text`, which replaces the end of `word`, in
+// uppercase if `word` is all uppercase, with a capital if `text` starts the
+// word and `word` is capitalized, and as is otherwise.
+fn push_in_case_of(word: &[char], text: &[char], at_start: bool, out: &mut String2) {
+    let all_upper = word.len() > 1 && word.iter().all(|ch| !ch.is_lowercase());
+    let capitalized = at_start && word.first().is_some_and(|ch| ch.is_uppercase());
+
+    for (idx, &ch) in text.iter().enumerate() {
+        if all_upper || (capitalized && idx == 0) {
+            out.extend(ch.to_uppercase());
+        } else {
+            out.push(ch);
+        }
+    }
+}
+
+impl String2 {
+    /// Returns this noun in the singular if `count` is `1`, and in the
+    /// plural otherwise, following the built-in English rules.
+    ///
+    /// For other rules, see [`PluralRules`].
+    ///
+    /// [`PluralRules`]: struct.PluralRules.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let file = String2::from("file");
+    /// assert_eq!(file.pluralize(1), "file");
+    /// assert_eq!(file.pluralize(0), "files");
+    ///
+    /// assert_eq!(String2::from("Category").pluralize(2), "Categories");
+    /// assert_eq!(String2::from("CHILD").pluralize(3), "CHILDREN");
+    /// assert_eq!(String2::from("search index").pluralize(2), "search indices");
+    /// assert_eq!(String2::from("sheep").pluralize(2), "sheep");
+    /// ```
+    pub fn pluralize(&self, count: u64) -> String2 {
+        // Without `std` there is nowhere to keep the table between calls.
+        #[cfg(feature = "std")]
+        {
+            static ENGLISH: OnceLock<PluralRules> = OnceLock::new();
+
+            self.pluralize_with(count, ENGLISH.get_or_init(PluralRules::english))
+        }
+        #[cfg(not(feature = "std"))]
+        self.pluralize_with(count, &PluralRules::english())
+    }
+
+    /// Returns this noun in the singular if `count` is `1`, and in the
+    /// plural otherwise, following `rules`.
+    pub fn pluralize_with(&self, count: u64, rules: &PluralRules) -> String2 {
+        if count == 1 {
+            self.clone()
+        } else {
+            rules.plural_of(self)
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use core::cmp::Ordering;
+use core::iter::FromIterator;
+use alloc::vec::Vec;
+
+use crate::String2;
+
+const DEFAULT_RESTART_INTERVAL: usize = 16;
+
+/// A sorted list of `String2`s stored with front coding.
+///
+/// Each entry only stores the chars that differ from the previous entry,
+/// along with the length of the prefix they share, so dictionary-like data
+/// with many common prefixes takes a fraction of the memory of a
+/// `Vec<String2>`. Every few entries a *restart* entry is stored in full,
+/// which keeps random access and binary search cheap.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{String2, PrefixCompressedList};
+///
+/// let list: PrefixCompressedList = ["interstellar", "internal", "internet", "interval"]
+///     .iter()
+///     .map(|&s| String2::from(s))
+///     .collect();
+///
+/// assert_eq!(list.len(), 4);
+/// assert_eq!(list.get(1), Some(String2::from("internet")));
+/// assert_eq!(list.binary_search(&String2::from("interval")), Ok(3));
+/// assert_eq!(list.binary_search(&String2::from("intern")), Err(0));
+///
+/// // "internal" is stored in full, then only "et", "stellar" and "val".
+/// assert_eq!(list.stored_chars(), 20);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PrefixCompressedList {
+    chars: Vec<char>,
+    // Length of the prefix each entry shares with the previous one; always
+    // `0` for restart entries.
+    shared: Vec<usize>,
+    // End offset in `chars` of the suffix stored for each entry.
+    ends: Vec<usize>,
+    restart_interval: usize,
+    last: Vec<char>
+}
+
+impl PrefixCompressedList {
+    /// Creates a new empty `PrefixCompressedList`.
+    #[inline]
+    pub fn new() -> PrefixCompressedList {
+        PrefixCompressedList::with_restart_interval(DEFAULT_RESTART_INTERVAL)
+    }
+
+    /// Creates a new empty `PrefixCompressedList` that stores every
+    /// `interval`-th entry in full.
+    ///
+    /// Larger intervals save more memory, at the cost of slower lookups.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is `0`.
+    pub fn with_restart_interval(interval: usize) -> PrefixCompressedList {
+        assert!(interval > 0, "restart interval must be greater than zero");
+
+        PrefixCompressedList {
+            chars: Vec::new(),
+            shared: Vec::new(),
+            ends: Vec::new(),
+            restart_interval: interval,
+            last: Vec::new()
+        }
+    }
+
+    /// Appends a string to the end of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` sorts before the last string in the list.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{String2, PrefixCompressedList};
+    ///
+    /// let mut list = PrefixCompressedList::new();
+    /// list.push(&String2::from("apple"));
+    /// list.push(&String2::from("applet"));
+    ///
+    /// assert_eq!(list.get(1), Some(String2::from("applet")));
+    /// ```
+    pub fn push(&mut self, s: &String2) {
+        let s = s.as_slice();
+
+        if !self.is_empty() {
+            assert!(self.last.as_slice() <= s, "strings must be pushed in sorted order");
+        }
+
+        let shared = if self.len().is_multiple_of(self.restart_interval) {
+            0
+        } else {
+            self.last.iter().zip(s).take_while(|&(a, b)| a == b).count()
+        };
+
+        self.chars.extend_from_slice(&s[shared..]);
+        self.shared.push(shared);
+        self.ends.push(self.chars.len());
+
+        self.last.clear();
+        self.last.extend_from_slice(s);
+    }
+
+    /// Returns the number of strings in the list.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if the list contains no strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the number of chars actually stored, after front coding.
+    #[inline]
+    pub fn stored_chars(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Returns the string at `idx`, or `None` if it is out of bounds.
+    pub fn get(&self, idx: usize) -> Option<String2> {
+        if idx >= self.len() {
+            return None
+        }
+
+        let mut buf = Vec::new();
+        let restart = idx - idx % self.restart_interval;
+
+        for i in restart..=idx {
+            self.decode_into(i, &mut buf);
+        }
+
+        Some(String2::from(buf))
+    }
+
+    /// Returns `true` if the list contains `key`.
+    #[inline]
+    pub fn contains<K: AsRef<[char]> + ?Sized>(&self, key: &K) -> bool {
+        self.binary_search(key).is_ok()
+    }
+
+    /// Binary searches the list for `key`.
+    ///
+    /// If the key is found, returns `Ok` with its index. Otherwise returns
+    /// `Err` with the index where it could be inserted while keeping the list
+    /// sorted.
+    pub fn binary_search<K: AsRef<[char]> + ?Sized>(&self, key: &K) -> Result<usize, usize> {
+        let key = key.as_ref();
+        let restarts = self.len().div_ceil(self.restart_interval);
+
+        // Find the last block whose restart entry is not greater than `key`.
+        let mut lo = 0;
+        let mut hi = restarts;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let idx = mid * self.restart_interval;
+
+            match self.suffix(idx).cmp(key) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Equal => return Ok(idx),
+                Ordering::Greater => hi = mid
+            }
+        }
+
+        if lo == 0 {
+            return Err(0)
+        }
+
+        let start = (lo - 1) * self.restart_interval;
+        let end = (start + self.restart_interval).min(self.len());
+        let mut buf = Vec::new();
+
+        for i in start..end {
+            self.decode_into(i, &mut buf);
+
+            match buf.as_slice().cmp(key) {
+                Ordering::Less => (),
+                Ordering::Equal => return Ok(i),
+                Ordering::Greater => return Err(i)
+            }
+        }
+
+        Err(end)
+    }
+
+    /// Returns an iterator over the strings in the list, in order.
+    #[inline]
+    pub fn iter(&self) -> PrefixCompressedIter<'_> {
+        PrefixCompressedIter {
+            list: self,
+            buf: Vec::new(),
+            idx: 0
+        }
+    }
+
+    fn suffix(&self, idx: usize) -> &[char] {
+        let start = if idx == 0 { 0 } else { self.ends[idx - 1] };
+        &self.chars[start..self.ends[idx]]
+    }
+
+    // Turns `buf`, holding entry `idx - 1`, into entry `idx`.
+    fn decode_into(&self, idx: usize, buf: &mut Vec<char>) {
+        buf.truncate(self.shared[idx]);
+        buf.extend_from_slice(self.suffix(idx));
+    }
+}
+
+impl Default for PrefixCompressedList {
+    #[inline]
+    fn default() -> PrefixCompressedList {
+        PrefixCompressedList::new()
+    }
+}
+
+impl FromIterator<String2> for PrefixCompressedList {
+    /// Collects the strings into a list, sorting them and removing
+    /// duplicates first.
+    fn from_iter<I: IntoIterator<Item = String2>>(iter: I) -> PrefixCompressedList {
+        let mut strings: Vec<String2> = iter.into_iter().collect();
+        strings.sort();
+        strings.dedup();
+
+        let mut list = PrefixCompressedList::new();
+        for s in &strings {
+            list.push(s);
+        }
+
+        list
+    }
+}
+
+impl<'a> IntoIterator for &'a PrefixCompressedList {
+    type Item = String2;
+    type IntoIter = PrefixCompressedIter<'a>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the strings of a [`PrefixCompressedList`].
+///
+/// This struct is created by the [`iter`] method on
+/// [`PrefixCompressedList`].
+///
+/// [`PrefixCompressedList`]: struct.PrefixCompressedList.html
+/// [`iter`]: struct.PrefixCompressedList.html#method.iter
+pub struct PrefixCompressedIter<'a> {
+    list: &'a PrefixCompressedList,
+    buf: Vec<char>,
+    idx: usize
+}
+
+impl<'a> Iterator for PrefixCompressedIter<'a> {
+    type Item = String2;
+
+    fn next(&mut self) -> Option<String2> {
+        if self.idx >= self.list.len() {
+            return None
+        }
+
+        self.list.decode_into(self.idx, &mut self.buf);
+        self.idx += 1;
+
+        Some(String2::from(self.buf.clone()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.list.len() - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for PrefixCompressedIter<'a> {}
@@ -0,0 +1,121 @@
+//! [`proptest`] strategies for `String2`.
+//!
+//! With these, property tests over `String2` APIs can generate their input
+//! directly, instead of generating `String`s and converting them. The
+//! `String2` type also implements proptest's `Arbitrary`, so
+//! `any::<String2>()` works too.
+//!
+//! This module requires the `proptest` feature.
+//!
+//! [`proptest`]: https://docs.rs/proptest/1
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! # #[macro_use] extern crate proptest;
+//! # extern crate string2;
+//! use string2::proptest::{any_string2, string2_regex};
+//!
+//! proptest! {
+//!     fn reverse_twice(s in any_string2()) {
+//!         let mut t = s.clone();
+//!         t.as_mut_slice().reverse();
+//!         t.as_mut_slice().reverse();
+//!         prop_assert_eq!(t, s);
+//!     }
+//!
+//!     fn snake_case_is_stable(s in string2_regex("[a-z]{1,8}(_[a-z0-9]{1,8}){0,3}").unwrap()) {
+//!         prop_assert_eq!(s.to_snake_case(), s);
+//!     }
+//! }
+//! # fn main() {
+//! #     reverse_twice();
+//! #     snake_case_is_stable();
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+use alloc::string::String;
+
+use proptest_crate::arbitrary::{any, Arbitrary};
+use proptest_crate::char::CharStrategy;
+use proptest_crate::collection::{vec, SizeRange, VecStrategy};
+use proptest_crate::string::{string_regex, Error, RegexGeneratorStrategy};
+use proptest_crate::strategy::{Map, Strategy};
+
+use crate::String2;
+
+/// The strategy returned by [`any_string2`] and [`string2_of`].
+///
+/// [`any_string2`]: fn.any_string2.html
+/// [`string2_of`]: fn.string2_of.html
+pub type String2Strategy<S> = Map<VecStrategy<S>, fn(Vec<char>) -> String2>;
+
+/// The strategy returned by [`string2_regex`].
+///
+/// [`string2_regex`]: fn.string2_regex.html
+pub type String2RegexStrategy = Map<RegexGeneratorStrategy<String>, fn(String) -> String2>;
+
+/// Generates any `String2` of up to 64 chars.
+///
+/// Chars are drawn like `any::<char>()` does, with a bias towards special
+/// and boundary chars, and failing cases shrink towards shorter strings of
+/// simpler chars.
+#[inline]
+pub fn any_string2() -> String2Strategy<CharStrategy<'static>> {
+    string2_of(any::<char>(), 0..=64)
+}
+
+/// Generates `String2`s whose chars are drawn from `chars`, with a length
+/// in `len`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate proptest;
+/// # extern crate string2;
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+/// use string2::proptest::string2_of;
+///
+/// # fn main() {
+/// let strategy = string2_of(proptest::char::range('a', 'f'), 3..5);
+/// let s = strategy.new_tree(&mut TestRunner::default()).unwrap().current();
+///
+/// assert!(s.len() >= 3 && s.len() < 5);
+/// assert!(s.as_slice().iter().all(|ch| ('a'..='f').contains(ch)));
+/// # }
+/// ```
+#[inline]
+pub fn string2_of<S>(chars: S, len: impl Into<SizeRange>) -> String2Strategy<S>
+    where S: Strategy<Value = char>
+{
+    vec(chars, len).prop_map(String2::from as fn(Vec<char>) -> String2)
+}
+
+/// Generates `String2`s matching the regular expression `regex`.
+///
+/// # Errors
+///
+/// Returns an error if `regex` is not a valid regular expression, or uses
+/// features, like anchors or look-around, that cannot be generated.
+// The error is large, but it is the one `string_regex` returns.
+#[allow(clippy::result_large_err)]
+#[inline]
+pub fn string2_regex(regex: &str) -> Result<String2RegexStrategy, Error> {
+    Ok(string_regex(regex)?.prop_map(String2::from as fn(String) -> String2))
+}
+
+impl Arbitrary for String2 {
+    type Parameters = ();
+    type Strategy = String2Strategy<CharStrategy<'static>>;
+
+    #[inline]
+    fn arbitrary_with(_args: ()) -> Self::Strategy {
+        any_string2()
+    }
+}
@@ -0,0 +1,87 @@
+use core::iter;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::String2;
+
+// An ASCII char of the same kind as `ch`, which a failing case is likely
+// to still fail with.
+fn simplify(ch: char) -> char {
+    if ch.is_uppercase() {
+        'A'
+    } else if ch.is_alphabetic() {
+        'a'
+    } else if ch.is_numeric() {
+        '0'
+    } else if ch.is_whitespace() {
+        ' '
+    } else {
+        '.'
+    }
+}
+
+/// Generates strings of arbitrary chars, and shrinks failing cases first by
+/// removing chars, then by replacing non-ASCII chars with similar ASCII
+/// ones.
+///
+/// This impl requires the `quickcheck` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate quickcheck;
+/// # extern crate string2;
+/// use quickcheck::Arbitrary;
+/// use string2::String2;
+///
+/// # fn main() {
+/// let shrunk: Vec<String2> = String2::from("Ab€").shrink().collect();
+///
+/// assert_eq!(shrunk[0], "");
+/// assert!(shrunk.contains(&String2::from("Ab")));
+/// assert!(shrunk.contains(&String2::from("Ab.")));
+/// # }
+/// ```
+impl Arbitrary for String2 {
+    #[inline]
+    fn arbitrary(g: &mut Gen) -> String2 {
+        String2::from(Vec::<char>::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = String2>> {
+        let chars = self.inner.clone();
+        let len = chars.len();
+
+        let empty = if len > 0 { Some(String2::new()) } else { None };
+
+        // Remove chunks of half the length, then a quarter, and so on down
+        // to single chars.
+        let removals = {
+            let chars = chars.clone();
+            iter::successors(Some(len / 2), |&size| Some(size / 2))
+                .take_while(|&size| size > 0)
+                .flat_map(move |size| (0..len).step_by(size).map(move |start| (start, size)))
+                .map(move |(start, size)| {
+                    let mut shorter = chars.clone();
+                    shorter.drain(start..len.min(start + size));
+                    String2::from(shorter)
+                })
+        };
+
+        let simplified = (0..len).filter_map(move |idx| {
+            if chars[idx].is_ascii() {
+                return None
+            }
+
+            let mut simpler = chars.clone();
+            simpler[idx] = simplify(simpler[idx]);
+            Some(String2::from(simpler))
+        });
+
+        Box::new(empty.into_iter().chain(removals).chain(simplified))
+    }
+}
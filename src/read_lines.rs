@@ -0,0 +1,207 @@
+use std::io::{self, BufRead};
+use core::str;
+use alloc::vec::Vec;
+
+use crate::String2;
+
+/// An iterator over the lines of a [`BufRead`], as `String2`s.
+///
+/// The bytes are decoded from UTF-8 as they are read, without first
+/// collecting each line into a `String`. Lines are split like
+/// [`String2::lines`]: at `\n` or `\r\n`, which are not included in the
+/// yielded lines.
+///
+/// A maximum line length, in chars, can be set with [`max_line_len`] to
+/// protect against unbounded input. A line that is too long is not kept in
+/// memory: the rest of it is skipped and an error of kind [`InvalidData`] is
+/// returned in its place. Lines that are not valid UTF-8 are reported the
+/// same way. In both cases iteration can go on with the next line.
+///
+/// This type requires the `std` feature.
+///
+/// [`BufRead`]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+/// [`String2::lines`]: struct.String2.html#method.lines
+/// [`max_line_len`]: #method.max_line_len
+/// [`InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{String2, Lines2};
+///
+/// let input: &[u8] = "héllo\r\nwörld\nthis line is too long\nend".as_bytes();
+/// let mut lines = Lines2::new(input).max_line_len(8);
+///
+/// assert_eq!(lines.next().unwrap().unwrap(), String2::from("héllo"));
+/// assert_eq!(lines.next().unwrap().unwrap(), String2::from("wörld"));
+/// assert!(lines.next().unwrap().is_err());
+/// assert_eq!(lines.next().unwrap().unwrap(), String2::from("end"));
+/// assert!(lines.next().is_none());
+/// ```
+#[derive(Debug)]
+pub struct Lines2<R> {
+    reader: R,
+    max_len: Option<usize>,
+    carry: Carry
+}
+
+// An incomplete UTF-8 sequence left at the end of the previous chunk.
+#[derive(Debug)]
+struct Carry {
+    buf: [u8; 4],
+    len: usize
+}
+
+impl Carry {
+    // Decodes `bytes` onto `out`, keeping an incomplete sequence at the end
+    // for the next call.
+    fn decode(&mut self, mut bytes: &[u8], out: &mut Vec<char>) -> Result<(), ()> {
+        while self.len > 0 && !bytes.is_empty() {
+            self.buf[self.len] = bytes[0];
+            self.len += 1;
+            bytes = &bytes[1..];
+
+            match str::from_utf8(&self.buf[..self.len]) {
+                Ok(s) => {
+                    out.extend(s.chars());
+                    self.len = 0;
+                }
+                Err(e) if e.error_len().is_none() => (),
+                Err(_) => return Err(())
+            }
+        }
+
+        match str::from_utf8(bytes) {
+            Ok(s) => out.extend(s.chars()),
+            Err(e) => {
+                let (valid, rest) = bytes.split_at(e.valid_up_to());
+                out.extend(str::from_utf8(valid).unwrap().chars());
+
+                if e.error_len().is_some() {
+                    return Err(())
+                }
+
+                self.buf[..rest.len()].copy_from_slice(rest);
+                self.len = rest.len();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Lines2<R> {
+    /// Creates a new `Lines2` reading from `reader`, with no maximum line
+    /// length.
+    #[inline]
+    pub fn new(reader: R) -> Lines2<R> {
+        Lines2 {
+            reader,
+            max_len: None,
+            carry: Carry {
+                buf: [0; 4],
+                len: 0
+            }
+        }
+    }
+
+    /// Sets the maximum length of a line, in chars, not counting its line
+    /// ending.
+    #[inline]
+    pub fn max_line_len(mut self, max: usize) -> Lines2<R> {
+        self.max_len = Some(max);
+        self
+    }
+
+    /// Returns a reference to the underlying reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Unwraps this `Lines2`, returning the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead> Iterator for Lines2<R> {
+    type Item = io::Result<String2>;
+
+    fn next(&mut self) -> Option<io::Result<String2>> {
+        let mut line = Vec::new();
+        let mut read_any = false;
+        let mut error = None;
+
+        loop {
+            let available = match self.reader.fill_buf() {
+                Ok(available) => available,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(e))
+            };
+
+            if available.is_empty() {
+                if !read_any {
+                    return None
+                }
+                break
+            }
+
+            read_any = true;
+
+            let (chunk, done) = match available.iter().position(|&b| b == b'\n') {
+                Some(idx) => (&available[..=idx], true),
+                None => (available, false)
+            };
+            let used = chunk.len();
+
+            if error.is_none() {
+                if self.carry.decode(chunk, &mut line).is_err() {
+                    error = Some("stream did not contain valid UTF-8");
+                } else if let Some(max) = self.max_len {
+                    // Leave room for a `\r\n` that is not stripped yet.
+                    if line.len() > max.saturating_add(2) {
+                        error = Some("line exceeds the maximum length");
+                    }
+                }
+
+                if error.is_some() {
+                    line = Vec::new();
+                }
+            }
+
+            self.reader.consume(used);
+
+            if done {
+                break
+            }
+        }
+
+        if error.is_none() && self.carry.len > 0 {
+            error = Some("stream did not contain valid UTF-8");
+        }
+        self.carry.len = 0;
+
+        if let Some(msg) = error {
+            return Some(Err(io::Error::new(io::ErrorKind::InvalidData, msg)))
+        }
+
+        if line.last() == Some(&'\n') {
+            line.pop();
+            if line.last() == Some(&'\r') {
+                line.pop();
+            }
+        }
+
+        if let Some(max) = self.max_len {
+            if line.len() > max {
+                return Some(Err(io::Error::new(io::ErrorKind::InvalidData, "line exceeds the maximum length")))
+            }
+        }
+
+        Some(Ok(String2::from(line)))
+    }
+}
@@ -0,0 +1,108 @@
+use core::time::Duration;
+
+use crate::String2;
+
+// Words are runs of alphanumeric chars, which may have apostrophes inside
+// them, as in "don't".
+pub(crate) fn words(chars: &[char]) -> impl Iterator<Item = &[char]> {
+    chars.split(|&ch| !(ch.is_alphanumeric() || ch == '\'' || ch == '’'))
+        .map(|word| {
+            let start = word.iter().take_while(|&&ch| !ch.is_alphanumeric()).count();
+            let end = word.len() - word[start..].iter().rev().take_while(|&&ch| !ch.is_alphanumeric()).count();
+            &word[start..end]
+        })
+        .filter(|word| !word.is_empty())
+}
+
+fn is_vowel(ch: char) -> bool {
+    matches!(ch.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+// Counts the groups of vowels of an English word, leaving out a silent
+// final "e". Words with letters have at least one syllable.
+fn syllables(word: &[char]) -> usize {
+    let letters = word.iter().filter(|ch| ch.is_alphabetic()).count();
+    if letters == 0 {
+        return 0
+    }
+
+    let mut count = 0;
+    let mut prev_vowel = false;
+    for &ch in word {
+        let vowel = is_vowel(ch);
+        if vowel && !prev_vowel {
+            count += 1;
+        }
+        prev_vowel = vowel;
+    }
+
+    // "make" has one syllable, but "table" and "free" have their final
+    // vowel group pronounced.
+    let lower = |idx: usize| word[idx].to_ascii_lowercase();
+    let len = word.len();
+    if count > 1 && len >= 3 && lower(len - 1) == 'e' && !is_vowel(lower(len - 2)) {
+        let consonant_le = lower(len - 2) == 'l' && !is_vowel(lower(len - 3));
+        if !consonant_le {
+            count -= 1;
+        }
+    }
+
+    count.max(1)
+}
+
+impl String2 {
+    /// Returns an estimate of the number of syllables in the words of this
+    /// `String2`.
+    ///
+    /// Words are runs of alphanumeric chars, which may have apostrophes
+    /// inside them. The syllables of each word are guessed with an English
+    /// heuristic: every group of vowels, `y` included, counts as one,
+    /// except for a silent final `e`. This is good enough for readability
+    /// scores, but will be off for some words and most other languages.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("The quick brown fox jumps over the lazy dog.");
+    /// assert_eq!(s.estimated_syllables(), 11);
+    ///
+    /// assert_eq!(String2::from("Make a table").estimated_syllables(), 4);
+    /// ```
+    pub fn estimated_syllables(&self) -> usize {
+        words(&self.inner).map(syllables).sum()
+    }
+
+    /// Returns an estimate of the time it takes to read this `String2` at
+    /// `words_per_minute`.
+    ///
+    /// Words are counted as in [`estimated_syllables`]. Adults typically
+    /// read 200 to 250 words per minute.
+    ///
+    /// [`estimated_syllables`]: #method.estimated_syllables
+    ///
+    /// # Panics
+    ///
+    /// Panics if `words_per_minute` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("The quick brown fox jumps over the lazy dog.");
+    /// assert_eq!(s.reading_time(200), Duration::from_millis(2700));
+    /// ```
+    pub fn reading_time(&self, words_per_minute: u32) -> Duration {
+        assert!(words_per_minute > 0, "words per minute must not be zero");
+
+        let count = words(&self.inner).count() as u64;
+        Duration::from_millis(count.saturating_mul(60_000) / u64::from(words_per_minute))
+    }
+}
@@ -0,0 +1,146 @@
+use core::fmt;
+
+use rkyv::bytecheck::CheckBytes;
+use rkyv::munge::munge;
+use rkyv::rancor::Fallible;
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Archived, Deserialize, Place, Portable, Serialize};
+
+use crate::String2;
+
+/// An archived `String2`.
+///
+/// It can be validated and read in place, straight from the bytes of an
+/// archive, for instance a memory-mapped file, without deserializing it
+/// into a `String2` first.
+///
+/// This type requires the `rkyv` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate rkyv;
+/// # extern crate string2;
+/// use rkyv::rancor::Error;
+/// use string2::{ArchivedString2, String2};
+///
+/// # fn main() {
+/// let s = String2::from("héllo");
+/// let bytes = rkyv::to_bytes::<Error>(&s).unwrap();
+///
+/// let archived = rkyv::access::<ArchivedString2, Error>(&bytes).unwrap();
+/// assert_eq!(archived.len(), 5);
+/// assert_eq!(archived.chars().nth(1), Some('é'));
+/// assert_eq!(*archived, s);
+///
+/// let back: String2 = rkyv::deserialize::<String2, Error>(archived).unwrap();
+/// assert_eq!(back, s);
+/// # }
+/// ```
+#[derive(Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(transparent)]
+pub struct ArchivedString2 {
+    chars: ArchivedVec<Archived<char>>
+}
+
+impl ArchivedString2 {
+    /// Returns the number of chars in the archived string.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Returns `true` if the archived string holds no chars.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Returns the archived chars.
+    #[inline]
+    pub fn as_slice(&self) -> &[Archived<char>] {
+        self.chars.as_slice()
+    }
+
+    /// Returns the char at `idx`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<char> {
+        self.chars.get(idx).map(|ch| ch.to_native())
+    }
+
+    /// An iterator over the chars of the archived string.
+    #[inline]
+    pub fn chars(&self) -> impl DoubleEndedIterator<Item = char> + ExactSizeIterator + '_ {
+        self.chars.iter().map(|ch| ch.to_native())
+    }
+
+    /// Copies the archived string into a new `String2`.
+    #[inline]
+    pub fn to_string2(&self) -> String2 {
+        self.chars().collect()
+    }
+}
+
+impl PartialEq for ArchivedString2 {
+    #[inline]
+    fn eq(&self, other: &ArchivedString2) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for ArchivedString2 {}
+
+impl PartialEq<String2> for ArchivedString2 {
+    #[inline]
+    fn eq(&self, other: &String2) -> bool {
+        self.chars().eq(other.as_slice().iter().cloned())
+    }
+}
+
+impl PartialEq<ArchivedString2> for String2 {
+    #[inline]
+    fn eq(&self, other: &ArchivedString2) -> bool {
+        other == self
+    }
+}
+
+impl fmt::Debug for ArchivedString2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string2(), f)
+    }
+}
+
+impl fmt::Display for ArchivedString2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_string2(), f)
+    }
+}
+
+impl Archive for String2 {
+    type Archived = ArchivedString2;
+    type Resolver = VecResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: VecResolver, out: Place<ArchivedString2>) {
+        munge!(let ArchivedString2 { chars } = out);
+        ArchivedVec::resolve_from_slice(self.as_slice(), resolver, chars);
+    }
+}
+
+impl<S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for String2 {
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<VecResolver, S::Error> {
+        ArchivedVec::serialize_from_slice(self.as_slice(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<String2, D> for ArchivedString2 {
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<String2, D::Error> {
+        Ok(self.to_string2())
+    }
+}
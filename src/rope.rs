@@ -0,0 +1,632 @@
+use core::fmt;
+use core::fmt::Write;
+use core::iter::FusedIterator;
+use core::mem;
+use core::ops::Range;
+use core::slice;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::String2;
+
+// The most chars a leaf holds. Leaves are kept this small so that edits
+// inside one of them stay cheap, and merged when they fit together.
+const MAX_LEAF: usize = 512;
+
+// A node of an AVL tree, whose leaves hold the chars of the rope in order.
+#[derive(Clone)]
+enum Node {
+    Leaf(String2),
+    Branch {
+        left: Box<Node>,
+        right: Box<Node>,
+        len: usize,
+        height: usize
+    }
+}
+
+impl Node {
+    fn len(&self) -> usize {
+        match *self {
+            Node::Leaf(ref s) => s.len(),
+            Node::Branch { len, .. } => len
+        }
+    }
+
+    fn height(&self) -> usize {
+        match *self {
+            Node::Leaf(_) => 0,
+            Node::Branch { height, .. } => height
+        }
+    }
+
+    fn branch(left: Node, right: Node) -> Node {
+        Node::Branch {
+            len: left.len() + right.len(),
+            height: left.height().max(right.height()) + 1,
+            left: Box::new(left),
+            right: Box::new(right)
+        }
+    }
+
+    fn into_children(self) -> (Node, Node) {
+        match self {
+            Node::Branch { left, right, .. } => (*left, *right),
+            Node::Leaf(_) => unreachable!("a leaf has no children")
+        }
+    }
+
+    // Builds a balanced tree of full leaves over `chars`.
+    fn build(chars: &[char]) -> Node {
+        if chars.len() <= MAX_LEAF {
+            return Node::Leaf(String2::from(chars))
+        }
+
+        let leaves = chars.len().div_ceil(MAX_LEAF);
+        let mid = leaves / 2 * MAX_LEAF;
+        Node::branch(Node::build(&chars[..mid]), Node::build(&chars[mid..]))
+    }
+
+    // Makes a branch of two trees whose heights differ by at most two,
+    // rotating once or twice if they differ by two.
+    fn balanced(left: Node, right: Node) -> Node {
+        let (left_height, right_height) = (left.height(), right.height());
+
+        if left_height > right_height + 1 {
+            let (outer, inner) = left.into_children();
+            if outer.height() >= inner.height() {
+                Node::branch(outer, Node::branch(inner, right))
+            } else {
+                let (inner_left, inner_right) = inner.into_children();
+                Node::branch(Node::branch(outer, inner_left), Node::branch(inner_right, right))
+            }
+        } else if right_height > left_height + 1 {
+            let (inner, outer) = right.into_children();
+            if outer.height() >= inner.height() {
+                Node::branch(Node::branch(left, inner), outer)
+            } else {
+                let (inner_left, inner_right) = inner.into_children();
+                Node::branch(Node::branch(left, inner_left), Node::branch(inner_right, outer))
+            }
+        } else {
+            Node::branch(left, right)
+        }
+    }
+
+    // Concatenates two trees, in time proportional to the difference of
+    // their heights.
+    fn join(left: Node, right: Node) -> Node {
+        if left.len() == 0 {
+            return right
+        }
+        if right.len() == 0 {
+            return left
+        }
+
+        let (left_height, right_height) = (left.height(), right.height());
+        if left_height > right_height + 1 {
+            let (outer, inner) = left.into_children();
+            Node::balanced(outer, Node::join(inner, right))
+        } else if right_height > left_height + 1 {
+            let (inner, outer) = right.into_children();
+            Node::balanced(Node::join(left, inner), outer)
+        } else {
+            match (left, right) {
+                (Node::Leaf(mut left), Node::Leaf(mut right)) if left.len() + right.len() <= MAX_LEAF => {
+                    left.append(&mut right);
+                    Node::Leaf(left)
+                }
+                (left, right) => Node::branch(left, right)
+            }
+        }
+    }
+
+    // Splits a tree into the chars before `idx` and the chars after it.
+    fn split(self, idx: usize) -> (Node, Node) {
+        match self {
+            Node::Leaf(mut s) => {
+                let rest = s.split_off(idx);
+                (Node::Leaf(s), Node::Leaf(rest))
+            }
+            Node::Branch { left, right, .. } => {
+                let left_len = left.len();
+                if idx < left_len {
+                    let (a, b) = left.split(idx);
+                    (a, Node::join(b, *right))
+                } else if idx > left_len {
+                    let (a, b) = right.split(idx - left_len);
+                    (Node::join(*left, a), b)
+                } else {
+                    (*left, *right)
+                }
+            }
+        }
+    }
+
+    // Inserts `chars` into the leaf holding `idx`, if they fit in it.
+    fn insert_in_leaf(&mut self, idx: usize, chars: &[char]) -> bool {
+        match *self {
+            Node::Leaf(ref mut s) => {
+                if s.len() + chars.len() > MAX_LEAF {
+                    return false
+                }
+                let mut rest = s.split_off(idx);
+                s.extend(chars);
+                s.append(&mut rest);
+                true
+            }
+            Node::Branch { ref mut left, ref mut right, ref mut len, .. } => {
+                let left_len = left.len();
+                let inserted = if idx <= left_len {
+                    left.insert_in_leaf(idx, chars)
+                } else {
+                    right.insert_in_leaf(idx - left_len, chars)
+                };
+                if inserted {
+                    *len += chars.len();
+                }
+                inserted
+            }
+        }
+    }
+
+    // Removes `range` from the leaf holding it, if it is inside one leaf
+    // and does not empty it.
+    fn remove_in_leaf(&mut self, range: Range<usize>) -> bool {
+        match *self {
+            Node::Leaf(ref mut s) => {
+                if range.end - range.start >= s.len() {
+                    return false
+                }
+                let mut rest = s.split_off(range.end);
+                s.truncate(range.start);
+                s.append(&mut rest);
+                true
+            }
+            Node::Branch { ref mut left, ref mut right, ref mut len, .. } => {
+                let left_len = left.len();
+                let removed = range.end - range.start;
+                let done = if range.end <= left_len {
+                    left.remove_in_leaf(range)
+                } else if range.start >= left_len {
+                    right.remove_in_leaf(range.start - left_len..range.end - left_len)
+                } else {
+                    false
+                };
+                if done {
+                    *len -= removed;
+                }
+                done
+            }
+        }
+    }
+
+    fn get(&self, idx: usize) -> Option<char> {
+        match *self {
+            Node::Leaf(ref s) => s.get(idx).cloned(),
+            Node::Branch { ref left, ref right, .. } => {
+                let left_len = left.len();
+                if idx < left_len {
+                    left.get(idx)
+                } else {
+                    right.get(idx - left_len)
+                }
+            }
+        }
+    }
+
+    fn push_range(&self, range: Range<usize>, out: &mut String2) {
+        match *self {
+            Node::Leaf(ref s) => out.extend(&s.as_slice()[range]),
+            Node::Branch { ref left, ref right, .. } => {
+                let left_len = left.len();
+                if range.start < left_len {
+                    left.push_range(range.start..range.end.min(left_len), out);
+                }
+                if range.end > left_len {
+                    right.push_range(range.start.max(left_len) - left_len..range.end - left_len, out);
+                }
+            }
+        }
+    }
+}
+
+/// A rope: a string stored as a balanced tree of `String2` chunks, for
+/// editing large texts.
+///
+/// Inserting into or removing from the middle of a `String2` moves all the
+/// chars after the edit, which gets slow for documents of millions of
+/// chars. A `Rope2` only touches the chunks along one path of its tree, so
+/// [`insert`], [`remove`], [`split_off`] and [`append`] take O(log n) time,
+/// as does looking up a char by its index with [`get`].
+///
+/// Like `String2`, a `Rope2` is addressed by char index.
+///
+/// [`insert`]: #method.insert
+/// [`remove`]: #method.remove
+/// [`split_off`]: #method.split_off
+/// [`append`]: #method.append
+/// [`get`]: #method.get
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::Rope2;
+///
+/// let mut rope = Rope2::from("Hello world!");
+/// rope.insert(5, ",");
+/// rope.insert(7, "wide ");
+/// rope.remove(11..17);
+///
+/// assert_eq!(rope, "Hello, wide!");
+/// assert_eq!(rope.get(7), Some('w'));
+///
+/// let tail = rope.split_off(5);
+/// assert_eq!(rope, "Hello");
+/// assert_eq!(tail, ", wide!");
+/// ```
+///
+/// Large ropes split their chars over many chunks, and edit them like a
+/// `String2` would:
+///
+/// ```
+/// use string2::{Rope2, String2};
+///
+/// // A small xorshift generator, so the edits are the same on every run.
+/// let mut state = 0x2545_f491_u32;
+/// let mut random = |n: usize| {
+///     state ^= state << 13;
+///     state ^= state >> 17;
+///     state ^= state << 5;
+///     state as usize % n
+/// };
+///
+/// let text = "lorem ipsum dolor sit amet ".repeat(200);
+/// let mut rope = Rope2::from(text.as_str());
+/// let mut model = String2::from(text.as_str());
+///
+/// for _ in 0..400 {
+///     let at = random(model.len() + 1);
+///     match random(4) {
+///         0 => {
+///             let string = "é".repeat(random(700));
+///             rope.insert(at, &string);
+///             model.splice(at..at, string.chars());
+///         }
+///         1 => {
+///             let end = at + random(model.len() - at + 1).min(900);
+///             rope.remove(at..end);
+///             model.splice(at..end, None);
+///         }
+///         2 => {
+///             let mut tail = rope.split_off(at);
+///             let mut model_tail = model.split_off(at);
+///             rope.append(&mut tail);
+///             model.append(&mut model_tail);
+///         }
+///         _ => {
+///             let mut other = Rope2::from("x".repeat(random(1500)).as_str());
+///             model.push_str(&"x".repeat(other.len()));
+///             rope.append(&mut other);
+///         }
+///     }
+///
+///     assert_eq!(rope.len(), model.len());
+///     if !model.is_empty() {
+///         let idx = random(model.len());
+///         assert_eq!(rope.get(idx), model.get(idx).cloned());
+///     }
+/// }
+///
+/// assert!(rope.chunks().count() > 1);
+/// assert_eq!(rope.to_string2(), model);
+/// ```
+#[derive(Clone)]
+pub struct Rope2 {
+    root: Node
+}
+
+impl Rope2 {
+    /// Creates a new empty `Rope2`.
+    #[inline]
+    pub fn new() -> Rope2 {
+        Rope2 {
+            root: Node::Leaf(String2::new())
+        }
+    }
+
+    /// Returns the length of this rope, in chars.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.root.len()
+    }
+
+    /// Returns `true` if this rope has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the char at char index `idx`, or `None` if it is out of
+    /// bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<char> {
+        self.root.get(idx)
+    }
+
+    /// Inserts a string slice at char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of this rope.
+    pub fn insert(&mut self, idx: usize, string: &str) {
+        let chars: Vec<char> = string.chars().collect();
+        self.insert_chars(idx, &chars);
+    }
+
+    /// Inserts a char at char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of this rope.
+    #[inline]
+    pub fn insert_char(&mut self, idx: usize, ch: char) {
+        self.insert_chars(idx, &[ch]);
+    }
+
+    /// Appends a string slice to the end of this rope.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        let len = self.len();
+        self.insert(len, string);
+    }
+
+    /// Removes the chars in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is after its end, or its end is past
+    /// the end of the rope.
+    pub fn remove(&mut self, range: Range<usize>) {
+        assert!(range.start <= range.end, "range start {} is after its end {}", range.start, range.end);
+        assert!(range.end <= self.len(), "range end {} is out of bounds of a rope of length {}", range.end, self.len());
+
+        if range.start == range.end || self.root.remove_in_leaf(range.clone()) {
+            return
+        }
+
+        let (rest, tail) = self.take_root().split(range.end);
+        let (head, _) = rest.split(range.start);
+        self.root = Node::join(head, tail);
+    }
+
+    /// Splits this rope in two at char index `at`, returning the chars
+    /// from `at` on and keeping the ones before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is larger than the length of this rope.
+    pub fn split_off(&mut self, at: usize) -> Rope2 {
+        assert!(at <= self.len(), "split index {} is out of bounds", at);
+
+        let (head, tail) = self.take_root().split(at);
+        self.root = head;
+        Rope2 {
+            root: tail
+        }
+    }
+
+    /// Moves all the chars of `other` to the end of this rope, leaving
+    /// `other` empty.
+    #[inline]
+    pub fn append(&mut self, other: &mut Rope2) {
+        let tail = other.take_root();
+        self.root = Node::join(self.take_root(), tail);
+    }
+
+    /// Returns a copy of the chars in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is after its end, or its end is past
+    /// the end of the rope.
+    pub fn slice(&self, range: Range<usize>) -> String2 {
+        assert!(range.start <= range.end, "range start {} is after its end {}", range.start, range.end);
+        assert!(range.end <= self.len(), "range end {} is out of bounds of a rope of length {}", range.end, self.len());
+
+        let mut out = String2::with_capacity(range.end - range.start);
+        if range.start < range.end {
+            self.root.push_range(range, &mut out);
+        }
+        out
+    }
+
+    /// Returns an iterator over the chunks of this rope, in order. Their
+    /// sizes are an implementation detail, but none is empty.
+    #[inline]
+    pub fn chunks(&self) -> RopeChunks<'_> {
+        RopeChunks {
+            stack: vec![&self.root]
+        }
+    }
+
+    /// Returns an iterator over the chars of this rope.
+    #[inline]
+    pub fn chars(&self) -> RopeChars<'_> {
+        RopeChars {
+            chunks: self.chunks(),
+            chunk: [].iter()
+        }
+    }
+
+    /// Copies this rope into a `String2`.
+    pub fn to_string2(&self) -> String2 {
+        let mut out = String2::with_capacity(self.len());
+        for chunk in self.chunks() {
+            out.extend(chunk);
+        }
+        out
+    }
+
+    fn insert_chars(&mut self, idx: usize, chars: &[char]) {
+        assert!(idx <= self.len(), "insertion index {} is out of bounds", idx);
+
+        if chars.is_empty() || self.root.insert_in_leaf(idx, chars) {
+            return
+        }
+
+        let (head, tail) = self.take_root().split(idx);
+        self.root = Node::join(Node::join(head, Node::build(chars)), tail);
+    }
+
+    fn take_root(&mut self) -> Node {
+        mem::replace(&mut self.root, Node::Leaf(String2::new()))
+    }
+}
+
+impl Default for Rope2 {
+    #[inline]
+    fn default() -> Rope2 {
+        Rope2::new()
+    }
+}
+
+impl<'a> From<&'a str> for Rope2 {
+    #[inline]
+    fn from(string: &'a str) -> Rope2 {
+        let chars: Vec<char> = string.chars().collect();
+        Rope2 {
+            root: Node::build(&chars)
+        }
+    }
+}
+
+impl From<String2> for Rope2 {
+    #[inline]
+    fn from(s: String2) -> Rope2 {
+        Rope2 {
+            root: Node::build(s.as_slice())
+        }
+    }
+}
+
+impl From<Rope2> for String2 {
+    #[inline]
+    fn from(rope: Rope2) -> String2 {
+        rope.to_string2()
+    }
+}
+
+impl PartialEq for Rope2 {
+    #[inline]
+    fn eq(&self, other: &Rope2) -> bool {
+        self.len() == other.len() && self.chars().eq(other.chars())
+    }
+}
+
+impl Eq for Rope2 {}
+
+impl PartialEq<String2> for Rope2 {
+    #[inline]
+    fn eq(&self, other: &String2) -> bool {
+        self.len() == other.len() && self.chars().eq(other.as_slice().iter().cloned())
+    }
+}
+
+impl PartialEq<str> for Rope2 {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Rope2 {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self == *other
+    }
+}
+
+impl fmt::Display for Rope2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.width().is_some() || f.precision().is_some() {
+            return crate::fmt_display(self.to_string2().as_slice(), f)
+        }
+
+        for chunk in self.chunks() {
+            for &ch in chunk {
+                f.write_char(ch)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Rope2 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::fmt_debug(self.to_string2().as_slice(), f)
+    }
+}
+
+/// An iterator over the chunks of a [`Rope2`], as char slices.
+///
+/// This struct is created by the [`chunks`] method on `Rope2`.
+///
+/// [`Rope2`]: struct.Rope2.html
+/// [`chunks`]: struct.Rope2.html#method.chunks
+#[derive(Clone)]
+pub struct RopeChunks<'a> {
+    // The subtrees left to visit, the next one last.
+    stack: Vec<&'a Node>
+}
+
+impl<'a> Iterator for RopeChunks<'a> {
+    type Item = &'a [char];
+
+    fn next(&mut self) -> Option<&'a [char]> {
+        while let Some(node) = self.stack.pop() {
+            match *node {
+                Node::Leaf(ref s) if !s.is_empty() => return Some(s.as_slice()),
+                Node::Leaf(_) => {}
+                Node::Branch { ref left, ref right, .. } => {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a> FusedIterator for RopeChunks<'a> {}
+
+/// An iterator over the chars of a [`Rope2`].
+///
+/// This struct is created by the [`chars`] method on `Rope2`.
+///
+/// [`Rope2`]: struct.Rope2.html
+/// [`chars`]: struct.Rope2.html#method.chars
+#[derive(Clone)]
+pub struct RopeChars<'a> {
+    chunks: RopeChunks<'a>,
+    chunk: slice::Iter<'a, char>
+}
+
+impl<'a> Iterator for RopeChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(&ch) = self.chunk.next() {
+                return Some(ch)
+            }
+            self.chunk = self.chunks.next()?.iter();
+        }
+    }
+}
+
+impl<'a> FusedIterator for RopeChars<'a> {}
@@ -0,0 +1,47 @@
+use alloc::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::String2;
+
+/// `String2` is described exactly like `String`: as an inline JSON Schema
+/// `"string"`.
+///
+/// JSON Schema counts `minLength` and `maxLength` in code points, which is
+/// what [`len`] counts too, so length constraints added with
+/// `#[schemars(length(min = 1, max = 64))]` on a `String2` field match the
+/// `String2`'s own length.
+///
+/// This impl requires the `schemars` feature.
+///
+/// [`len`]: struct.String2.html#method.len
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::String2;
+///
+/// let schema = schemars::schema_for!(String2);
+///
+/// assert_eq!(schema.get("type").and_then(|t| t.as_str()), Some("string"));
+/// ```
+impl JsonSchema for String2 {
+    #[inline]
+    fn inline_schema() -> bool {
+        true
+    }
+
+    #[inline]
+    fn schema_name() -> Cow<'static, str> {
+        "string".into()
+    }
+
+    #[inline]
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string"
+        })
+    }
+}
@@ -0,0 +1,228 @@
+use core::fmt;
+use core::str;
+use alloc::string::String;
+
+use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::String2;
+
+/// Serializes a `String2` as a UTF-8 string, like `String`.
+///
+/// This impl requires the `serde` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate serde_json;
+/// # extern crate string2;
+/// use string2::String2;
+///
+/// let s = String2::from("héllo");
+///
+/// assert_eq!(serde_json::to_string(&s).unwrap(), "\"héllo\"");
+/// ```
+impl Serialize for String2 {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes a `String2` from a string, a char or UTF-8 bytes.
+///
+/// This impl requires the `serde` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate serde_json;
+/// # extern crate string2;
+/// use string2::String2;
+///
+/// let s: String2 = serde_json::from_str("\"h\\u00e9llo\"").unwrap();
+///
+/// assert_eq!(s, "héllo");
+/// ```
+impl<'de> Deserialize<'de> for String2 {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<String2, D::Error> {
+        deserializer.deserialize_string(String2Visitor)
+    }
+}
+
+struct String2Visitor;
+
+impl<'de> Visitor<'de> for String2Visitor {
+    type Value = String2;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<String2, E> {
+        Ok(String2::from(v))
+    }
+
+    #[inline]
+    fn visit_string<E: de::Error>(self, v: String) -> Result<String2, E> {
+        Ok(String2::from(v))
+    }
+
+    #[inline]
+    fn visit_char<E: de::Error>(self, v: char) -> Result<String2, E> {
+        Ok(String2::from(vec![v]))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<String2, E> {
+        match str::from_utf8(v) {
+            Ok(s) => Ok(String2::from(s)),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self))
+        }
+    }
+}
+
+/// Serializes a `String2` as a sequence of chars, for use with
+/// `#[serde(with = "string2::as_chars")]`.
+///
+/// This keeps the char-vector structure of a `String2` visible in the
+/// serialized data, and round-trips it exactly.
+///
+/// This module requires the `serde` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate serde;
+/// # extern crate serde_json;
+/// # extern crate string2;
+/// use serde::{Deserialize, Serialize};
+/// use string2::String2;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Word {
+///     #[serde(with = "string2::as_chars")]
+///     text: String2
+/// }
+///
+/// # fn main() {
+/// let word = Word { text: String2::from("hé") };
+/// let json = serde_json::to_string(&word).unwrap();
+///
+/// assert_eq!(json, r#"{"text":["h","é"]}"#);
+/// assert_eq!(serde_json::from_str::<Word>(&json).unwrap().text, "hé");
+/// # }
+/// ```
+pub mod as_chars {
+    use alloc::vec::Vec;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::String2;
+
+    /// Serializes `s` as a sequence of chars.
+    #[inline]
+    pub fn serialize<S: Serializer>(s: &String2, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(s.as_slice())
+    }
+
+    /// Deserializes a `String2` from a sequence of chars.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String2, D::Error> {
+        Vec::<char>::deserialize(deserializer).map(String2::from)
+    }
+}
+
+/// Serializes a `String2` as a sequence of Unicode code points, for use
+/// with `#[serde(with = "string2::as_code_points")]`.
+///
+/// Each char is written as a `u32`, which suits formats that have no char
+/// or string type of their own. Deserializing fails on numbers that are
+/// not Unicode scalar values.
+///
+/// This module requires the `serde` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # extern crate serde;
+/// # extern crate serde_json;
+/// # extern crate string2;
+/// use serde::{Deserialize, Serialize};
+/// use string2::String2;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Word {
+///     #[serde(with = "string2::as_code_points")]
+///     text: String2
+/// }
+///
+/// # fn main() {
+/// let word = Word { text: String2::from("hé") };
+/// let json = serde_json::to_string(&word).unwrap();
+///
+/// assert_eq!(json, r#"{"text":[104,233]}"#);
+/// assert_eq!(serde_json::from_str::<Word>(&json).unwrap().text, "hé");
+///
+/// assert!(serde_json::from_str::<Word>(r#"{"text":[55296]}"#).is_err());
+/// # }
+/// ```
+pub mod as_code_points {
+    use core::char;
+    use core::fmt;
+
+    use serde::de::{self, Deserializer, SeqAccess, Unexpected, Visitor};
+    use serde::Serializer;
+
+    use crate::String2;
+
+    /// Serializes `s` as a sequence of `u32` code points.
+    #[inline]
+    pub fn serialize<S: Serializer>(s: &String2, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(s.as_slice().iter().map(|&ch| ch as u32))
+    }
+
+    /// Deserializes a `String2` from a sequence of `u32` code points.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String2, D::Error> {
+        deserializer.deserialize_seq(CodePointsVisitor)
+    }
+
+    struct CodePointsVisitor;
+
+    impl<'de> Visitor<'de> for CodePointsVisitor {
+        type Value = String2;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a sequence of Unicode code points")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<String2, A::Error> {
+            // The hint comes from the input, so it is not trusted blindly.
+            let mut s = String2::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+            while let Some(code) = seq.next_element::<u32>()? {
+                match char::from_u32(code) {
+                    Some(ch) => s.push(ch),
+                    None => {
+                        return Err(de::Error::invalid_value(
+                            Unexpected::Unsigned(code as u64),
+                            &"a Unicode scalar value"
+                        ))
+                    }
+                }
+            }
+
+            Ok(s)
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+
+use crate::String2;
+
+// A shard may end at `idx` unless that would cut a word or a grapheme
+// cluster in two.
+#[inline]
+fn is_boundary(chars: &[char], idx: usize) -> bool {
+    if idx == 0 || idx == chars.len() {
+        return true
+    }
+
+    let (before, after) = (chars[idx - 1], chars[idx]);
+    (before.is_whitespace() || after.is_whitespace())
+        && !(before == '\r' && after == '\n')
+        && !extends_cluster(after)
+}
+
+// Returns `true` if `ch` joins the grapheme cluster of the char before it,
+// for the chars that occur in practice: combining diacritical marks, zero
+// width joiners, variation selectors, emoji modifiers and tags.
+#[inline]
+fn extends_cluster(ch: char) -> bool {
+    matches!(ch,
+        '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{200C}' | '\u{200D}' | '\u{20D0}'..='\u{20FF}' | '\u{FE00}'..='\u{FE0F}'
+        | '\u{FE20}'..='\u{FE2F}' | '\u{1F3FB}'..='\u{1F3FF}' | '\u{E0020}'..='\u{E007F}'
+        | '\u{E0100}'..='\u{E01EF}'
+    )
+}
+
+impl String2 {
+    /// Splits this `String2` into `n` pieces of near-equal length, without
+    /// cutting any word or grapheme cluster in two.
+    ///
+    /// The ideal cut points are every `len / n` chars; each one is moved to
+    /// the nearest position that is next to whitespace, but not between two
+    /// non-whitespace chars, inside a `\r\n` pair, or before a combining
+    /// mark, joiner or variation selector that attaches to a whitespace
+    /// char. Clusters inside words are never cut, as words are not, so this
+    /// keeps every grapheme cluster whole in practice, although the full
+    /// segmentation rules of UAX #29 are not applied.
+    /// The result always holds exactly `n` slices, in order, and
+    /// concatenating them gives back the original string, so the work can
+    /// be handed out to threads deterministically. Some slices may be
+    /// empty when the string is short or has very long words.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let text = String2::from("the quick brown fox jumps");
+    /// let shards = text.shards(3);
+    ///
+    /// assert_eq!(shards.len(), 3);
+    /// assert_eq!(String2::from(shards[0]), String2::from("the quick"));
+    /// assert_eq!(String2::from(shards[1]), String2::from(" brown "));
+    /// assert_eq!(String2::from(shards[2]), String2::from("fox jumps"));
+    ///
+    /// let lines = String2::from("one\r\ntwo");
+    /// assert_eq!(String2::from(lines.shards(2)[0]), String2::from("one\r\n"));
+    /// ```
+    pub fn shards(&self, n: usize) -> Vec<&[char]> {
+        assert!(n > 0, "number of shards must be greater than zero");
+
+        let chars = self.inner.as_slice();
+        let len = chars.len();
+        let mut shards = Vec::with_capacity(n);
+        let mut start = 0;
+
+        for i in 1..n {
+            let target = (len * i / n).max(start);
+
+            let mut after = target;
+            while !is_boundary(chars, after) {
+                after += 1;
+            }
+
+            let mut before = target;
+            while before > start && !is_boundary(chars, before) {
+                before -= 1;
+            }
+
+            let end = if is_boundary(chars, before) && target - before < after - target {
+                before
+            } else {
+                after
+            };
+
+            shards.push(&chars[start..end]);
+            start = end;
+        }
+
+        shards.push(&chars[start..]);
+        shards
+    }
+}
@@ -0,0 +1,250 @@
+use core::ops::{self, Range};
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::view::View2;
+
+/// A string whose slices share one reference-counted buffer, copying it
+/// only when one of them is changed.
+///
+/// Cloning a `SharedString2` or taking a [`slice`] of it only bumps a
+/// reference count, which makes it cheap to cut a large text into many
+/// owned pieces, such as the tokens of a parser. Changing one of them
+/// first copies its own chars into a buffer of its own, unless it is the
+/// only user of the whole buffer, so the others are never affected.
+///
+/// The buffer is shared with `Rc`, so a `SharedString2` cannot be sent to
+/// another thread.
+///
+/// [`slice`]: #method.slice
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::{SharedString2, String2};
+///
+/// let text = SharedString2::from(String2::from("hello world"));
+/// let mut hello = text.slice(0..5);
+/// let world = text.slice(6..11);
+/// assert!(hello.is_shared());
+///
+/// hello.push('!');
+/// assert!(!hello.is_shared());
+///
+/// assert_eq!(hello, "hello!");
+/// assert_eq!(world, "world");
+/// assert_eq!(text, "hello world");
+/// ```
+#[derive(Clone, Default)]
+pub struct SharedString2 {
+    buf: Rc<Vec<char>>,
+    start: usize,
+    end: usize
+}
+
+impl SharedString2 {
+    /// Creates a new empty `SharedString2`.
+    #[inline]
+    pub fn new() -> SharedString2 {
+        SharedString2::default()
+    }
+
+    /// Returns the length of this string, in chars.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if this string has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns `true` if the buffer of this string is shared with other
+    /// strings, so changing it would copy its chars.
+    #[inline]
+    pub fn is_shared(&self) -> bool {
+        Rc::strong_count(&self.buf) > 1
+    }
+
+    /// Returns the chars of this string.
+    #[inline]
+    pub fn as_slice(&self) -> &[char] {
+        &self.buf[self.start..self.end]
+    }
+
+    /// Returns a [`View2`] of this string, to search, trim and split it.
+    ///
+    /// [`View2`]: struct.View2.html
+    #[inline]
+    pub fn view(&self) -> View2<'_> {
+        View2::new(self.as_slice())
+    }
+
+    /// Returns the chars in `range` as a `SharedString2` that shares the
+    /// buffer of this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is after its end, or its end is past
+    /// the end of the string.
+    pub fn slice(&self, range: Range<usize>) -> SharedString2 {
+        assert!(range.start <= range.end, "range start {} is after its end {}", range.start, range.end);
+        assert!(range.end <= self.len(), "range end {} is out of bounds of a string of length {}", range.end, self.len());
+
+        SharedString2 {
+            buf: Rc::clone(&self.buf),
+            start: self.start + range.start,
+            end: self.start + range.end
+        }
+    }
+
+    /// Appends a char to the end of this string.
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        self.make_mut().push(ch);
+        self.end += 1;
+    }
+
+    /// Appends a string slice to the end of this string.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        let buf = self.make_mut();
+        buf.extend(string.chars());
+        self.end = buf.len();
+    }
+
+    /// Inserts a char at char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of this string.
+    #[inline]
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        assert!(idx <= self.len(), "insertion index {} is out of bounds", idx);
+        self.make_mut().insert(idx, ch);
+        self.end += 1;
+    }
+
+    /// Removes the last char and returns it, or `None` if this string is
+    /// empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = *self.as_slice().last()?;
+        self.end -= 1;
+        Some(ch)
+    }
+
+    /// Removes the char at char index `idx` and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> char {
+        assert!(idx < self.len(), "removal index {} is out of bounds", idx);
+        let ch = self.make_mut().remove(idx);
+        self.end -= 1;
+        ch
+    }
+
+    /// Shortens this string to `new_len` chars, doing nothing if it is
+    /// already shorter. This never copies the buffer.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.end = self.start + new_len.min(self.len());
+    }
+
+    /// Removes all chars. This never copies the buffer.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.end = self.start;
+    }
+
+    /// Converts this string into a `String2`, which reuses the buffer if it
+    /// is not shared.
+    #[inline]
+    pub fn into_string2(mut self) -> String2 {
+        self.make_mut();
+        String2::from(Rc::try_unwrap(self.buf).unwrap_or_else(|buf| buf.to_vec()))
+    }
+
+    /// Copies this string into a `String2`.
+    #[inline]
+    pub fn to_string2(&self) -> String2 {
+        String2::from(self.as_slice())
+    }
+
+    // Makes the buffer unique to this string and holding exactly its chars,
+    // copying them if needed, and returns it.
+    fn make_mut(&mut self) -> &mut Vec<char> {
+        let len = self.len();
+        match Rc::get_mut(&mut self.buf) {
+            Some(buf) => {
+                buf.truncate(self.end);
+                buf.drain(..self.start);
+            }
+            None => self.buf = Rc::new(self.buf[self.start..self.end].to_vec())
+        }
+        self.start = 0;
+        self.end = len;
+
+        Rc::get_mut(&mut self.buf).unwrap()
+    }
+}
+
+impl ops::Deref for SharedString2 {
+    type Target = [char];
+
+    #[inline]
+    fn deref(&self) -> &[char] {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[char]> for SharedString2 {
+    #[inline]
+    fn as_ref(&self) -> &[char] {
+        self.as_slice()
+    }
+}
+
+impl<'a> From<&'a str> for SharedString2 {
+    #[inline]
+    fn from(string: &'a str) -> SharedString2 {
+        SharedString2::from(String2::from(string))
+    }
+}
+
+impl From<String2> for SharedString2 {
+    /// Reuses the buffer of `s` without copying it.
+    #[inline]
+    fn from(s: String2) -> SharedString2 {
+        let len = s.len();
+        SharedString2 {
+            buf: Rc::new(s.inner),
+            start: 0,
+            end: len
+        }
+    }
+}
+
+impl From<SharedString2> for String2 {
+    #[inline]
+    fn from(s: SharedString2) -> String2 {
+        s.into_string2()
+    }
+}
+
+impl PartialEq for SharedString2 {
+    #[inline]
+    fn eq(&self, other: &SharedString2) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl_char_string_traits!([] SharedString2);
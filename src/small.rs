@@ -0,0 +1,320 @@
+use core::iter::FromIterator;
+use core::ops;
+
+use crate::String2;
+
+#[derive(Clone)]
+enum Repr<const N: usize> {
+    Inline {
+        buf: [char; N],
+        len: usize
+    },
+    Heap(String2)
+}
+
+/// A `String2` that keeps up to `N` chars inline, without allocating.
+///
+/// Most identifiers and tokens are only a few chars long, and a `String2`
+/// always puts its chars on the heap. A `SmallString2` stores them in
+/// place instead, and only moves them to a heap-allocated `String2` once
+/// it grows past `N` chars. After that it stays on the heap, like a `Vec`
+/// keeps its capacity.
+///
+/// A `SmallString2` dereferences to `[char]`. For the rest of the `String2`
+/// API, convert it with [`to_string2`] or [`into_string2`].
+///
+/// [`to_string2`]: #method.to_string2
+/// [`into_string2`]: #method.into_string2
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::SmallString2;
+///
+/// let mut s = SmallString2::<8>::from("token");
+/// assert!(s.is_inline());
+///
+/// s.push_str("izer");
+/// assert!(!s.is_inline());
+///
+/// assert_eq!(s, "tokenizer");
+/// assert_eq!(s.len(), 9);
+/// ```
+#[derive(Clone)]
+pub struct SmallString2<const N: usize> {
+    repr: Repr<N>
+}
+
+impl<const N: usize> SmallString2<N> {
+    /// Creates a new empty `SmallString2`, which does not allocate.
+    #[inline]
+    pub fn new() -> SmallString2<N> {
+        SmallString2 {
+            repr: Repr::Inline {
+                buf: ['\0'; N],
+                len: 0
+            }
+        }
+    }
+
+    /// Returns `true` if the chars are stored inline, `false` if they have
+    /// moved to the heap.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline { .. })
+    }
+
+    /// Returns the number of chars this string can hold without
+    /// allocating or reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match self.repr {
+            Repr::Inline { .. } => N,
+            Repr::Heap(ref s) => s.capacity()
+        }
+    }
+
+    /// Returns the chars of this string.
+    #[inline]
+    pub fn as_slice(&self) -> &[char] {
+        match self.repr {
+            Repr::Inline { ref buf, len } => &buf[..len],
+            Repr::Heap(ref s) => s.as_slice()
+        }
+    }
+
+    /// Returns the chars of this string, mutably. Its chars can be changed,
+    /// but not its length.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [char] {
+        match self.repr {
+            Repr::Inline { ref mut buf, len } => &mut buf[..len],
+            Repr::Heap(ref mut s) => s.as_mut_slice()
+        }
+    }
+
+    /// Appends a char to the end of this string.
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        self.reserve(1);
+
+        match self.repr {
+            Repr::Inline { ref mut buf, ref mut len } => {
+                buf[*len] = ch;
+                *len += 1;
+            }
+            Repr::Heap(ref mut s) => s.push(ch)
+        }
+    }
+
+    /// Appends a string slice to the end of this string.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.extend(string.chars());
+    }
+
+    /// Removes the last char and returns it, or `None` if this string is
+    /// empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        match self.repr {
+            Repr::Inline { ref buf, ref mut len } => {
+                *len = len.checked_sub(1)?;
+                Some(buf[*len])
+            }
+            Repr::Heap(ref mut s) => s.pop()
+        }
+    }
+
+    /// Inserts a char at char index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of this string.
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        assert!(idx <= self.len(), "insertion index {} is out of bounds", idx);
+        self.reserve(1);
+
+        match self.repr {
+            Repr::Inline { ref mut buf, ref mut len } => {
+                buf.copy_within(idx..*len, idx + 1);
+                buf[idx] = ch;
+                *len += 1;
+            }
+            Repr::Heap(ref mut s) => s.insert(idx, ch)
+        }
+    }
+
+    /// Removes the char at char index `idx` and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn remove(&mut self, idx: usize) -> char {
+        assert!(idx < self.len(), "removal index {} is out of bounds", idx);
+
+        match self.repr {
+            Repr::Inline { ref mut buf, ref mut len } => {
+                let ch = buf[idx];
+                buf.copy_within(idx + 1..*len, idx);
+                *len -= 1;
+                ch
+            }
+            Repr::Heap(ref mut s) => s.remove(idx)
+        }
+    }
+
+    /// Shortens this string to `new_len` chars, doing nothing if it is
+    /// already shorter.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        match self.repr {
+            Repr::Inline { ref mut len, .. } => *len = new_len.min(*len),
+            Repr::Heap(ref mut s) => s.truncate(new_len)
+        }
+    }
+
+    /// Removes all chars, keeping the capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Makes room for at least `additional` more chars, moving the chars to
+    /// the heap if they no longer fit inline.
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.len() + additional;
+
+        match self.repr {
+            Repr::Inline { ref buf, len } if needed > N => {
+                let mut s = String2::with_capacity(needed.max(2 * N));
+                s.extend(&buf[..len]);
+                self.repr = Repr::Heap(s);
+            }
+            Repr::Inline { .. } => {}
+            Repr::Heap(ref mut s) => s.reserve(additional)
+        }
+    }
+
+    /// Copies this string into a `String2`.
+    #[inline]
+    pub fn to_string2(&self) -> String2 {
+        String2::from(self.as_slice())
+    }
+
+    /// Converts this string into a `String2`, which only allocates if the
+    /// chars are inline.
+    #[inline]
+    pub fn into_string2(self) -> String2 {
+        match self.repr {
+            Repr::Inline { ref buf, len } => String2::from(&buf[..len]),
+            Repr::Heap(s) => s
+        }
+    }
+}
+
+impl<const N: usize> Default for SmallString2<N> {
+    #[inline]
+    fn default() -> SmallString2<N> {
+        SmallString2::new()
+    }
+}
+
+impl<const N: usize> ops::Deref for SmallString2<N> {
+    type Target = [char];
+
+    #[inline]
+    fn deref(&self) -> &[char] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> ops::DerefMut for SmallString2<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [char] {
+        self.as_mut_slice()
+    }
+}
+
+impl<const N: usize> AsRef<[char]> for SmallString2<N> {
+    #[inline]
+    fn as_ref(&self) -> &[char] {
+        self.as_slice()
+    }
+}
+
+impl<'a, const N: usize> From<&'a str> for SmallString2<N> {
+    #[inline]
+    fn from(string: &'a str) -> SmallString2<N> {
+        string.chars().collect()
+    }
+}
+
+impl<'a, const N: usize> From<&'a [char]> for SmallString2<N> {
+    #[inline]
+    fn from(chars: &'a [char]) -> SmallString2<N> {
+        chars.iter().cloned().collect()
+    }
+}
+
+impl<const N: usize> From<String2> for SmallString2<N> {
+    /// Keeps the chars of `s` where they are, on the heap, unless they fit
+    /// inline.
+    #[inline]
+    fn from(s: String2) -> SmallString2<N> {
+        if s.len() <= N {
+            SmallString2::from(s.as_slice())
+        } else {
+            SmallString2 {
+                repr: Repr::Heap(s)
+            }
+        }
+    }
+}
+
+impl<const N: usize> From<SmallString2<N>> for String2 {
+    #[inline]
+    fn from(s: SmallString2<N>) -> String2 {
+        s.into_string2()
+    }
+}
+
+impl<const N: usize> FromIterator<char> for SmallString2<N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> SmallString2<N> {
+        let mut s = SmallString2::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl<const N: usize> Extend<char> for SmallString2<N> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for ch in iter {
+            self.push(ch);
+        }
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a str> for SmallString2<N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(s);
+        }
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<SmallString2<M>> for SmallString2<N> {
+    #[inline]
+    fn eq(&self, other: &SmallString2<M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl_char_string_traits!([const N: usize] SmallString2<N>);
@@ -0,0 +1,112 @@
+use core::fmt;
+use alloc::sync::Arc;
+
+use crate::String2;
+
+/// A frozen copy of the contents of a string.
+///
+/// Snapshots are created by [`snapshot`] and brought back with
+/// [`restore`]. The chars are stored once, behind an `Arc`, so cloning a
+/// snapshot, keeping many of them around or sending them to other threads
+/// never copies the text again.
+///
+/// A `String2` owns its buffer, so taking a snapshot of one, or restoring
+/// one, copies all of its chars. An [`ArcString2`] shares its chars with
+/// its snapshots instead, so both are free; it suits texts that are
+/// snapshotted more often than they are changed.
+///
+/// [`snapshot`]: struct.String2.html#method.snapshot
+/// [`restore`]: struct.String2.html#method.restore
+/// [`ArcString2`]: struct.ArcString2.html
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Snapshot2 {
+    pub(crate) chars: Arc<[char]>
+}
+
+impl Snapshot2 {
+    /// Returns the number of chars in the snapshot.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Returns `true` if the snapshot holds no chars.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Returns the chars of the snapshot.
+    #[inline]
+    pub fn as_slice(&self) -> &[char] {
+        &self.chars
+    }
+
+    /// Returns `true` if both snapshots share the same storage.
+    #[inline]
+    pub fn ptr_eq(this: &Snapshot2, other: &Snapshot2) -> bool {
+        Arc::ptr_eq(&this.chars, &other.chars)
+    }
+
+    /// Copies the snapshot into a new `String2`.
+    #[inline]
+    pub fn to_string2(&self) -> String2 {
+        String2::from(&self.chars[..])
+    }
+}
+
+impl AsRef<[char]> for Snapshot2 {
+    #[inline]
+    fn as_ref(&self) -> &[char] {
+        &self.chars
+    }
+}
+
+impl fmt::Debug for Snapshot2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Snapshot2").field(&self.to_string2()).finish()
+    }
+}
+
+impl String2 {
+    /// Takes a snapshot of the contents of this `String2`.
+    ///
+    /// This copies all the chars, in O(n) time, into storage that the
+    /// clones of the snapshot share. To take snapshots without copying,
+    /// use an [`ArcString2`].
+    ///
+    /// [`ArcString2`]: struct.ArcString2.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut s = String2::from("draft");
+    /// let saved = s.snapshot();
+    ///
+    /// s.push_str(" with a preview");
+    /// assert_eq!(s, "draft with a preview");
+    ///
+    /// s.restore(&saved);
+    /// assert_eq!(s, "draft");
+    /// ```
+    #[inline]
+    pub fn snapshot(&self) -> Snapshot2 {
+        Snapshot2 {
+            chars: Arc::from(&self.inner[..])
+        }
+    }
+
+    /// Replaces the contents of this `String2` with those of `snapshot`.
+    ///
+    /// This copies all the chars of the snapshot, reusing the current
+    /// buffer when it is large enough.
+    #[inline]
+    pub fn restore(&mut self, snapshot: &Snapshot2) {
+        self.clear();
+        self.extend(snapshot.chars.iter());
+    }
+}
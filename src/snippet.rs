@@ -0,0 +1,142 @@
+use core::ops::Range;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::String2;
+
+// Tabs are shown as this many spaces, so the carets stay aligned whatever
+// the tab stops of the terminal.
+const TAB_WIDTH: usize = 4;
+
+// The number of columns `ch` takes in a snippet.
+fn snippet_width(ch: char) -> usize {
+    if ch == '\t' {
+        return TAB_WIDTH
+    }
+
+    #[cfg(feature = "unicode-width")]
+    {
+        crate::width::char_width(ch)
+    }
+    #[cfg(not(feature = "unicode-width"))]
+    {
+        1
+    }
+}
+
+fn push_repeated(out: &mut String2, ch: char, count: usize) {
+    out.extend((0..count).map(|_| ch));
+}
+
+fn push_number(out: &mut String2, n: usize, width: usize) {
+    let digits = n.to_string();
+    push_repeated(out, ' ', width - digits.len());
+    out.push_str(&digits);
+}
+
+impl String2 {
+    /// Renders an excerpt of this source text with the chars in `range`
+    /// underlined and labeled with `message`, in the style of rustc
+    /// diagnostics.
+    ///
+    /// Each line the range touches is shown after its 1-based number, with
+    /// carets under the part of it in the range; `message` follows the last
+    /// carets. An empty range gets a single caret at its position. The
+    /// carets are sized in display columns: tabs are shown as four spaces
+    /// and, with the `unicode-width` feature, wide chars get two carets and
+    /// combining marks none. Lines are split at `\n`, and a `\r` before it
+    /// is left out.
+    ///
+    /// The result has no trailing newline.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is after its end, or its end is past
+    /// the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let source = String2::from("fn main() {\n    let x = foo(1);\n}\n");
+    ///
+    /// let snippet = source.render_snippet(24..27, "not found in this scope");
+    /// assert_eq!(snippet, concat!(
+    ///     "  |\n",
+    ///     "2 |     let x = foo(1);\n",
+    ///     "  |             ^^^ not found in this scope"
+    /// ));
+    /// ```
+    pub fn render_snippet(&self, range: Range<usize>, message: &str) -> String2 {
+        assert!(range.start <= range.end, "range start {} is after its end {}", range.start, range.end);
+        assert!(range.end <= self.len(), "range end {} is out of bounds of a string of length {}", range.end, self.len());
+
+        let chars = &self.inner;
+
+        // The lines touched by the range, as their number and bounds. A
+        // range ending just after a newline does not touch the next line.
+        let last_char = if range.end > range.start { range.end - 1 } else { range.start };
+        let mut lines = Vec::new();
+        let mut line_start = 0;
+        for (number, line) in chars.split(|&ch| ch == '\n').enumerate() {
+            let line_end = line_start + line.len();
+            if line_end >= range.start && line_start <= last_char {
+                lines.push((number + 1, line_start, line_end));
+            }
+            if line_start > last_char {
+                break
+            }
+            line_start = line_end + 1;
+        }
+
+        let gutter = lines.last().map_or(1, |&(number, _, _)| number.to_string().len());
+
+        let mut out = String2::new();
+        push_repeated(&mut out, ' ', gutter);
+        out.push_str(" |");
+
+        for (idx, &(number, start, end)) in lines.iter().enumerate() {
+            let mut text = &chars[start..end];
+            if text.last() == Some(&'\r') {
+                text = &text[..text.len() - 1];
+            }
+
+            out.push('\n');
+            push_number(&mut out, number, gutter);
+            out.push_str(" |");
+            if !text.is_empty() {
+                out.push(' ');
+            }
+            for &ch in text {
+                if ch == '\t' {
+                    push_repeated(&mut out, ' ', TAB_WIDTH);
+                } else {
+                    out.push(ch);
+                }
+            }
+
+            // The part of the line in the range, clamped to its text.
+            let from = range.start.saturating_sub(start).min(text.len());
+            let to = (range.end - start).min(text.len());
+
+            let indent: usize = text[..from].iter().map(|&ch| snippet_width(ch)).sum();
+            let carets: usize = text[from..to].iter().map(|&ch| snippet_width(ch)).sum();
+
+            out.push('\n');
+            push_repeated(&mut out, ' ', gutter);
+            out.push_str(" | ");
+            push_repeated(&mut out, ' ', indent);
+            push_repeated(&mut out, '^', carets.max(1));
+
+            if idx == lines.len() - 1 && !message.is_empty() {
+                out.push(' ');
+                out.push_str(message);
+            }
+        }
+
+        out
+    }
+}
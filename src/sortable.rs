@@ -0,0 +1,114 @@
+use core::char;
+use alloc::vec::Vec;
+
+use crate::String2;
+
+const ESCAPE: u8 = 0xff;
+
+impl String2 {
+    /// Encodes this `String2` into bytes that sort, compared as byte
+    /// strings, in the same order as the `String2`s themselves.
+    ///
+    /// Each char is written as its code point in three big-endian bytes.
+    /// Every `0x00` byte is then escaped as `0x00 0xff`, and the key ends
+    /// with a `0x00 0x00` terminator, so the order is kept even when more
+    /// bytes follow the key, as in composite keys of ordered key-value
+    /// stores.
+    ///
+    /// Use [`from_sortable_bytes`] to decode the key back.
+    ///
+    /// [`from_sortable_bytes`]: #method.from_sortable_bytes
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let a = String2::from("ab");
+    /// let b = String2::from("abc");
+    /// let c = String2::from("b");
+    ///
+    /// assert!(a.to_sortable_bytes() < b.to_sortable_bytes());
+    /// assert!(b.to_sortable_bytes() < c.to_sortable_bytes());
+    ///
+    /// // The order holds with trailing data.
+    /// let mut a_key = a.to_sortable_bytes();
+    /// a_key.push(0xff);
+    /// assert!(a_key < b.to_sortable_bytes());
+    /// ```
+    pub fn to_sortable_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.inner.len() * 3 + 2);
+
+        for &ch in &self.inner {
+            let c = ch as u32;
+
+            for &byte in &[(c >> 16) as u8, (c >> 8) as u8, c as u8] {
+                out.push(byte);
+                if byte == 0 {
+                    out.push(ESCAPE);
+                }
+            }
+        }
+
+        out.extend_from_slice(&[0, 0]);
+        out
+    }
+
+    /// Decodes a key written by [`to_sortable_bytes`] from the start of
+    /// `bytes`, returning it along with the bytes that follow it.
+    ///
+    /// Returns `None` if `bytes` does not start with a valid key.
+    ///
+    /// [`to_sortable_bytes`]: #method.to_sortable_bytes
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let mut key = String2::from("user\0").to_sortable_bytes();
+    /// key.extend_from_slice(&[1, 2]);
+    ///
+    /// let (s, rest) = String2::from_sortable_bytes(&key).unwrap();
+    /// assert_eq!(s, String2::from("user\0"));
+    /// assert_eq!(rest, &[1, 2]);
+    /// ```
+    pub fn from_sortable_bytes(bytes: &[u8]) -> Option<(String2, &[u8])> {
+        let mut chars = Vec::new();
+        let mut code = [0u8; 3];
+        let mut filled = 0;
+        let mut idx = 0;
+
+        loop {
+            let byte = *bytes.get(idx)?;
+            idx += 1;
+
+            if byte == 0 {
+                match *bytes.get(idx)? {
+                    0 => break,
+                    ESCAPE => idx += 1,
+                    _ => return None
+                }
+            }
+
+            code[filled] = byte;
+            filled += 1;
+
+            if filled == 3 {
+                filled = 0;
+                let c = (code[0] as u32) << 16 | (code[1] as u32) << 8 | code[2] as u32;
+                chars.push(char::from_u32(c)?);
+            }
+        }
+
+        if filled != 0 {
+            return None
+        }
+
+        Some((String2::from(chars), &bytes[idx + 1..]))
+    }
+}
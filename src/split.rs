@@ -0,0 +1,153 @@
+use crate::pattern::CharPattern;
+
+/// An iterator over the slices of a `String2` separated by a pattern, with
+/// the matched char kept at the end of each slice.
+///
+/// This struct is created by the [`split_inclusive`] method on `String2`.
+///
+/// [`split_inclusive`]: struct.String2.html#method.split_inclusive
+pub struct SplitInclusive<'a, P> {
+    inner: SplitKeepDelimiter<'a, P>
+}
+
+impl<'a, P: CharPattern> SplitInclusive<'a, P> {
+    #[inline]
+    pub(crate) fn new(haystack: &'a [char], pattern: P) -> SplitInclusive<'a, P> {
+        SplitInclusive {
+            inner: SplitKeepDelimiter::new(haystack, pattern, DelimiterMode::Preceding)
+        }
+    }
+}
+
+impl<'a, P: CharPattern> Iterator for SplitInclusive<'a, P> {
+    type Item = &'a [char];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [char]> {
+        self.inner.next()
+    }
+}
+
+/// Where [`split_keep_delimiter`] puts the matched delimiters.
+///
+/// [`split_keep_delimiter`]: struct.String2.html#method.split_keep_delimiter
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DelimiterMode {
+    /// Each delimiter is kept at the end of the piece that precedes it, as
+    /// with [`split_inclusive`].
+    ///
+    /// [`split_inclusive`]: struct.String2.html#method.split_inclusive
+    Preceding,
+    /// Each delimiter is yielded as a piece of its own.
+    Separate
+}
+
+/// An iterator over the pieces of a `String2` separated by a pattern, with
+/// the matched delimiters kept according to a [`DelimiterMode`].
+///
+/// This struct is created by the [`split_keep_delimiter`] method on
+/// `String2`.
+///
+/// [`DelimiterMode`]: enum.DelimiterMode.html
+/// [`split_keep_delimiter`]: struct.String2.html#method.split_keep_delimiter
+pub struct SplitKeepDelimiter<'a, P> {
+    remainder: &'a [char],
+    pattern: P,
+    mode: DelimiterMode
+}
+
+impl<'a, P: CharPattern> SplitKeepDelimiter<'a, P> {
+    #[inline]
+    pub(crate) fn new(haystack: &'a [char], pattern: P, mode: DelimiterMode) -> SplitKeepDelimiter<'a, P> {
+        SplitKeepDelimiter {
+            remainder: haystack,
+            pattern,
+            mode
+        }
+    }
+}
+
+impl<'a, P: CharPattern> Iterator for SplitKeepDelimiter<'a, P> {
+    type Item = &'a [char];
+
+    fn next(&mut self) -> Option<&'a [char]> {
+        if self.remainder.is_empty() {
+            return None
+        }
+
+        let remainder = self.remainder;
+        let pattern = &mut self.pattern;
+
+        let end = match (remainder.iter().position(|&c| pattern.matches(c)), self.mode) {
+            (Some(idx), DelimiterMode::Preceding) => idx + 1,
+            (Some(0), DelimiterMode::Separate) => 1,
+            (Some(idx), DelimiterMode::Separate) => idx,
+            (None, _) => remainder.len()
+        };
+
+        let (piece, rest) = remainder.split_at(end);
+        self.remainder = rest;
+
+        Some(piece)
+    }
+}
+
+/// An iterator over the lines of a `String2`, without their line endings.
+///
+/// This struct is created by the [`lines`] method on `String2`.
+///
+/// [`lines`]: struct.String2.html#method.lines
+pub struct Lines<'a> {
+    inner: SplitInclusive<'a, char>
+}
+
+impl<'a> Lines<'a> {
+    #[inline]
+    pub(crate) fn new(haystack: &'a [char]) -> Lines<'a> {
+        Lines {
+            inner: SplitInclusive::new(haystack, '\n')
+        }
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a [char];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [char]> {
+        self.inner.next().map(|line| {
+            match line.strip_suffix(&['\n']) {
+                Some(line) => line.strip_suffix(&['\r']).unwrap_or(line),
+                None => line
+            }
+        })
+    }
+}
+
+/// An iterator over the lines of a `String2`, with their line endings kept.
+///
+/// This struct is created by the [`lines_with_terminators`] method on
+/// `String2`.
+///
+/// [`lines_with_terminators`]: struct.String2.html#method.lines_with_terminators
+pub struct LinesWithTerminators<'a> {
+    inner: SplitInclusive<'a, char>
+}
+
+impl<'a> LinesWithTerminators<'a> {
+    #[inline]
+    pub(crate) fn new(haystack: &'a [char]) -> LinesWithTerminators<'a> {
+        LinesWithTerminators {
+            inner: SplitInclusive::new(haystack, '\n')
+        }
+    }
+}
+
+impl<'a> Iterator for LinesWithTerminators<'a> {
+    type Item = &'a [char];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [char]> {
+        self.inner.next()
+    }
+}
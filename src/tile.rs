@@ -0,0 +1,49 @@
+use crate::String2;
+
+impl String2 {
+    /// Repeats this `String2` until the result is exactly `width` chars
+    /// long, cutting the last repetition short.
+    ///
+    /// This is handy for ruler lines and separators in terminal output. An
+    /// empty string stays empty, whatever the width.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("-=").tile_to_width(7), "-=-=-=-");
+    /// assert_eq!(String2::from("─").tile_to_width(3), "───");
+    /// assert_eq!(String2::new().tile_to_width(3), "");
+    /// ```
+    #[inline]
+    pub fn tile_to_width(&self, width: usize) -> String2 {
+        self.tile_to_width_with(width, "")
+    }
+
+    /// Repeats this `String2`, with `separator` between the repetitions,
+    /// until the result is exactly `width` chars long, cutting the last
+    /// piece short.
+    ///
+    /// If both this string and `separator` are empty, the result is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let tick = String2::from("|");
+    ///
+    /// assert_eq!(tick.tile_to_width_with(11, "····"), "|····|····|");
+    /// assert_eq!(tick.tile_to_width_with(4, "··"), "|··|");
+    /// ```
+    pub fn tile_to_width_with(&self, width: usize, separator: &str) -> String2 {
+        let unit = self.inner.iter().cloned().chain(separator.chars());
+
+        unit.cycle().take(width).collect()
+    }
+}
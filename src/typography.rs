@@ -0,0 +1,188 @@
+use alloc::vec::Vec;
+
+use crate::String2;
+use crate::case::{is_cased, is_final_sigma, push_titlecase};
+
+// Whether a quote after `prev` opens a quotation rather than closing one.
+fn opens_quote(prev: Option<char>) -> bool {
+    match prev {
+        None => true,
+        Some(ch) => ch.is_whitespace() || matches!(ch, '(' | '[' | '{' | '<' | '—' | '–' | '-' | '/' | '“' | '‘')
+    }
+}
+
+// Chars that never have a space before them.
+fn closes(ch: char) -> bool {
+    matches!(ch, ',' | '.' | ';' | ':' | '!' | '?' | ')' | ']' | '}')
+}
+
+// Chars that never have a space after them.
+fn opens(ch: char) -> bool {
+    matches!(ch, '(' | '[' | '{')
+}
+
+// Whether a space is missing between the punctuation `punct` and `next`,
+// where `before` is the char before `punct`.
+fn space_missing(before: Option<char>, punct: char, next: char) -> bool {
+    match punct {
+        ',' | ';' | '!' | '?' => next.is_alphabetic(),
+        ':' => before.is_some_and(char::is_alphabetic) && next.is_alphabetic(),
+        '.' => before.is_some_and(char::is_lowercase) && next.is_uppercase(),
+        _ => false
+    }
+}
+
+impl String2 {
+    /// Returns a copy of this `String2` with straight quotes replaced by
+    /// curly ones.
+    ///
+    /// A quote at the start of the text, or after whitespace, an opening
+    /// bracket, a dash or another opening quote, opens a quotation; any
+    /// other quote closes one. A `'` between two alphanumeric chars is an
+    /// apostrophe, as is a `'` before a digit, as in `'90s`; both become
+    /// `’`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("\"It's the '90s,\" she said. 'Really?'");
+    /// assert_eq!(s.smart_quotes(), "“It’s the ’90s,” she said. ‘Really?’");
+    /// ```
+    pub fn smart_quotes(&self) -> String2 {
+        let mut out = String2::with_capacity(self.len());
+
+        for (idx, &ch) in self.inner.iter().enumerate() {
+            let prev = out.inner.last().cloned();
+            let next = self.inner.get(idx + 1).cloned();
+
+            let curly = match ch {
+                '"' if opens_quote(prev) => '“',
+                '"' => '”',
+                '\'' if prev.is_some_and(char::is_alphanumeric) && next.is_some_and(char::is_alphanumeric) => '’',
+                '\'' if opens_quote(prev) && !next.is_some_and(|ch| ch.is_ascii_digit()) => '‘',
+                '\'' => '’',
+                _ => ch
+            };
+            out.push(curly);
+        }
+
+        out
+    }
+
+    /// Returns a copy of this `String2` with its spacing cleaned up,
+    /// following English conventions.
+    ///
+    /// On each line, runs of whitespace are collapsed to a single space and
+    /// trailing whitespace is removed, while the indentation is kept.
+    /// Spaces before `,`, `.`, `;`, `:`, `!`, `?` and closing brackets, and
+    /// after opening brackets, are removed. A missing space is added after
+    /// `,`, `;`, `!` and `?` followed by a letter, after `:` between two
+    /// letters, and after `.` between a lowercase and an uppercase letter,
+    /// so numbers like `1,000` and `3.14` and times like `10:30` are left
+    /// alone.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("Hello ,world!How are  you ( really ) ?\n  Costs 1,000.50 at 10:30.Fine ");
+    /// assert_eq!(s.fix_spacing(), "Hello, world! How are you (really)?\n  Costs 1,000.50 at 10:30.Fine");
+    /// ```
+    pub fn fix_spacing(&self) -> String2 {
+        let mut out = String2::with_capacity(self.len());
+
+        for (line_idx, line) in self.inner.split(|&ch| ch == '\n').enumerate() {
+            if line_idx > 0 {
+                out.push('\n');
+            }
+
+            let indent = line.iter().take_while(|ch| ch.is_whitespace()).count();
+            out.extend(&line[..indent]);
+            let line_start = out.len();
+
+            let mut space = false;
+            for &ch in &line[indent..] {
+                if ch.is_whitespace() {
+                    space = true;
+                    continue
+                }
+
+                let text = &out.inner[line_start..];
+                if let Some(&prev) = text.last() {
+                    let before = text.len().checked_sub(2).map(|idx| text[idx]);
+                    let wanted = if space { !closes(ch) && !opens(prev) } else { space_missing(before, prev, ch) };
+                    if wanted {
+                        out.push(' ');
+                    }
+                }
+                space = false;
+                out.push(ch);
+            }
+        }
+
+        out
+    }
+
+    /// Returns a copy of this `String2` in sentence case: lowercase, except
+    /// for the first letter of each sentence and the English pronoun "I".
+    ///
+    /// A sentence starts at the beginning of the text, and after whitespace
+    /// following a `.`, `!` or `?`, with any closing quotes or brackets in
+    /// between. Its first cased char is mapped to titlecase, unless a digit
+    /// comes before it. Abbreviations like "e.g." are taken as the end of a
+    /// sentence, and proper nouns and acronyms are lowercased.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("THE END IS NEAR. OR IS IT? \"YES,\" I SAID! 3 MORE");
+    /// assert_eq!(s.sentence_case(), "The end is near. Or is it? \"Yes,\" I said! 3 more");
+    /// ```
+    pub fn sentence_case(&self) -> String2 {
+        let chars = &self.inner;
+        let mut out = Vec::with_capacity(chars.len());
+
+        let mut sentence_start = true;
+        let mut after_end = false;
+        for (idx, &ch) in chars.iter().enumerate() {
+            let prev = idx.checked_sub(1).map(|idx| chars[idx]);
+            let next = chars.get(idx + 1).cloned();
+            let pronoun = (ch == 'i' || ch == 'I')
+                && !prev.is_some_and(|ch| ch.is_alphanumeric() || ch == '\'' || ch == '’')
+                && !next.is_some_and(char::is_alphanumeric);
+
+            if (sentence_start && is_cased(ch)) || pronoun {
+                push_titlecase(ch, &mut out);
+            } else if ch == 'Σ' {
+                out.push(if is_final_sigma(chars, idx) { 'ς' } else { 'σ' });
+            } else {
+                out.extend(ch.to_lowercase());
+            }
+
+            if matches!(ch, '.' | '!' | '?') {
+                after_end = true;
+            } else if ch.is_whitespace() {
+                sentence_start |= after_end;
+                after_end = false;
+            } else if ch.is_alphanumeric() {
+                sentence_start = false;
+                after_end = false;
+            } else if !matches!(ch, '"' | '\'' | '”' | '’' | ')' | ']' | '}') {
+                after_end = false;
+            }
+        }
+
+        String2::from(out)
+    }
+}
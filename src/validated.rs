@@ -0,0 +1,277 @@
+use core::error;
+use core::fmt;
+
+/// The error returned when a value does not pass the validation of a type
+/// generated by [`validated_string2!`].
+///
+/// [`validated_string2!`]: macro.validated_string2.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    type_name: &'static str
+}
+
+impl ValidationError {
+    #[doc(hidden)]
+    #[inline]
+    pub fn new(type_name: &'static str) -> ValidationError {
+        ValidationError {
+            type_name
+        }
+    }
+
+    /// Returns the name of the type whose validation failed.
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid value for {}", self.type_name)
+    }
+}
+
+impl error::Error for ValidationError {}
+
+/// Generates a newtype over `String2` whose contents always satisfy a
+/// predicate.
+///
+/// The predicate is a function or non-capturing closure taking a
+/// `&String2` and returning a `bool`. It is checked when the value is
+/// created and after every mutation; a mutation that would make the value
+/// invalid returns a [`ValidationError`] and leaves it untouched.
+///
+/// The generated type has `new`, `is_valid`, `as_string2`, `into_string2`,
+/// the mutating methods `push`, `push_str`, `insert`, `pop`, `remove`,
+/// `truncate`, `clear` and the general `modify`, and implements `Deref` to
+/// `String2`, `AsRef`, `TryFrom<String2>`, `TryFrom<&str>`, `Display`,
+/// `Debug`, and the comparison traits.
+///
+/// [`ValidationError`]: struct.ValidationError.html
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #[macro_use]
+/// extern crate string2;
+///
+/// use std::convert::TryFrom;
+/// use string2::String2;
+///
+/// validated_string2!(
+///     /// A lowercase, dash-separated URL slug.
+///     pub Slug2,
+///     |s| !s.is_empty() && s.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+/// );
+///
+/// fn main() {
+///     let mut slug = Slug2::try_from("hello-world").unwrap();
+///
+///     slug.push_str("-2").unwrap();
+///     assert!(slug.push('!').is_err());
+///     assert_eq!(slug.as_string2(), &String2::from("hello-world-2"));
+///
+///     assert!(Slug2::try_from("Hello World").is_err());
+///     assert!(Slug2::is_valid(&String2::from("ok")));
+/// }
+/// ```
+#[macro_export]
+macro_rules! validated_string2 {
+    ($(#[$attr:meta])* $vis:vis $name:ident, $predicate:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+        $vis struct $name {
+            inner: $crate::String2
+        }
+
+        #[allow(dead_code)]
+        impl $name {
+            /// Creates a new value, checking it against the predicate.
+            #[inline]
+            pub fn new(s: $crate::String2) -> $crate::__private::result::Result<$name, $crate::ValidationError> {
+                if $name::is_valid(&s) {
+                    Ok($name { inner: s })
+                } else {
+                    Err($crate::ValidationError::new(stringify!($name)))
+                }
+            }
+
+            /// Returns `true` if `s` satisfies the predicate.
+            #[inline]
+            pub fn is_valid(s: &$crate::String2) -> bool {
+                let predicate: fn(&$crate::String2) -> bool = $predicate;
+                predicate(s)
+            }
+
+            /// Returns a reference to the underlying `String2`.
+            #[inline]
+            pub fn as_string2(&self) -> &$crate::String2 {
+                &self.inner
+            }
+
+            /// Converts this value into the underlying `String2`.
+            #[inline]
+            pub fn into_string2(self) -> $crate::String2 {
+                self.inner
+            }
+
+            /// Applies `f` to a copy of the underlying `String2`, and keeps
+            /// the result only if it satisfies the predicate.
+            ///
+            /// This copies the whole string on every call. The other
+            /// mutating methods edit it in place, and undo the edit if the
+            /// result is invalid.
+            pub fn modify<F, T>(&mut self, f: F) -> $crate::__private::result::Result<T, $crate::ValidationError>
+                where F: FnOnce(&mut $crate::String2) -> T
+            {
+                let mut copy = self.inner.clone();
+                let out = f(&mut copy);
+
+                if $name::is_valid(&copy) {
+                    self.inner = copy;
+                    Ok(out)
+                } else {
+                    Err($crate::ValidationError::new(stringify!($name)))
+                }
+            }
+
+            /// Appends a char, if the result is valid.
+            #[inline]
+            pub fn push(&mut self, ch: char) -> $crate::__private::result::Result<(), $crate::ValidationError> {
+                self.inner.push(ch);
+                self.validate_or(|s| {
+                    s.pop();
+                })
+            }
+
+            /// Appends a string slice, if the result is valid.
+            #[inline]
+            pub fn push_str(&mut self, string: &str) -> $crate::__private::result::Result<(), $crate::ValidationError> {
+                let len = self.inner.len();
+                self.inner.push_str(string);
+                self.validate_or(|s| s.truncate(len))
+            }
+
+            /// Inserts a char at char index `idx`, if the result is valid.
+            #[inline]
+            pub fn insert(&mut self, idx: usize, ch: char) -> $crate::__private::result::Result<(), $crate::ValidationError> {
+                self.inner.insert(idx, ch);
+                self.validate_or(|s| {
+                    s.remove(idx);
+                })
+            }
+
+            /// Removes and returns the last char, if the result is valid.
+            #[inline]
+            pub fn pop(&mut self) -> $crate::__private::result::Result<$crate::__private::option::Option<char>, $crate::ValidationError> {
+                match self.inner.pop() {
+                    Some(ch) => self.validate_or(|s| s.push(ch)).map(|()| Some(ch)),
+                    None => Ok(None)
+                }
+            }
+
+            /// Removes and returns the char at char index `idx`, if the
+            /// result is valid.
+            #[inline]
+            pub fn remove(&mut self, idx: usize) -> $crate::__private::result::Result<char, $crate::ValidationError> {
+                let ch = self.inner.remove(idx);
+                self.validate_or(|s| s.insert(idx, ch)).map(|()| ch)
+            }
+
+            /// Shortens the value to `new_len` chars, if the result is
+            /// valid.
+            #[inline]
+            pub fn truncate(&mut self, new_len: usize) -> $crate::__private::result::Result<(), $crate::ValidationError> {
+                if new_len >= self.inner.len() {
+                    return Ok(())
+                }
+
+                let mut tail = self.inner.split_off(new_len);
+                self.validate_or(|s| s.append(&mut tail))
+            }
+
+            /// Removes all chars, if the empty string is valid.
+            #[inline]
+            pub fn clear(&mut self) -> $crate::__private::result::Result<(), $crate::ValidationError> {
+                let old = $crate::__private::mem::take(&mut self.inner);
+                self.validate_or(|s| *s = old)
+            }
+
+            // Checks the value after an edit made in place, and calls
+            // `undo` to revert the edit if it is invalid.
+            #[inline]
+            fn validate_or<U>(&mut self, undo: U) -> $crate::__private::result::Result<(), $crate::ValidationError>
+                where U: FnOnce(&mut $crate::String2)
+            {
+                if $name::is_valid(&self.inner) {
+                    Ok(())
+                } else {
+                    undo(&mut self.inner);
+                    Err($crate::ValidationError::new(stringify!($name)))
+                }
+            }
+        }
+
+        impl $crate::__private::ops::Deref for $name {
+            type Target = $crate::String2;
+            #[inline]
+            fn deref(&self) -> &$crate::String2 {
+                &self.inner
+            }
+        }
+
+        impl $crate::__private::convert::AsRef<$crate::String2> for $name {
+            #[inline]
+            fn as_ref(&self) -> &$crate::String2 {
+                &self.inner
+            }
+        }
+
+        impl $crate::__private::convert::AsRef<[char]> for $name {
+            #[inline]
+            fn as_ref(&self) -> &[char] {
+                self.inner.as_ref()
+            }
+        }
+
+        impl $crate::__private::convert::TryFrom<$crate::String2> for $name {
+            type Error = $crate::ValidationError;
+            #[inline]
+            fn try_from(s: $crate::String2) -> $crate::__private::result::Result<$name, $crate::ValidationError> {
+                $name::new(s)
+            }
+        }
+
+        impl<'a> $crate::__private::convert::TryFrom<&'a str> for $name {
+            type Error = $crate::ValidationError;
+            #[inline]
+            fn try_from(s: &'a str) -> $crate::__private::result::Result<$name, $crate::ValidationError> {
+                $name::new($crate::String2::from(s))
+            }
+        }
+
+        impl $crate::__private::convert::From<$name> for $crate::String2 {
+            #[inline]
+            fn from(s: $name) -> $crate::String2 {
+                s.inner
+            }
+        }
+
+        impl $crate::__private::fmt::Display for $name {
+            #[inline]
+            fn fmt(&self, f: &mut $crate::__private::fmt::Formatter) -> $crate::__private::fmt::Result {
+                $crate::__private::fmt::Display::fmt(&self.inner, f)
+            }
+        }
+
+        impl $crate::__private::fmt::Debug for $name {
+            #[inline]
+            fn fmt(&self, f: &mut $crate::__private::fmt::Formatter) -> $crate::__private::fmt::Result {
+                $crate::__private::fmt::Debug::fmt(&self.inner, f)
+            }
+        }
+    };
+}
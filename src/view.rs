@@ -0,0 +1,309 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::slice::SliceIndex;
+
+use crate::String2;
+use crate::pattern::{CharPattern, CharSeq};
+
+/// A borrowed view of a range of chars, with the searching, trimming and
+/// splitting methods of a string.
+///
+/// Views are `Copy`, and every method that narrows a view returns another
+/// view of the same chars instead of copying them, so a string can be
+/// taken apart without allocating. Views are created by the [`view`]
+/// method on `String2`, or from any char slice.
+///
+/// [`view`]: struct.String2.html#method.view
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use string2::String2;
+///
+/// let s = String2::from("  key = some value  ");
+/// let line = s.view(..).trim();
+///
+/// let (key, value) = line.split_once('=').unwrap();
+/// assert_eq!(key.trim(), "key");
+/// assert_eq!(value.trim(), "some value");
+/// assert!(value.contains("val"));
+/// assert_eq!(value.find(|ch: char| ch == 'v'), Some(6));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct View2<'a> {
+    chars: &'a [char]
+}
+
+impl<'a> View2<'a> {
+    /// Creates a view of `chars`.
+    #[inline]
+    pub fn new(chars: &'a [char]) -> View2<'a> {
+        View2 {
+            chars
+        }
+    }
+
+    /// Returns the length of this view, in chars.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Returns `true` if this view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Returns the chars of this view.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [char] {
+        self.chars
+    }
+
+    /// Returns the char at char index `idx`, or `None` if it is out of
+    /// bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<char> {
+        self.chars.get(idx).cloned()
+    }
+
+    /// Returns a view of the chars in `range` of this view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    #[inline]
+    pub fn slice(&self, range: Range<usize>) -> View2<'a> {
+        View2::new(&self.chars[range])
+    }
+
+    /// Returns the char index of the first char matched by `pat`.
+    #[inline]
+    pub fn find<P: CharPattern>(&self, mut pat: P) -> Option<usize> {
+        self.chars.iter().position(|&ch| pat.matches(ch))
+    }
+
+    /// Returns the char index of the last char matched by `pat`.
+    #[inline]
+    pub fn rfind<P: CharPattern>(&self, mut pat: P) -> Option<usize> {
+        self.chars.iter().rposition(|&ch| pat.matches(ch))
+    }
+
+    /// Returns `true` if `needle` occurs in this view. An empty needle
+    /// occurs in every view.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("ababc");
+    /// let view = s.view(1..);
+    ///
+    /// assert!(view.contains("abc"));
+    /// assert!(view.contains(""));
+    /// assert!(!view.contains("aba"));
+    /// ```
+    pub fn contains<S: CharSeq>(&self, needle: S) -> bool
+        where S::Iter: Clone
+    {
+        let needle = needle.char_seq();
+        (0..=self.chars.len()).any(|start| {
+            let mut rest = self.chars[start..].iter();
+            needle.clone().all(|ch| rest.next() == Some(&ch))
+        })
+    }
+
+    /// Returns `true` if this view starts with `prefix`.
+    #[inline]
+    pub fn starts_with<S: CharSeq>(&self, prefix: S) -> bool {
+        let mut chars = self.chars.iter();
+        prefix.char_seq().all(|ch| chars.next() == Some(&ch))
+    }
+
+    /// Returns `true` if this view ends with `suffix`.
+    #[inline]
+    pub fn ends_with<S: CharSeq>(&self, suffix: S) -> bool {
+        let mut chars = self.chars.iter().rev();
+        suffix.char_seq().rev().all(|ch| chars.next() == Some(&ch))
+    }
+
+    /// Returns a view with leading and trailing whitespace removed.
+    #[inline]
+    pub fn trim(&self) -> View2<'a> {
+        self.trim_start().trim_end()
+    }
+
+    /// Returns a view with leading whitespace removed.
+    #[inline]
+    pub fn trim_start(&self) -> View2<'a> {
+        let start = self.chars.iter().take_while(|ch| ch.is_whitespace()).count();
+        View2::new(&self.chars[start..])
+    }
+
+    /// Returns a view with trailing whitespace removed.
+    #[inline]
+    pub fn trim_end(&self) -> View2<'a> {
+        let end = self.chars.len() - self.chars.iter().rev().take_while(|ch| ch.is_whitespace()).count();
+        View2::new(&self.chars[..end])
+    }
+
+    /// Splits this view at the first char matched by `pat`, returning the
+    /// views before and after it, or `None` if no char matches.
+    #[inline]
+    pub fn split_once<P: CharPattern>(&self, pat: P) -> Option<(View2<'a>, View2<'a>)> {
+        let idx = self.find(pat)?;
+        Some((View2::new(&self.chars[..idx]), View2::new(&self.chars[idx + 1..])))
+    }
+
+    /// Returns an iterator over the views between the chars matched by
+    /// `pat`. Like `str::split`, it yields empty views between adjacent
+    /// matches and at the ends, so `n` matches give `n + 1` views.
+    #[inline]
+    pub fn split<P: CharPattern>(&self, pat: P) -> ViewSplit<'a, P> {
+        ViewSplit {
+            rest: Some(self.chars),
+            pattern: pat
+        }
+    }
+
+    /// Copies the chars of this view into a new `String2`.
+    #[inline]
+    pub fn to_string2(&self) -> String2 {
+        String2::from(self.chars)
+    }
+}
+
+impl String2 {
+    /// Returns a [`View2`] of the chars in `range`, to search, trim and
+    /// split them without copying.
+    ///
+    /// [`View2`]: struct.View2.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("a,b,,c");
+    /// let parts: Vec<String2> = s.view(..).split(',').map(|part| part.to_string2()).collect();
+    ///
+    /// assert_eq!(parts, vec!["a", "b", "", "c"]);
+    /// assert_eq!(s.view(2..5), "b,,");
+    /// ```
+    #[inline]
+    pub fn view<R>(&self, range: R) -> View2<'_>
+        where R: SliceIndex<[char], Output = [char]>
+    {
+        View2::new(&self.inner[range])
+    }
+}
+
+impl<'a> From<&'a [char]> for View2<'a> {
+    #[inline]
+    fn from(chars: &'a [char]) -> View2<'a> {
+        View2::new(chars)
+    }
+}
+
+impl<'a> From<&'a String2> for View2<'a> {
+    #[inline]
+    fn from(s: &'a String2) -> View2<'a> {
+        View2::new(s.as_slice())
+    }
+}
+
+impl<'a> From<View2<'a>> for String2 {
+    #[inline]
+    fn from(view: View2<'a>) -> String2 {
+        view.to_string2()
+    }
+}
+
+impl<'a> AsRef<[char]> for View2<'a> {
+    #[inline]
+    fn as_ref(&self) -> &[char] {
+        self.chars
+    }
+}
+
+impl<'a> PartialEq<String2> for View2<'a> {
+    #[inline]
+    fn eq(&self, other: &String2) -> bool {
+        self.chars == other.as_slice()
+    }
+}
+
+impl<'a> PartialEq<str> for View2<'a> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.chars.iter().cloned().eq(other.chars())
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for View2<'a> {
+    #[inline]
+    fn eq(&self, other: &&'b str) -> bool {
+        self == *other
+    }
+}
+
+impl<'a> fmt::Display for View2<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::fmt_display(self.chars, f)
+    }
+}
+
+impl<'a> fmt::Debug for View2<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::fmt_debug(self.chars, f)
+    }
+}
+
+/// An iterator over the views of a [`View2`] separated by a pattern.
+///
+/// This struct is created by the [`split`] method on `View2`.
+///
+/// [`View2`]: struct.View2.html
+/// [`split`]: struct.View2.html#method.split
+pub struct ViewSplit<'a, P> {
+    // `None` once the last view has been yielded.
+    rest: Option<&'a [char]>,
+    pattern: P
+}
+
+impl<'a, P: CharPattern> Iterator for ViewSplit<'a, P> {
+    type Item = View2<'a>;
+
+    fn next(&mut self) -> Option<View2<'a>> {
+        let rest = self.rest?;
+        let pattern = &mut self.pattern;
+
+        match rest.iter().position(|&ch| pattern.matches(ch)) {
+            Some(idx) => {
+                self.rest = Some(&rest[idx + 1..]);
+                Some(View2::new(&rest[..idx]))
+            }
+            None => {
+                self.rest = None;
+                Some(View2::new(rest))
+            }
+        }
+    }
+}
+
+impl<'a, P: CharPattern> FusedIterator for ViewSplit<'a, P> {}
@@ -0,0 +1,81 @@
+use unicode_width::UnicodeWidthChar;
+
+use crate::String2;
+
+// The number of terminal columns taken by `ch`. Control chars take none.
+#[inline]
+pub(crate) fn char_width(ch: char) -> usize {
+    ch.width().unwrap_or(0)
+}
+
+impl String2 {
+    /// Returns the number of terminal columns this `String2` takes when
+    /// displayed.
+    ///
+    /// Wide chars, like most CJK ideographs and emoji, take two columns,
+    /// and combining marks and control chars take none, as given by the
+    /// East Asian Width property of UAX #11.
+    ///
+    /// This method requires the `unicode-width` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// assert_eq!(String2::from("abc").display_width(), 3);
+    /// assert_eq!(String2::from("日本").display_width(), 4);
+    /// assert_eq!(String2::from("e\u{301}").display_width(), 1);
+    /// ```
+    #[inline]
+    pub fn display_width(&self) -> usize {
+        self.inner.iter().map(|&ch| char_width(ch)).sum()
+    }
+
+    /// Repeats this `String2`, with `separator` between the repetitions,
+    /// until the result fills `width` terminal columns.
+    ///
+    /// The result never goes past `width`: when the next char is a wide
+    /// char that does not fit in the last column, it is left out and the
+    /// result is one column short. Repetitions that take no columns at all
+    /// give an empty result.
+    ///
+    /// This method requires the `unicode-width` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let wave = String2::from("〜");
+    ///
+    /// assert_eq!(wave.tile_to_display_width(6, ""), "〜〜〜");
+    /// assert_eq!(wave.tile_to_display_width(5, ""), "〜〜");
+    /// assert_eq!(wave.tile_to_display_width(7, "-"), "〜-〜-");
+    /// ```
+    pub fn tile_to_display_width(&self, width: usize, separator: &str) -> String2 {
+        let unit = self.inner.iter().cloned().chain(separator.chars());
+        let mut out = String2::new();
+
+        if unit.clone().all(|ch| char_width(ch) == 0) {
+            return out
+        }
+
+        let mut used = 0;
+        for ch in unit.cycle() {
+            let ch_width = char_width(ch);
+            if used + ch_width > width {
+                break
+            }
+
+            used += ch_width;
+            out.push(ch);
+        }
+
+        out
+    }
+}
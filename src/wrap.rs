@@ -0,0 +1,196 @@
+use alloc::vec::Vec;
+
+use crate::String2;
+
+/// How [`wrap_aligned`] lines up the text of each line within the width.
+///
+/// [`wrap_aligned`]: struct.String2.html#method.wrap_aligned
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    /// Text starts at the left edge, padded with spaces on the right.
+    Left,
+    /// Text ends at the right edge, padded with spaces on the left.
+    Right,
+    /// Text is centered, with any odd space of padding on the right.
+    Center,
+    /// Text spans the whole width, with the spaces between words widened
+    /// evenly, the leftmost ones first. The last line of a paragraph, and
+    /// lines with a single word, are aligned left.
+    Justify
+}
+
+// Wraps `chars` into lines of at most `width` chars. Words that do not fit
+// at the end of a line are broken at the last of their `break_points` that
+// fits, with a hyphen; words that do not fit on a line of their own either
+// are cut at the width.
+pub(crate) fn wrap_with<F>(chars: &[char], width: usize, mut break_points: F) -> Vec<String2>
+    where F: FnMut(&[char]) -> Vec<usize>
+{
+    let width = width.max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in chars.split(|&ch| ch == '\n') {
+        let mut line = String2::new();
+
+        for word in paragraph.split(|ch| ch.is_whitespace()).filter(|word| !word.is_empty()) {
+            // Only looked up for words that do not fit.
+            let mut points = None;
+            let mut rest = word;
+            // Where `rest` starts in `word`, to offset the break points.
+            let mut offset = 0;
+
+            loop {
+                let room = if line.is_empty() { width } else { width.saturating_sub(line.len() + 1) };
+                if rest.len() <= room {
+                    if !line.is_empty() {
+                        line.push(' ');
+                    }
+                    line.extend(rest);
+                    break
+                }
+
+                // The longest piece that fits with its hyphen.
+                let piece = points.get_or_insert_with(|| break_points(word))
+                    .iter()
+                    .map(|&point| point - offset.min(point))
+                    .filter(|&len| len > 0 && len < rest.len() && len < room)
+                    .max();
+
+                let len = match piece {
+                    Some(len) => len,
+                    None if !line.is_empty() => {
+                        lines.push(line);
+                        line = String2::new();
+                        continue
+                    }
+                    None => width
+                };
+
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.extend(&rest[..len]);
+                if piece.is_some() {
+                    line.push('-');
+                }
+                lines.push(line);
+                line = String2::new();
+
+                rest = &rest[len..];
+                offset += len;
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+fn push_spaces(out: &mut String2, count: usize) {
+    out.extend((0..count).map(|_| ' '));
+}
+
+impl String2 {
+    /// Wraps this `String2` into lines of at most `width` chars.
+    ///
+    /// Lines are filled greedily with the words of the text, separated by
+    /// single spaces. Existing newlines are kept as line breaks, so
+    /// paragraphs and blank lines survive. A word longer than `width` is
+    /// cut into pieces of `width` chars. A width of zero is treated as one.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::String2;
+    ///
+    /// let s = String2::from("The quick brown fox jumps over the lazy dog");
+    ///
+    /// assert_eq!(s.wrap(15), vec!["The quick brown", "fox jumps over", "the lazy dog"]);
+    /// assert_eq!(String2::from("abcdefgh ij").wrap(3), vec!["abc", "def", "gh", "ij"]);
+    /// ```
+    #[inline]
+    pub fn wrap(&self, width: usize) -> Vec<String2> {
+        wrap_with(&self.inner, width, |_| Vec::new())
+    }
+
+    /// Wraps this `String2` into lines of `width` chars, like [`wrap`], and
+    /// pads each line with spaces to exactly `width` chars, aligned as
+    /// `alignment` says.
+    ///
+    /// [`wrap`]: #method.wrap
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use string2::{Alignment, String2};
+    ///
+    /// let s = String2::from("The quick brown fox jumps over the lazy dog");
+    ///
+    /// assert_eq!(s.wrap_aligned(16, Alignment::Right), vec![
+    ///     " The quick brown",
+    ///     "  fox jumps over",
+    ///     "    the lazy dog"
+    /// ]);
+    /// assert_eq!(s.wrap_aligned(16, Alignment::Center), vec![
+    ///     "The quick brown ",
+    ///     " fox jumps over ",
+    ///     "  the lazy dog  "
+    /// ]);
+    /// assert_eq!(s.wrap_aligned(16, Alignment::Justify), vec![
+    ///     "The  quick brown",
+    ///     "fox  jumps  over",
+    ///     "the lazy dog    "
+    /// ]);
+    /// ```
+    pub fn wrap_aligned(&self, width: usize, alignment: Alignment) -> Vec<String2> {
+        let width = width.max(1);
+        let mut lines = Vec::new();
+
+        for paragraph in self.inner.split(|&ch| ch == '\n') {
+            let wrapped = wrap_with(paragraph, width, |_| Vec::new());
+            let last = wrapped.len() - 1;
+
+            for (idx, line) in wrapped.into_iter().enumerate() {
+                let padding = width - line.len();
+                let mut out = String2::with_capacity(width);
+
+                match alignment {
+                    Alignment::Justify if idx < last && line.inner.contains(&' ') => {
+                        let gaps = line.inner.iter().filter(|&&ch| ch == ' ').count();
+                        let mut gap = 0;
+                        for &ch in line.inner.iter() {
+                            out.push(ch);
+                            if ch == ' ' {
+                                let extra = padding / gaps + usize::from(gap < padding % gaps);
+                                push_spaces(&mut out, extra);
+                                gap += 1;
+                            }
+                        }
+                    }
+                    Alignment::Left | Alignment::Justify => {
+                        out.extend(line.inner.iter());
+                        push_spaces(&mut out, padding);
+                    }
+                    Alignment::Right => {
+                        push_spaces(&mut out, padding);
+                        out.extend(line.inner.iter());
+                    }
+                    Alignment::Center => {
+                        push_spaces(&mut out, padding / 2);
+                        out.extend(line.inner.iter());
+                        push_spaces(&mut out, padding - padding / 2);
+                    }
+                }
+
+                lines.push(out);
+            }
+        }
+
+        lines
+    }
+}